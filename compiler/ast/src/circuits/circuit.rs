@@ -28,6 +28,9 @@ use std::fmt;
 /// The fields are named so `circuit Foo(u8, u16)` is not allowed.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct Circuit {
+    /// The doc comments of the circuit, one per `///` line, without the leading `///`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
     /// The name of the type in the type system in this module.
     pub identifier: Identifier,
     /// The fields, constant variables, and functions of this structure.
@@ -43,6 +43,9 @@ pub enum CircuitMember {
         Identifier,
         /// The type the constant has.
         Type,
+        /// The doc comments of the variable, one per `///` line, without the leading `///`.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        Vec<String>,
     ),
     // CAUTION: circuit functions are unstable for Leo testnet3.
     // /// A function definition in a circuit.
@@ -57,7 +60,7 @@ impl CircuitMember {
     /// Returns the name of the circuit member without span.
     pub fn name(&self) -> Symbol {
         match self {
-            CircuitMember::CircuitVariable(ident, _type) => ident.name,
+            CircuitMember::CircuitVariable(ident, ..) => ident.name,
         }
     }
 }
@@ -65,7 +68,7 @@ impl CircuitMember {
 impl fmt::Display for CircuitMember {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            CircuitMember::CircuitVariable(ref identifier, ref type_) => write!(f, "{}: {}", identifier, type_),
+            CircuitMember::CircuitVariable(ref identifier, ref type_, _) => write!(f, "{}: {}", identifier, type_),
         }
     }
 }
@@ -55,7 +55,7 @@ impl UnaryOperation {
     }
 
     /// Represents the opera.tor as a string.
-    fn as_str(self) -> &'static str {
+    pub fn as_str(self) -> &'static str {
         match self {
             Self::Abs => "abs",
             Self::AbsWrapped => "abs_wrapped",
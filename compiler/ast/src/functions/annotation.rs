@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{simple_node_impl, Identifier, Node};
+use leo_span::{sym, Span};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An annotation on a function, e.g., `@test`.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct Annotation {
+    /// The name of the annotation, e.g., `test` in `@test`.
+    pub identifier: Identifier,
+    /// The span of the annotation, including the `@`.
+    pub span: Span,
+}
+
+impl Annotation {
+    /// Returns `true` if the annotation is `@test`.
+    pub fn is_test(&self) -> bool {
+        self.identifier.name == sym::test
+    }
+}
+
+impl fmt::Display for Annotation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "@{}", self.identifier)
+    }
+}
+
+simple_node_impl!(Annotation);
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Block, FunctionInput, Identifier, Node, Type};
+use crate::{Annotation, Block, FunctionInput, Identifier, Node, Printer, Type};
 use leo_span::{sym, Span, Symbol};
 
 use serde::{Deserialize, Serialize};
@@ -24,6 +24,12 @@ use std::fmt;
 /// A function definition.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct Function {
+    /// The doc comments of the function, one per `///` line, without the leading `///`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    /// The annotations of the function, e.g., `@test`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Annotation>,
     /// The function identifier, e.g., `foo` in `function foo(...) { ... }`.
     pub identifier: Identifier,
     /// The function's parameters.
@@ -58,15 +64,16 @@ impl Function {
         self.name() == sym::main
     }
 
+    /// Returns `true` if the function is annotated with `@test`.
+    pub fn is_test(&self) -> bool {
+        self.annotations.iter().any(Annotation::is_test)
+    }
+
     ///
     /// Private formatting method used for optimizing [fmt::Debug] and [fmt::Display] implementations.
     ///
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "function {}", self.identifier)?;
-
-        let parameters = self.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",");
-        let returns = self.output.to_string();
-        write!(f, "({}) -> {} {}", parameters, returns, self.block)
+        f.write_str(&Printer::default().print_function(self))
     }
 }
 
@@ -62,13 +62,31 @@ impl TryFrom<(Type, Expression)> for InputValue {
                 }
             },
             (type_, Expression::Unary(unary)) if unary.op == UnaryOperation::Negate => {
-                InputValue::try_from((type_, *unary.receiver))?
+                InputValue::try_from((type_, *unary.receiver))?.negate()
             }
             (_type_, expr) => return Err(InputError::illegal_expression(&expr, expr.span()).into()),
         })
     }
 }
 
+impl InputValue {
+    /// Negates a signed integer by toggling the sign of its digits, leaving other values as they are.
+    fn negate(self) -> Self {
+        let negate = |value: String| match value.strip_prefix('-') {
+            Some(digits) => digits.to_string(),
+            None => format!("-{}", value),
+        };
+        match self {
+            Self::I8(value) => Self::I8(negate(value)),
+            Self::I16(value) => Self::I16(negate(value)),
+            Self::I32(value) => Self::I32(negate(value)),
+            Self::I64(value) => Self::I64(negate(value)),
+            Self::I128(value) => Self::I128(negate(value)),
+            value => value,
+        }
+    }
+}
+
 impl fmt::Display for InputValue {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
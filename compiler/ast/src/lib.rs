@@ -45,6 +45,9 @@ pub use self::input::*;
 pub mod passes;
 pub use self::passes::*;
 
+pub mod printer;
+pub use self::printer::*;
+
 pub mod program;
 pub use self::program::*;
 
@@ -280,6 +280,8 @@ pub trait ProgramReconstructor: StatementReconstructor {
 
     fn reconstruct_function(&mut self, input: Function) -> Function {
         Function {
+            docs: input.docs,
+            annotations: input.annotations,
             identifier: input.identifier,
             input: input.input,
             output: input.output,
@@ -0,0 +1,271 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The precedence of ternary expressions, which bind the loosest.
+const TERNARY: u8 = 1;
+/// The precedence of unary `!` and `-`.
+const UNARY: u8 = 13;
+/// The precedence of postfix expressions, i.e., accesses, calls, method calls, and primary expressions.
+const POSTFIX: u8 = 14;
+
+/// Returns the precedence of an expression, following the layering of the parser.
+fn precedence(expression: &Expression) -> u8 {
+    match expression {
+        Expression::Ternary(_) => TERNARY,
+        Expression::Binary(binary) => match binary_operator(binary.op) {
+            Some(_) => binary_precedence(binary.op),
+            None => POSTFIX,
+        },
+        Expression::Unary(unary) if unary_operator(unary.op).is_some() => UNARY,
+        _ => POSTFIX,
+    }
+}
+
+/// Returns the precedence of an infix binary operation.
+fn binary_precedence(op: BinaryOperation) -> u8 {
+    match op {
+        BinaryOperation::Or => 2,
+        BinaryOperation::And => 3,
+        BinaryOperation::Eq | BinaryOperation::Neq => 4,
+        BinaryOperation::Lt | BinaryOperation::Lte | BinaryOperation::Gt | BinaryOperation::Gte => 5,
+        BinaryOperation::Xor => 6,
+        BinaryOperation::BitwiseOr => 7,
+        BinaryOperation::BitwiseAnd => 8,
+        BinaryOperation::Shl | BinaryOperation::Shr => 9,
+        BinaryOperation::Add | BinaryOperation::Sub => 10,
+        BinaryOperation::Mul | BinaryOperation::Div => 11,
+        BinaryOperation::Pow => 12,
+        _ => POSTFIX,
+    }
+}
+
+/// Returns the infix operator of a binary operation, if it has one.
+fn binary_operator(op: BinaryOperation) -> Option<&'static str> {
+    Some(match op {
+        BinaryOperation::Add => "+",
+        BinaryOperation::And => "&&",
+        BinaryOperation::BitwiseAnd => "&",
+        BinaryOperation::Div => "/",
+        BinaryOperation::Eq => "==",
+        BinaryOperation::Gte => ">=",
+        BinaryOperation::Gt => ">",
+        BinaryOperation::Lte => "<=",
+        BinaryOperation::Lt => "<",
+        BinaryOperation::Mul => "*",
+        BinaryOperation::Neq => "!=",
+        BinaryOperation::Or => "||",
+        BinaryOperation::BitwiseOr => "|",
+        BinaryOperation::Pow => "**",
+        BinaryOperation::Shl => "<<",
+        BinaryOperation::Shr => ">>",
+        BinaryOperation::Sub => "-",
+        BinaryOperation::Xor => "^",
+        _ => return None,
+    })
+}
+
+/// Returns the method name of a binary operation without an infix operator.
+fn binary_method(op: BinaryOperation) -> &'static str {
+    match op {
+        BinaryOperation::AddWrapped => "add_wrapped",
+        BinaryOperation::DivWrapped => "div_wrapped",
+        BinaryOperation::MulWrapped => "mul_wrapped",
+        BinaryOperation::Nand => "nand",
+        BinaryOperation::Nor => "nor",
+        BinaryOperation::PowWrapped => "pow_wrapped",
+        BinaryOperation::ShlWrapped => "shl_wrapped",
+        BinaryOperation::ShrWrapped => "shr_wrapped",
+        BinaryOperation::SubWrapped => "sub_wrapped",
+        op => unreachable!("`{}` is an infix operator", op),
+    }
+}
+
+/// Returns the prefix operator of a unary operation, if it has one.
+fn unary_operator(op: UnaryOperation) -> Option<&'static str> {
+    match op {
+        UnaryOperation::Negate => Some("-"),
+        UnaryOperation::Not => Some("!"),
+        _ => None,
+    }
+}
+
+/// Returns the method name of a unary operation without a prefix operator.
+fn unary_method(op: UnaryOperation) -> &'static str {
+    match op {
+        UnaryOperation::Abs => "abs",
+        UnaryOperation::AbsWrapped => "abs_wrapped",
+        UnaryOperation::Double => "double",
+        UnaryOperation::Inverse => "inv",
+        UnaryOperation::Square => "square",
+        UnaryOperation::SquareRoot => "square_root",
+        UnaryOperation::Negate | UnaryOperation::Not => unreachable!("`{:?}` is a prefix operator", op),
+    }
+}
+
+/// Returns `true` if the expression constructs a circuit outside of any parentheses,
+/// which is not allowed in the conditions of `if` statements and the bounds of `for` loops.
+fn constructs_circuit(expression: &Expression) -> bool {
+    match expression {
+        Expression::Circuit(_) => true,
+        Expression::Binary(binary) => constructs_circuit(&binary.left) || constructs_circuit(&binary.right),
+        Expression::Unary(unary) => constructs_circuit(&unary.receiver),
+        Expression::Ternary(ternary) => constructs_circuit(&ternary.condition),
+        Expression::Access(AccessExpression::Member(access)) => constructs_circuit(&access.inner),
+        Expression::Access(AccessExpression::Tuple(access)) => constructs_circuit(&access.tuple),
+        Expression::Call(call) => constructs_circuit(&call.function),
+        _ => false,
+    }
+}
+
+impl Printer<'_> {
+    /// Returns the code text of an expression at the current nesting level.
+    pub(super) fn expression(&self, expression: &Expression) -> String {
+        self.operand(expression, TERNARY, self.indent)
+    }
+
+    /// Returns the code text of the condition of an `if` statement or the bound of a `for` loop.
+    pub(super) fn condition(&self, expression: &Expression) -> String {
+        if constructs_circuit(expression) {
+            format!("({})", self.expression(expression))
+        } else {
+            self.expression(expression)
+        }
+    }
+
+    /// Returns the code text of a comma separated list of expressions.
+    fn expressions(&self, expressions: &[Expression], indent: usize) -> String {
+        expressions
+            .iter()
+            .map(|expression| self.operand(expression, TERNARY, indent))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Returns the code text of an expression, in parentheses if it binds looser than `min_precedence`.
+    /// Circuit expressions are printed with their members at nesting level `indent + 1`.
+    fn operand(&self, expression: &Expression, min_precedence: u8, indent: usize) -> String {
+        let printed = match expression {
+            Expression::Access(access) => self.access(access, indent),
+            Expression::Binary(binary) => match binary_operator(binary.op) {
+                Some(operator) => {
+                    let precedence = binary_precedence(binary.op);
+                    let (left, right) = match binary.op {
+                        // Exponentiation is right associative.
+                        BinaryOperation::Pow => (UNARY, precedence),
+                        // Comparisons do not associate.
+                        BinaryOperation::Eq
+                        | BinaryOperation::Neq
+                        | BinaryOperation::Lt
+                        | BinaryOperation::Lte
+                        | BinaryOperation::Gt
+                        | BinaryOperation::Gte => (precedence + 1, precedence + 1),
+                        _ => (precedence, precedence + 1),
+                    };
+                    format!(
+                        "{} {} {}",
+                        self.operand(&binary.left, left, indent),
+                        operator,
+                        self.operand(&binary.right, right, indent)
+                    )
+                }
+                None => format!(
+                    "{}.{}({})",
+                    self.operand(&binary.left, POSTFIX, indent),
+                    binary_method(binary.op),
+                    self.operand(&binary.right, TERNARY, indent)
+                ),
+            },
+            Expression::Call(call) => format!(
+                "{}({})",
+                self.operand(&call.function, POSTFIX, indent),
+                self.expressions(&call.arguments, indent)
+            ),
+            Expression::Circuit(circuit) => self.circuit_init(circuit, indent),
+            Expression::Err(_) => self.error(),
+            Expression::Identifier(identifier) => identifier.to_string(),
+            Expression::Literal(Literal::String(string, _)) => format!("\"{}\"", string),
+            Expression::Literal(literal) => literal.to_string(),
+            Expression::Ternary(ternary) => format!(
+                "{} ? {} : {}",
+                self.operand(&ternary.condition, TERNARY + 1, indent),
+                self.operand(&ternary.if_true, TERNARY, indent),
+                self.operand(&ternary.if_false, TERNARY, indent)
+            ),
+            Expression::Tuple(tuple) if tuple.elements.len() == 1 => {
+                format!("({},)", self.operand(&tuple.elements[0], TERNARY, indent))
+            }
+            Expression::Tuple(tuple) => format!("({})", self.expressions(&tuple.elements, indent)),
+            Expression::Unary(unary) => match unary_operator(unary.op) {
+                Some(operator) => format!("{}{}", operator, self.operand(&unary.receiver, UNARY, indent)),
+                None => format!(
+                    "{}.{}()",
+                    self.operand(&unary.receiver, POSTFIX, indent),
+                    unary_method(unary.op)
+                ),
+            },
+        };
+
+        if precedence(expression) < min_precedence {
+            format!("({})", printed)
+        } else {
+            printed
+        }
+    }
+
+    /// Returns the code text of an access expression.
+    fn access(&self, access: &AccessExpression, indent: usize) -> String {
+        match access {
+            AccessExpression::AssociatedConstant(constant) => {
+                format!("{}::{}", self.type_(&constant.ty), constant.name)
+            }
+            AccessExpression::AssociatedFunction(function) => format!(
+                "{}::{}({})",
+                self.type_(&function.ty),
+                function.name,
+                self.expressions(&function.args, indent)
+            ),
+            AccessExpression::Member(member) => {
+                format!("{}.{}", self.operand(&member.inner, POSTFIX, indent), member.name)
+            }
+            AccessExpression::Tuple(tuple) => {
+                format!("{}.{}", self.operand(&tuple.tuple, POSTFIX, indent), tuple.index)
+            }
+        }
+    }
+
+    /// Returns the code text of a circuit expression with one member per line, each followed by a comma.
+    fn circuit_init(&self, circuit: &CircuitExpression, indent: usize) -> String {
+        if circuit.members.is_empty() {
+            return format!("{} {{}}", circuit.name);
+        }
+
+        let mut printed = format!("{} {{\n", circuit.name);
+        for member in &circuit.members {
+            printed.push_str(&INDENT.repeat(indent + 1));
+            printed.push_str(&member.identifier.to_string());
+            if let Some(expression) = &member.expression {
+                printed.push_str(": ");
+                printed.push_str(&self.operand(expression, TERNARY, indent + 1));
+            }
+            printed.push_str(",\n");
+        }
+        printed.push_str(&INDENT.repeat(indent));
+        printed.push('}');
+        printed
+    }
+}
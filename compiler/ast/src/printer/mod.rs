@@ -0,0 +1,223 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! This module contains the [`Printer`], which prints an AST back into Leo code text.
+//!
+//! The printed code text parses back into the same AST, apart from spans.
+//! Given the source code text of the AST and its comments,
+//! the printer also keeps the comments and blank lines of the source code text.
+
+use crate::*;
+use leo_span::span::{BytePos, Pos};
+use leo_span::Span;
+
+mod expression;
+mod program;
+mod statement;
+
+/// The indentation of one nesting level.
+const INDENT: &str = "    ";
+
+/// A comment of Leo code text, which is not part of the AST.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Comment {
+    /// The text of the comment, including its `//` or `/* */` delimiters.
+    pub text: String,
+    pub span: Span,
+}
+
+/// Prints an AST into Leo code text.
+///
+/// Comments are printed in between the items, circuit members,
+/// and statements that surround them in the source code text.
+#[derive(Default)]
+pub struct Printer<'a> {
+    /// The source code text of the AST and the position of its first byte, if known.
+    source: Option<(&'a str, BytePos)>,
+    /// All comments of the source code text, in order.
+    comments: Vec<Comment>,
+    /// The index of the first comment that has not been printed yet.
+    next_comment: usize,
+    /// The current nesting level.
+    indent: usize,
+    /// The end of the last line printed at the current nesting level, if any.
+    /// Used to carry blank lines over from the source code text.
+    prev_end: Option<BytePos>,
+    /// Whether the next line must be preceded by a blank line.
+    force_blank_line: bool,
+    /// Whether nodes with errors are a bug, as the printed code text must parse again.
+    /// Otherwise, they are printed as `<error>`, e.g., to display an AST recovered from syntax errors.
+    strict: bool,
+    /// The printed code text.
+    out: String,
+}
+
+impl<'a> Printer<'a> {
+    /// Returns a new printer that keeps the `comments` and blank lines of the source code text
+    /// `source`, whose first byte is at `start_pos`.
+    pub fn with_comments(source: &'a str, start_pos: BytePos, comments: Vec<Comment>) -> Self {
+        Self {
+            source: Some((source, start_pos)),
+            comments,
+            strict: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the code text of a program, followed by any comments after its last item.
+    pub fn print_program(mut self, program: &Program) -> String {
+        self.write_program(program);
+        self.out
+    }
+
+    /// Returns the code text of a function.
+    pub(crate) fn print_function(mut self, function: &Function) -> String {
+        self.write_function(function);
+        self.out
+    }
+
+    /// Returns the code text of a statement, ending with a newline.
+    pub fn print_statement(mut self, statement: &Statement) -> String {
+        self.write_statement(statement);
+        self.out
+    }
+
+    /// Returns the code text of an expression.
+    pub fn print_expression(self, expression: &Expression) -> String {
+        self.expression(expression)
+    }
+
+    /// Returns the placeholder for a node with errors, which is a bug if the printer is strict.
+    fn error(&self) -> String {
+        assert!(!self.strict, "code with errors cannot be formatted");
+        String::from("<error>")
+    }
+
+    /// Returns the source code text between `lo` and `hi`.
+    fn source_between(&self, lo: BytePos, hi: BytePos) -> &'a str {
+        match self.source {
+            Some((source, start_pos)) if lo <= hi => {
+                &source[lo.to_usize() - start_pos.to_usize()..hi.to_usize() - start_pos.to_usize()]
+            }
+            _ => "",
+        }
+    }
+
+    /// Returns `true` if there is a blank line between `lo` and `hi` in the source code text.
+    fn blank_line_between(&self, lo: BytePos, hi: BytePos) -> bool {
+        let lines = self.source_between(lo, hi).split('\n').collect::<Vec<_>>();
+        lines.len() > 2 && lines[1..lines.len() - 1].iter().any(|line| line.trim().is_empty())
+    }
+
+    /// Returns the comment that has not been printed yet and starts before `pos`, if any.
+    fn comment_before(&self, pos: BytePos) -> Option<&Comment> {
+        self.comments.get(self.next_comment).filter(|c| c.span.lo < pos)
+    }
+
+    /// Writes all comments that start before `pos` on their own lines.
+    fn write_comments_before(&mut self, pos: BytePos) {
+        while let Some(comment) = self.comment_before(pos) {
+            let (text, span) = (comment.text.clone(), comment.span);
+            self.next_comment += 1;
+
+            self.start_line(span.lo);
+            self.out.push_str(&text);
+            self.out.push('\n');
+            self.prev_end = Some(span.hi);
+        }
+    }
+
+    /// Starts a line for the code starting at `lo`, preceded by a blank line if there is one in the source code text.
+    fn start_line(&mut self, lo: BytePos) {
+        let blank_line = self.prev_end.map_or(false, |end| self.blank_line_between(end, lo));
+        if (blank_line || self.force_blank_line) && !self.out.is_empty() {
+            self.out.push('\n');
+        }
+        self.force_blank_line = false;
+        self.write_indent();
+    }
+
+    /// Ends the line of the code ending at `hi`,
+    /// keeping the comment that follows it on the same line in the source code text.
+    fn end_line(&mut self, hi: BytePos) {
+        self.prev_end = Some(hi);
+        if let Some(comment) = self.comments.get(self.next_comment) {
+            if comment.span.lo >= hi && !self.source_between(hi, comment.span.lo).contains('\n') {
+                self.out.push(' ');
+                self.out.push_str(&comment.text);
+                self.prev_end = Some(comment.span.hi);
+                self.next_comment += 1;
+            }
+        }
+        self.out.push('\n');
+    }
+
+    /// Writes doc comments on their own lines, before the code of the line that has been started.
+    /// With the source code text, they are written with its other comments instead.
+    fn write_docs(&mut self, docs: &[String]) {
+        if self.source.is_some() {
+            return;
+        }
+        for doc in docs {
+            if doc.is_empty() {
+                self.out.push_str("///\n");
+            } else {
+                self.out.push_str(&format!("/// {}\n", doc));
+            }
+            self.write_indent();
+        }
+    }
+
+    fn write_indent(&mut self) {
+        for _ in 0..self.indent {
+            self.out.push_str(INDENT);
+        }
+    }
+
+    /// Writes the lines of a `{ ... }` body ending at `hi`, using `write_lines` for its contents.
+    /// Empty bodies are written as `{}`.
+    fn write_body(&mut self, is_empty: bool, hi: BytePos, write_lines: impl FnOnce(&mut Self)) {
+        if is_empty && self.comment_before(hi).is_none() {
+            self.out.push_str("{}");
+            return;
+        }
+
+        self.out.push_str("{\n");
+        let outer_end = self.prev_end.take();
+        self.indent += 1;
+
+        write_lines(self);
+        self.write_comments_before(hi);
+
+        self.indent -= 1;
+        self.prev_end = outer_end;
+        self.write_indent();
+        self.out.push('}');
+    }
+
+    /// Returns the code text of a type.
+    fn type_(&self, type_: &Type) -> String {
+        match type_ {
+            Type::Identifier(identifier) => identifier.to_string(),
+            Type::Tuple(tuple) => format!(
+                "({})",
+                tuple.0.iter().map(|t| self.type_(t)).collect::<Vec<_>>().join(", ")
+            ),
+            Type::Err => self.error(),
+            type_ => type_.to_string(),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// An item of a program, in the order of the source code text.
+enum Item<'a> {
+    Import(&'a Identifier),
+    Circuit(&'a Circuit),
+    Function(&'a Function),
+}
+
+impl Item<'_> {
+    fn span(&self) -> Span {
+        match self {
+            Item::Import(name) => name.span,
+            Item::Circuit(circuit) => circuit.span,
+            Item::Function(function) => function.span,
+        }
+    }
+}
+
+impl Printer<'_> {
+    /// Writes the items of a [`Program`], separated by blank lines, followed by any trailing comments.
+    pub(super) fn write_program(&mut self, program: &Program) {
+        let mut items = program
+            .imports
+            .keys()
+            .map(Item::Import)
+            .chain(program.circuits.values().map(Item::Circuit))
+            .chain(program.functions.values().map(Item::Function))
+            .collect::<Vec<_>>();
+        items.sort_by_key(|item| item.span().lo);
+
+        let mut previous: Option<&Item> = None;
+        for item in &items {
+            // Consecutive imports are kept together.
+            self.force_blank_line = !matches!((previous, item), (None, _) | (Some(Item::Import(_)), Item::Import(_)));
+            previous = Some(item);
+
+            let span = item.span();
+            self.write_comments_before(span.lo);
+            self.start_line(span.lo);
+            match item {
+                Item::Import(name) => self.out.push_str(&format!("import {}.leo;", name)),
+                Item::Circuit(circuit) => self.write_circuit(circuit),
+                Item::Function(function) => self.write_function(function),
+            }
+            self.end_line(span.hi);
+        }
+
+        // Write the comments after the last item.
+        self.write_comments_before(BytePos(u32::MAX));
+    }
+
+    /// Writes a circuit or record definition with one member per line.
+    pub(super) fn write_circuit(&mut self, circuit: &Circuit) {
+        self.write_docs(&circuit.docs);
+        let keyword = if circuit.is_record { "record" } else { "circuit" };
+        self.out.push_str(&format!("{} {} ", keyword, circuit.identifier));
+
+        self.write_body(circuit.members.is_empty(), circuit.span.hi, |p| {
+            for member in &circuit.members {
+                match member {
+                    CircuitMember::CircuitVariable(name, type_, docs) => {
+                        p.write_comments_before(name.span.lo);
+                        p.start_line(name.span.lo);
+                        p.write_docs(docs);
+                        p.out.push_str(&format!("{}: {},", name, p.type_(type_)));
+                        p.end_line(name.span.hi);
+                    }
+                }
+            }
+        });
+    }
+
+    /// Writes a function's doc comments and annotations, one per line, followed by its signature and body.
+    pub(super) fn write_function(&mut self, function: &Function) {
+        self.write_docs(&function.docs);
+        for annotation in &function.annotations {
+            self.out.push_str(&annotation.to_string());
+            self.end_line(annotation.span.hi);
+            self.write_comments_before(function.identifier.span.lo);
+            self.start_line(function.identifier.span.lo);
+        }
+
+        let inputs = function
+            .input
+            .iter()
+            .map(|input| {
+                let input = input.get_variable();
+                let mode = match input.mode() {
+                    ParamMode::Const => "constant ",
+                    ParamMode::Private => "",
+                    ParamMode::Public => "public ",
+                };
+                format!("{}{}: {}", mode, input.identifier, self.type_(&input.type_))
+            })
+            .collect::<Vec<_>>();
+
+        self.out.push_str(&format!(
+            "function {}({}) -> {} ",
+            function.identifier,
+            inputs.join(", "),
+            self.type_(&function.output)
+        ));
+        self.write_block(&function.block);
+    }
+}
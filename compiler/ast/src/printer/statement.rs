@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl Printer<'_> {
+    /// Writes a block with one statement per line.
+    pub(super) fn write_block(&mut self, block: &Block) {
+        self.write_body(block.statements.is_empty(), block.span.hi, |p| {
+            for statement in &block.statements {
+                p.write_statement(statement);
+            }
+        });
+    }
+
+    /// Writes a statement on its own lines.
+    pub(super) fn write_statement(&mut self, statement: &Statement) {
+        let span = statement.span();
+        match statement {
+            // Comments within a compound statement are printed within its blocks.
+            Statement::Conditional(_) | Statement::Iteration(_) | Statement::Block(_) => {
+                self.write_comments_before(span.lo)
+            }
+            _ => self.write_comments_before(span.hi),
+        }
+        self.start_line(span.lo);
+
+        match statement {
+            Statement::Return(ret) => {
+                let expression = self.expression(&ret.expression);
+                self.out.push_str(&format!("return {};", expression));
+            }
+            Statement::Definition(definition) => {
+                let value = self.expression(&definition.value);
+                self.out.push_str(&format!(
+                    "{} {}: {} = {};",
+                    definition.declaration_type,
+                    definition.variable_name,
+                    self.type_(&definition.type_),
+                    value
+                ));
+            }
+            Statement::Assign(assign) => {
+                let (place, value) = (self.expression(&assign.place), self.expression(&assign.value));
+                self.out
+                    .push_str(&format!("{} {} {};", place, assign.operation.as_ref(), value));
+            }
+            Statement::Conditional(conditional) => self.write_conditional(conditional),
+            Statement::Iteration(iteration) => {
+                let start = self.expression(&iteration.start);
+                let stop = self.condition(&iteration.stop);
+                self.out.push_str(&format!(
+                    "for {}: {} in {}..{} ",
+                    iteration.variable,
+                    self.type_(&iteration.type_),
+                    start,
+                    stop
+                ));
+                self.write_block(&iteration.block);
+            }
+            Statement::Console(console) => {
+                let function = match &console.function {
+                    ConsoleFunction::Assert(expression) => format!("assert({})", self.expression(expression)),
+                    ConsoleFunction::Error(args) => format!("error({})", self.console_args(args)),
+                    ConsoleFunction::Log(args) => format!("log({})", self.console_args(args)),
+                };
+                self.out.push_str(&format!("console.{};", function));
+            }
+            Statement::Block(block) => self.write_block(block),
+        }
+
+        self.end_line(span.hi);
+    }
+
+    /// Writes an `if` statement and its `else` branches.
+    fn write_conditional(&mut self, conditional: &ConditionalStatement) {
+        let condition = self.condition(&conditional.condition);
+        self.out.push_str(&format!("if {} ", condition));
+        self.write_block(&conditional.block);
+
+        match conditional.next.as_deref() {
+            Some(Statement::Block(block)) => {
+                self.out.push_str(" else ");
+                self.write_block(block);
+            }
+            Some(Statement::Conditional(conditional)) => {
+                self.out.push_str(" else ");
+                self.write_conditional(conditional);
+            }
+            // The parser only puts blocks and conditionals after `else`, so this is a hand-made AST.
+            Some(statement) => {
+                self.out.push_str(" else ");
+                self.write_block(&Block {
+                    statements: vec![statement.clone()],
+                    span: statement.span(),
+                });
+            }
+            None => {}
+        }
+    }
+
+    /// Returns the code text of the arguments of a `console.log` or `console.error` call.
+    fn console_args(&self, args: &ConsoleArgs) -> String {
+        std::iter::once(format!("\"{}\"", args.string))
+            .chain(args.parameters.iter().map(|parameter| self.expression(parameter)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
@@ -17,7 +17,7 @@
 //! A Leo program consists of import, circuit, and function definitions.
 //! Each defined type consists of ast statements and expressions.
 
-use crate::{Circuit, Function, FunctionInput, Identifier};
+use crate::{Circuit, Function, FunctionInput, Identifier, Printer};
 
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
@@ -34,6 +34,7 @@ pub struct Program {
     /// Empty after parsing.
     pub expected_input: Vec<FunctionInput>,
     /// A map from import names to import definitions.
+    /// The definitions are empty after parsing, until the imported programs are resolved.
     pub imports: IndexMap<Identifier, Program>,
     /// A map from function names to function definitions.
     pub functions: IndexMap<Identifier, Function>,
@@ -43,18 +44,7 @@ pub struct Program {
 
 impl fmt::Display for Program {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for (id, _import) in self.imports.iter() {
-            writeln!(f, "import {}.leo;", id)?;
-        }
-        for (_, function) in self.functions.iter() {
-            function.fmt(f)?;
-            writeln!(f,)?;
-        }
-        for (_, circuit) in self.circuits.iter() {
-            circuit.fmt(f)?;
-            writeln!(f,)?;
-        }
-        write!(f, "")
+        f.write_str(&Printer::default().print_program(self))
     }
 }
 
@@ -14,9 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{GroupLiteral, Identifier, Literal, Type};
+use crate::{GroupLiteral, Identifier, InputValue, Literal, Type};
 
-use leo_errors::{type_name, FlattenError, LeoError, Result};
+use leo_errors::{type_name, AstError, FlattenError, InputError, LeoError, Result};
 use leo_span::{Span, Symbol};
 
 use indexmap::IndexMap;
@@ -70,9 +70,7 @@ macro_rules! implement_const_unary {
             l: $logic:expr
         ]),+]
     ) => {
-        // TODO: This is temporary since the currently unused code is used in constant folding.
-        #[allow(dead_code)]
-        pub(crate) fn $name(self, span: Span) -> Result<Self> {
+        pub fn $name(self, span: Span) -> Result<Self> {
             use Value::*;
 
             match self {
@@ -156,9 +154,7 @@ macro_rules! implement_const_binary {
             logic: $logic:expr
         ]),+]
     ) => {
-        // This is temporary since the currently unused code is used in constant folding.
-        #[allow(dead_code)]
-        pub(crate) fn $name(self, other: Self, span: Span) -> Result<Self> {
+        pub fn $name(self, other: Self, span: Span) -> Result<Self> {
             use Value::*;
 
             match (self, other) {
@@ -198,6 +194,7 @@ pub enum Value {
     U128(u128, Span),
     Scalar(String, Span),
     String(String, Span),
+    Tuple(Vec<Value>),
 }
 
 impl Value {
@@ -728,7 +725,13 @@ impl Display for Value {
         match self {
             Input(type_, ident) => write!(f, "input var {}: {type_}", ident.name),
             Address(val, _) => write!(f, "{val}"),
-            Circuit(val, _) => write!(f, "{}", val.name),
+            Circuit(val, members) => {
+                let members = members
+                    .iter()
+                    .map(|(name, value)| format!("{name}: {value}"))
+                    .collect::<Vec<_>>();
+                write!(f, "{} {{ {} }}", val.name, members.join(", "))
+            }
             Boolean(val, _) => write!(f, "{val}"),
             Field(val, _) => write!(f, "{val}"),
             Group(val) => write!(f, "{val}"),
@@ -744,6 +747,10 @@ impl Display for Value {
             U128(val, _) => write!(f, "{val}"),
             Scalar(val, _) => write!(f, "{val}"),
             String(val, _) => write!(f, "{val}"),
+            Tuple(values) => {
+                let values = values.iter().map(|value| value.to_string()).collect::<Vec<_>>();
+                write!(f, "({})", values.join(", "))
+            }
         }
     }
 }
@@ -860,6 +867,7 @@ impl From<&Value> for Type {
             U128(_, _) => Type::U128,
             Scalar(_, _) => Type::Scalar,
             String(_, _) => Type::String,
+            Tuple(values) => Type::Tuple(crate::Tuple(values.iter().map(Type::from).collect())),
         }
     }
 }
@@ -890,14 +898,43 @@ impl From<&Literal> for Value {
     }
 }
 
-impl From<Value> for Literal {
-    fn from(v: Value) -> Self {
+impl TryFrom<&InputValue> for Value {
+    type Error = LeoError;
+
+    /// Converts an input value to a value, failing if an integer does not fit its type.
+    fn try_from(input: &InputValue) -> Result<Self> {
+        let span = Span::default();
+        let out_of_range =
+            |string: &String, type_| -> LeoError { InputError::integer_out_of_range(string, type_).into() };
+        Ok(match input {
+            InputValue::Address(string) => Self::Address(string.clone(), span),
+            InputValue::Boolean(bool) => Self::Boolean(*bool, span),
+            InputValue::Field(string) => Self::Field(string.clone(), span),
+            InputValue::Group(group_literal) => Self::Group(Box::new(group_literal.clone())),
+            InputValue::I8(string) => Self::I8(string.parse().map_err(|_| out_of_range(string, "i8"))?, span),
+            InputValue::I16(string) => Self::I16(string.parse().map_err(|_| out_of_range(string, "i16"))?, span),
+            InputValue::I32(string) => Self::I32(string.parse().map_err(|_| out_of_range(string, "i32"))?, span),
+            InputValue::I64(string) => Self::I64(string.parse().map_err(|_| out_of_range(string, "i64"))?, span),
+            InputValue::I128(string) => Self::I128(string.parse().map_err(|_| out_of_range(string, "i128"))?, span),
+            InputValue::U8(string) => Self::U8(string.parse().map_err(|_| out_of_range(string, "u8"))?, span),
+            InputValue::U16(string) => Self::U16(string.parse().map_err(|_| out_of_range(string, "u16"))?, span),
+            InputValue::U32(string) => Self::U32(string.parse().map_err(|_| out_of_range(string, "u32"))?, span),
+            InputValue::U64(string) => Self::U64(string.parse().map_err(|_| out_of_range(string, "u64"))?, span),
+            InputValue::U128(string) => Self::U128(string.parse().map_err(|_| out_of_range(string, "u128"))?, span),
+        })
+    }
+}
+
+impl TryFrom<Value> for Literal {
+    type Error = LeoError;
+
+    /// Converts a value to a literal, failing for inputs, circuits and tuples.
+    fn try_from(v: Value) -> Result<Self> {
         use Value::*;
-        match v {
-            Input(_, _) => todo!("We need to test if this is hittable"),
+        Ok(match v {
+            Input(..) | Circuit(..) | Tuple(_) => return Err(AstError::value_is_not_a_literal(v).into()),
             Address(v, span) => Literal::Address(v, span),
             Boolean(v, span) => Literal::Boolean(v, span),
-            Circuit(_ident, _values) => todo!("We need to test if this is hittable"),
             Field(v, span) => Literal::Field(v, span),
             Group(v) => Literal::Group(v),
             I8(v, span) => Literal::I8(v.to_string(), span),
@@ -912,6 +949,6 @@ impl From<Value> for Literal {
             U128(v, span) => Literal::U128(v.to_string(), span),
             Scalar(v, span) => Literal::Scalar(v, span),
             String(v, span) => Literal::String(v, span),
-        }
+        })
     }
 }
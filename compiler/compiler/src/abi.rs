@@ -0,0 +1,307 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The interface of a compiled Leo program, written next to its Aleo instructions as `build/<program>.abi.json`.
+//!
+//! The interface describes how to call the functions of the program, and how to read the records and circuits
+//! they take and return, without parsing the Aleo instructions. Its schema is versioned by [`ABI_VERSION`],
+//! which changes whenever a field is removed or its meaning changes. New fields may be added within a version.
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "program": "token.aleo",
+//!   "imports": ["credits.aleo"],
+//!   "records": [{
+//!     "name": "Token",
+//!     "members": [
+//!       { "name": "owner", "type": { "kind": "primitive", "name": "address" }, "visibility": "private" },
+//!       { "name": "gates", "type": { "kind": "primitive", "name": "u64" }, "visibility": "private" }
+//!     ]
+//!   }],
+//!   "circuits": [],
+//!   "functions": [{
+//!     "name": "mint",
+//!     "inputs": [
+//!       { "name": "owner", "mode": "public", "type": { "kind": "primitive", "name": "address" }, "visibility": "public" }
+//!     ],
+//!     "outputs": [
+//!       { "type": { "kind": "record", "name": "Token", "program": "token.aleo" }, "visibility": "record" }
+//!     ]
+//!   }]
+//! }
+//! ```
+
+use leo_ast::{Circuit, CircuitMember, Function, ParamMode, Program, Type};
+use leo_passes::SymbolTable;
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+use serde::{Deserialize, Serialize};
+
+/// The version of the schema of [`Abi`].
+pub const ABI_VERSION: u32 = 1;
+
+/// The interface of a program: its imports, records, circuits and functions, in the order of the source code.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Abi {
+    /// The version of the schema, [`ABI_VERSION`] when written by this compiler.
+    pub version: u32,
+    /// The program ID, e.g. `token.aleo`.
+    pub program: String,
+    /// The IDs of the programs imported by the program, e.g. `credits.aleo`.
+    pub imports: Vec<String>,
+    /// The records declared by the program.
+    pub records: Vec<AbiCircuit>,
+    /// The circuits declared by the program.
+    pub circuits: Vec<AbiCircuit>,
+    /// The functions of the program. The `@test` functions and the closures are not part of the interface.
+    pub functions: Vec<AbiFunction>,
+}
+
+/// The layout of a record or circuit.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbiCircuit {
+    /// The name of the record or circuit, as declared in Leo.
+    /// Aleo instructions use the lowercased name.
+    pub name: String,
+    /// The members, in the order of their declaration.
+    pub members: Vec<AbiMember>,
+}
+
+/// A member of a record or circuit.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbiMember {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: AbiType,
+    /// The visibility of the member of a record. Circuit members have none of their own.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+}
+
+/// The signature of a function.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbiFunction {
+    pub name: String,
+    /// The inputs, in the order of the parameters.
+    pub inputs: Vec<AbiInput>,
+    /// The outputs, one for each member of a returned tuple.
+    pub outputs: Vec<AbiOutput>,
+}
+
+/// An input of a function.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbiInput {
+    pub name: String,
+    /// The mode of the parameter in Leo: `const`, `private` or `public`.
+    pub mode: String,
+    #[serde(rename = "type")]
+    pub type_: AbiType,
+    /// The visibility of the input in the Aleo program.
+    pub visibility: Visibility,
+}
+
+/// An output of a function.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbiOutput {
+    #[serde(rename = "type")]
+    pub type_: AbiType,
+    /// The visibility of the output in the Aleo program.
+    pub visibility: Visibility,
+}
+
+/// The type of an input, output or member.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AbiType {
+    /// A type built into Leo, e.g. `u64` or `address`.
+    Primitive { name: String },
+    /// A record declared by `program`, which is the program itself or one of its imports.
+    Record { name: String, program: String },
+    /// A circuit declared by `program`, which is the program itself or one of its imports.
+    Circuit { name: String, program: String },
+    /// A tuple of types.
+    Tuple { elements: Vec<AbiType> },
+}
+
+/// The visibility of a value in the Aleo program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Visibility {
+    Constant,
+    Private,
+    Public,
+    /// The value is a record, which is encrypted for its owner.
+    Record,
+}
+
+impl Abi {
+    /// Returns the interface of the type checked `program` named `program_name` on the `network`.
+    /// The records and circuits that its types refer to are looked up in the `symbol_table`,
+    /// and the functions in `closures` are left out, since they cannot be called from outside the program.
+    pub fn new(
+        program_name: &str,
+        network: &str,
+        program: &Program,
+        symbol_table: &SymbolTable,
+        closures: &IndexSet<Symbol>,
+    ) -> Self {
+        let builder = Builder {
+            program_id: format!("{}.{}", program_name, network),
+            network,
+            program,
+            symbol_table,
+        };
+
+        let (records, circuits): (Vec<&Circuit>, Vec<&Circuit>) =
+            program.circuits.values().partition(|circuit| circuit.is_record);
+
+        Self {
+            version: ABI_VERSION,
+            program: builder.program_id.clone(),
+            imports: program.imports.keys().map(|name| builder.id(name.name)).collect(),
+            records: records.into_iter().map(|record| builder.circuit(record)).collect(),
+            circuits: circuits.into_iter().map(|circuit| builder.circuit(circuit)).collect(),
+            functions: program
+                .functions
+                .values()
+                .filter(|function| !function.is_test() && !closures.contains(&function.name()))
+                .map(|function| builder.function(function))
+                .collect(),
+        }
+    }
+}
+
+/// Converts the items of a program into their interface.
+struct Builder<'a> {
+    program_id: String,
+    network: &'a str,
+    program: &'a Program,
+    symbol_table: &'a SymbolTable,
+}
+
+impl Builder<'_> {
+    /// Returns the ID of the program `name`, e.g. `token.aleo`.
+    fn id(&self, name: Symbol) -> String {
+        format!("{}.{}", name, self.network)
+    }
+
+    fn circuit(&self, circuit: &Circuit) -> AbiCircuit {
+        // Record members are private. Circuit members have no visibility of their own.
+        let visibility = circuit.is_record.then(|| Visibility::Private);
+        AbiCircuit {
+            name: circuit.name().to_string(),
+            members: circuit
+                .members
+                .iter()
+                .map(|CircuitMember::CircuitVariable(name, type_, _)| AbiMember {
+                    name: name.to_string(),
+                    type_: self.type_(type_),
+                    visibility,
+                })
+                .collect(),
+        }
+    }
+
+    fn function(&self, function: &Function) -> AbiFunction {
+        let inputs = function
+            .input
+            .iter()
+            .map(|input| {
+                let input = input.get_variable();
+                AbiInput {
+                    name: input.identifier.to_string(),
+                    mode: input.mode().to_string(),
+                    type_: self.type_(&input.type_),
+                    visibility: self.visibility(&input.type_, input.mode()),
+                }
+            })
+            .collect();
+
+        // Outputs are private, and a returned tuple is one output for each of its members.
+        let outputs = match &function.output {
+            Type::Tuple(tuple) => tuple.0.iter().collect(),
+            type_ => vec![type_],
+        };
+        let outputs = outputs
+            .into_iter()
+            .map(|type_| AbiOutput {
+                type_: self.type_(type_),
+                visibility: self.visibility(type_, ParamMode::Private),
+            })
+            .collect();
+
+        AbiFunction {
+            name: function.name().to_string(),
+            inputs,
+            outputs,
+        }
+    }
+
+    /// Returns the visibility of a value of type `type_` passed with the given `mode`.
+    /// Records are always passed as records, and circuits privately.
+    fn visibility(&self, type_: &Type, mode: ParamMode) -> Visibility {
+        match type_ {
+            Type::Identifier(identifier) => match self.symbol_table.lookup_circuit(identifier.name) {
+                Some(circuit) if circuit.is_record => Visibility::Record,
+                _ => Visibility::Private,
+            },
+            _ => match mode {
+                ParamMode::Const => Visibility::Constant,
+                ParamMode::Private => Visibility::Private,
+                ParamMode::Public => Visibility::Public,
+            },
+        }
+    }
+
+    fn type_(&self, type_: &Type) -> AbiType {
+        match type_ {
+            Type::Identifier(identifier) => {
+                let name = identifier.to_string();
+                let program = match declaring_program(self.program, identifier.name) {
+                    Some(import) => self.id(import),
+                    None => self.program_id.clone(),
+                };
+                match self.symbol_table.lookup_circuit(identifier.name) {
+                    Some(circuit) if circuit.is_record => AbiType::Record { name, program },
+                    _ => AbiType::Circuit { name, program },
+                }
+            }
+            Type::Tuple(tuple) => AbiType::Tuple {
+                elements: tuple.0.iter().map(|type_| self.type_(type_)).collect(),
+            },
+            _ => AbiType::Primitive {
+                name: type_.to_string(),
+            },
+        }
+    }
+}
+
+/// Returns the name of the import of `program` that declares the record or circuit `name`,
+/// or `None` if `program` declares it itself.
+fn declaring_program(program: &Program, name: Symbol) -> Option<Symbol> {
+    if program.circuits.keys().any(|identifier| identifier.name == name) {
+        return None;
+    }
+    program.imports.iter().find_map(|(import_name, import)| {
+        if import.circuits.keys().any(|identifier| identifier.name == name) {
+            Some(import_name.name)
+        } else {
+            declaring_program(import, name)
+        }
+    })
+}
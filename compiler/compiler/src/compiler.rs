@@ -17,20 +17,24 @@
 //! The compiler for Leo programs.
 //!
 //! The [`Compiler`] type compiles Leo programs into R1CS circuits.
-use leo_ast::Program;
 pub use leo_ast::{Ast, InputAst};
+use leo_ast::{Identifier, Program};
 use leo_errors::emitter::Handler;
 use leo_errors::{CompilerError, Result};
-pub use leo_passes::SymbolTable;
 use leo_passes::*;
+pub use leo_passes::{InstructionSpan, SymbolTable};
 use leo_span::source_map::FileName;
 use leo_span::symbol::with_session_globals;
+use leo_span::Symbol;
 
+use indexmap::{IndexMap, IndexSet};
 use sha2::{Digest, Sha256};
+use snarkvm::prelude::{Network, Parser, Program as AleoProgram};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
-use crate::OutputOptions;
+use crate::{Abi, OutputOptions};
 
 /// The primary entry point of the Leo compiler.
 #[derive(Clone)]
@@ -49,6 +53,10 @@ pub struct Compiler<'a> {
     pub ast: Ast,
     /// The input ast for the program if it exists.
     pub input_ast: Option<InputAst>,
+    /// The imports of the program that have been filled in with the imported programs.
+    resolved_imports: IndexSet<Symbol>,
+    /// The functions and circuits included from libraries.
+    included: IndexSet<Symbol>,
     /// Compiler options on some optional output files.
     output_options: OutputOptions,
 }
@@ -71,6 +79,8 @@ impl<'a> Compiler<'a> {
             network,
             ast: Ast::new(Program::default()),
             input_ast: None,
+            resolved_imports: IndexSet::new(),
+            included: IndexSet::new(),
             output_options: output_options.unwrap_or_default(),
         }
     }
@@ -108,7 +118,10 @@ impl<'a> Compiler<'a> {
             }
         }
 
+        // The imports of the new program are yet to be resolved.
         self.ast = ast;
+        self.resolved_imports.clear();
+        self.included.clear();
 
         Ok(())
     }
@@ -122,6 +135,139 @@ impl<'a> Compiler<'a> {
         self.parse_program_from_string(&program_string, FileName::Real(self.main_file_path.clone()))
     }
 
+    /// Fills in the imports of the parsed program with the given, separately parsed, programs.
+    /// Imports without a matching program are left empty, and fail the compiler stages until they are resolved.
+    pub fn resolve_imports(&mut self, programs: &IndexMap<Symbol, Program>) {
+        let mut program = std::mem::take(&mut self.ast).into_repr();
+        for (identifier, import) in program.imports.iter_mut() {
+            if let Some(resolved) = programs.get(&identifier.name) {
+                *import = resolved.clone();
+                self.resolved_imports.insert(identifier.name);
+            }
+        }
+        self.ast = Ast::new(program);
+    }
+
+    /// Resolves the imports of the parsed program from the `.leo` files in `imports_directory`,
+    /// which are parsed, resolved the same way, and type checked.
+    pub fn resolve_imports_from(&mut self, imports_directory: &Path) -> Result<()> {
+        let mut programs = IndexMap::new();
+        self.parse_imports(imports_directory, &mut programs, &mut Vec::new())?;
+        self.resolve_imports(&programs);
+        Ok(())
+    }
+
+    /// Parses the unresolved imports of the program into `programs`, each after the programs it imports.
+    /// The `stack` holds the imports currently being parsed, to report cycles.
+    fn parse_imports(
+        &self,
+        imports_directory: &Path,
+        programs: &mut IndexMap<Symbol, Program>,
+        stack: &mut Vec<Symbol>,
+    ) -> Result<()> {
+        let imports: Vec<Identifier> = self.ast.as_repr().imports.keys().cloned().collect();
+        for import in imports {
+            if self.resolved_imports.contains(&import.name) || programs.contains_key(&import.name) {
+                continue;
+            }
+
+            if let Some(position) = stack.iter().position(|name| *name == import.name) {
+                let cycle = stack[position..]
+                    .iter()
+                    .chain(std::iter::once(&import.name))
+                    .map(|name| format!("{}.leo", name))
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+                return Err(CompilerError::cyclic_import(cycle, import.span).into());
+            }
+
+            let import_file_path = imports_directory.join(format!("{}.leo", import.name));
+            if !import_file_path.exists() {
+                return Err(CompilerError::import_not_found(import_file_path.display(), import.span).into());
+            }
+
+            let mut compiler = Compiler::new(
+                import.name.to_string(),
+                self.network.clone(),
+                self.handler,
+                import_file_path,
+                self.output_directory.clone(),
+                None,
+            );
+            compiler.parse_program()?;
+
+            stack.push(import.name);
+            compiler.parse_imports(imports_directory, programs, stack)?;
+            stack.pop();
+            compiler.resolve_imports(programs);
+
+            // The imported program is not compiled on its own, so check it here.
+            compiler.clone().compiler_stages()?;
+            programs.insert(import.name, compiler.ast.into_repr());
+        }
+        Ok(())
+    }
+
+    /// Returns the `imports/` directory of the package whose `src/` directory holds the main file.
+    fn imports_directory(&self) -> PathBuf {
+        let package_path = self.main_file_path.parent().and_then(Path::parent);
+        package_path.unwrap_or_else(|| Path::new("")).join("imports")
+    }
+
+    /// Replaces the import of the library `name` with the library's circuits, records and functions.
+    /// Libraries are not compiled on their own, so their definitions become part of the importing program,
+    /// and their functions become closures of it.
+    pub fn include_library(&mut self, name: Symbol, library: &Program) -> Result<()> {
+        // The program may not redefine the names it includes from the library.
+        let library_names: IndexSet<Symbol> = library
+            .circuits
+            .keys()
+            .chain(library.functions.keys())
+            .map(|identifier| identifier.name)
+            .collect();
+        let program = self.ast.as_repr();
+        if let Some(identifier) = program
+            .circuits
+            .keys()
+            .chain(program.functions.keys())
+            .find(|identifier| library_names.contains(&identifier.name))
+        {
+            return Err(CompilerError::library_name_conflict(identifier, name, identifier.span).into());
+        }
+
+        let mut program = std::mem::take(&mut self.ast).into_repr();
+        program.imports.retain(|identifier, _| identifier.name != name);
+        for (identifier, import) in library.imports.iter() {
+            if !program.imports.keys().any(|existing| existing.name == identifier.name) {
+                program.imports.insert(*identifier, import.clone());
+            }
+        }
+
+        self.included.extend(library_names);
+
+        // Library definitions come first, so that the program's definitions may refer to them.
+        let mut circuits = library.circuits.clone();
+        circuits.extend(program.circuits);
+        program.circuits = circuits;
+        let mut functions = library.functions.clone();
+        functions.extend(program.functions);
+        program.functions = functions;
+
+        self.ast = Ast::new(program);
+        Ok(())
+    }
+
+    /// Returns the functions included from libraries, which are generated as closures of the program.
+    pub fn closures(&self) -> IndexSet<Symbol> {
+        self.ast
+            .as_repr()
+            .functions
+            .keys()
+            .map(|identifier| identifier.name)
+            .filter(|name| self.included.contains(name))
+            .collect()
+    }
+
     /// Parses and stores the input file, constructs a syntax tree, and generates a program input.
     pub fn parse_input(&mut self, input_file_path: PathBuf) -> Result<()> {
         if input_file_path.exists() {
@@ -159,6 +305,12 @@ impl<'a> Compiler<'a> {
         TypeChecker::do_pass((&self.ast, self.handler, symbol_table))
     }
 
+    /// Runs the linting pass.
+    /// The definitions included from libraries are linted with their library.
+    pub fn lint_pass(&self, symbol_table: &SymbolTable) -> Result<()> {
+        Linter::do_pass((&self.ast, self.handler, symbol_table, &self.included))
+    }
+
     /// Runs the loop unrolling pass.
     pub fn loop_unrolling_pass(&mut self, symbol_table: SymbolTable) -> Result<SymbolTable> {
         let (ast, symbol_table) = Unroller::do_pass((std::mem::take(&mut self.ast), self.handler, symbol_table))?;
@@ -173,9 +325,25 @@ impl<'a> Compiler<'a> {
 
     /// Runs the compiler stages.
     pub fn compiler_stages(&mut self) -> Result<SymbolTable> {
+        // The parser leaves the imported programs empty, see `resolve_imports`.
+        if let Some(import) = self
+            .ast
+            .as_repr()
+            .imports
+            .keys()
+            .find(|import| !self.resolved_imports.contains(&import.name))
+        {
+            return Err(CompilerError::unresolved_import(import, import.span).into());
+        }
+
         let st = self.symbol_table_pass()?;
         let st = self.type_checker_pass(st)?;
 
+        // A program with syntax errors is checked, but not compiled any further.
+        self.handler.last_err()?;
+
+        self.lint_pass(&st)?;
+
         // TODO: Make this pass optional.
         let st = self.loop_unrolling_pass(st)?;
         Ok(st)
@@ -183,21 +351,99 @@ impl<'a> Compiler<'a> {
 
     /// Returns a compiled Leo program and prints the resulting bytecode.
     // TODO: Remove when code generation is ready to be integrated into the compiler.
-    pub fn compile_and_generate_instructions(&mut self) -> Result<(SymbolTable, String)> {
+    pub fn compile_and_generate_instructions(&mut self) -> Result<(SymbolTable, String, Vec<InstructionSpan>)> {
         self.parse_program()?;
+        self.resolve_imports_from(&self.imports_directory())?;
+        self.generate_instructions()
+    }
+
+    /// Runs the compiler stages on the already parsed program and returns the resulting bytecode,
+    /// with the spans of the Leo code that its instructions were generated for.
+    pub fn generate_instructions(&mut self) -> Result<(SymbolTable, String, Vec<InstructionSpan>)> {
         let symbol_table = self.compiler_stages()?;
 
-        let bytecode = CodeGenerator::do_pass((&self.ast, self.handler))?;
+        let (bytecode, instruction_spans) = CodeGenerator::do_pass((
+            &self.ast,
+            self.handler,
+            self.output_options.source_comments,
+            self.output_options.test_functions,
+            &self.closures(),
+        ))?;
 
-        Ok((symbol_table, bytecode))
+        Ok((symbol_table, bytecode, instruction_spans))
+    }
+
+    /// Parses the Aleo instructions generated for the program with snarkVM, for the network `N`.
+    /// An invalid instruction points to the Leo code it was generated for, or else to the Leo function or circuit containing it.
+    pub fn check_instructions<N: Network>(
+        &self,
+        instructions: &str,
+        instruction_spans: &[InstructionSpan],
+    ) -> Result<()> {
+        // snarkVM's reason for rejecting the program.
+        let reason = match AleoProgram::<N>::from_str(instructions) {
+            Ok(_) => return Ok(()),
+            Err(error) => error.to_string(),
+        };
+
+        // snarkVM stops parsing at the invalid instruction, and returns the rest of the program.
+        let offset = match AleoProgram::<N>::parse(instructions) {
+            Ok((remainder, _)) if !remainder.is_empty() => instructions.len() - remainder.len(),
+            _ => return Err(CompilerError::invalid_aleo_program(reason).into()),
+        };
+
+        // The invalid instruction is the line that contains the offset.
+        let line_start = instructions[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = instructions[offset..]
+            .find('\n')
+            .map_or(instructions.len(), |index| offset + index);
+        let instruction = instructions[line_start..line_end].trim();
+        let line = instructions[..line_start].matches('\n').count() + 1;
+        if let Some(instruction_span) = instruction_spans.iter().find(|instruction| instruction.line == line) {
+            return Err(CompilerError::invalid_aleo_instruction(instruction, &reason, instruction_span.span).into());
+        }
+
+        // The definition is the closest one that starts before the instruction, e.g. `function main:`.
+        let definition = instructions[..line_end].lines().rev().find_map(|line| {
+            let (keyword, name) = line.trim().strip_suffix(':')?.split_once(' ')?;
+            matches!(keyword, "function" | "closure" | "interface" | "record").then(|| name.trim().to_string())
+        });
+
+        // Circuits and records are lowercased in Aleo instructions.
+        let program = self.ast.as_repr();
+        let span = definition.and_then(|name| {
+            let function = program.functions.keys().find(|id| id.name.to_string() == name);
+            let circuit = program
+                .circuits
+                .keys()
+                .find(|id| id.name.to_string().to_lowercase() == name);
+            function.or(circuit).map(|id| id.span)
+        });
+
+        Err(match span {
+            Some(span) => CompilerError::invalid_aleo_instruction(instruction, reason, span).into(),
+            None => CompilerError::invalid_aleo_program(format!("{}: `{}`", reason, instruction)).into(),
+        })
     }
 
     /// Returns a compiled Leo program.
     pub fn compile(&mut self) -> Result<SymbolTable> {
         self.parse_program()?;
+        self.resolve_imports_from(&self.imports_directory())?;
         self.compiler_stages()
     }
 
+    /// Returns the interface of the compiled program, given the symbol table of its compilation.
+    pub fn abi(&self, symbol_table: &SymbolTable) -> Abi {
+        Abi::new(
+            &self.program_name,
+            &self.network,
+            self.ast.as_repr(),
+            symbol_table,
+            &self.closures(),
+        )
+    }
+
     /// Writes the AST to a JSON file.
     fn write_ast_to_json(&self, file_name: &str) -> Result<()> {
         // Remove `Span`s if they are not enabled.
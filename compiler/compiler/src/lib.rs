@@ -18,11 +18,17 @@
 #![allow(clippy::upper_case_acronyms)]
 #![doc = include_str!("../README.md")]
 
+pub mod abi;
+pub use abi::Abi;
+
 mod compiler;
 pub use compiler::*;
 
 mod options;
 pub use options::*;
 
+pub mod source_map;
+pub use source_map::{source_map, SourceMapEntry};
+
 #[cfg(test)]
 mod test;
@@ -24,4 +24,8 @@ pub struct OutputOptions {
     pub initial_input_ast: bool,
     /// If enabled writes the AST after loop unrolling.
     pub unrolled_ast: bool,
+    /// If enabled interleaves the lines of Leo code as comments into the generated Aleo instructions.
+    pub source_comments: bool,
+    /// If enabled generates Aleo instructions for the `@test` functions, which are left out otherwise.
+    pub test_functions: bool,
 }
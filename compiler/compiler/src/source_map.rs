@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The source map of a compiled Leo program, written next to its Aleo instructions as `build/<program>.aleo.map`.
+//!
+//! The source map lists the instructions that were generated for Leo code, in order, with the location of that code.
+//! Each instruction is named by its function and its index in the body of that function, which count the inputs and
+//! outputs of the function too. Other lines of the Aleo program, e.g. `function main:` or source comments, are left out.
+//!
+//! ```json
+//! [{
+//!   "function": "main",
+//!   "index": 2,
+//!   "line": 7,
+//!   "instruction": "add r0 r1 into r2;",
+//!   "span": { "file": "main.leo", "line_start": 2, "column_start": 17, "line_end": 2, "column_end": 22 }
+//! }]
+//! ```
+
+use leo_errors::emitter::DiagnosticSpan;
+use leo_passes::InstructionSpan;
+use leo_span::Symbol;
+
+use serde::Serialize;
+
+/// An instruction in the source map.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SourceMapEntry {
+    /// The function or closure containing the instruction.
+    pub function: String,
+    /// The index of the instruction in the body of the function, starting at 0.
+    pub index: usize,
+    /// The line of the instruction in the Aleo program, starting at 1.
+    pub line: usize,
+    /// The instruction.
+    pub instruction: String,
+    /// The location of the Leo code that the instruction was generated for.
+    pub span: Option<DiagnosticSpan>,
+}
+
+/// Returns the source map of the instructions generated for Leo code, given their spans.
+pub fn source_map(instruction_spans: &[InstructionSpan]) -> Vec<SourceMapEntry> {
+    instruction_spans
+        .iter()
+        .map(|instruction| SourceMapEntry {
+            function: instruction.function.to_string(),
+            index: instruction.index,
+            line: instruction.line,
+            instruction: instruction.instruction.clone(),
+            span: DiagnosticSpan::new(instruction.span),
+        })
+        .collect()
+}
+
+/// Returns the entry of the instruction at `index` in the body of `function`, if it was generated for Leo code.
+pub fn find_entry<'a>(source_map: &'a [SourceMapEntry], function: &str, index: usize) -> Option<&'a SourceMapEntry> {
+    source_map
+        .iter()
+        .find(|entry| entry.function == function && entry.index == index)
+}
+
+/// Returns the instruction of `function` that snarkVM reports as failing in `error`.
+/// snarkVM names the instruction as it is written in the Aleo program, e.g. `Failed to evaluate instruction (add r0 r1 into r2;)`.
+pub fn failed_instruction<'a>(
+    instruction_spans: &'a [InstructionSpan],
+    function: Symbol,
+    error: &str,
+) -> Option<&'a InstructionSpan> {
+    instruction_spans
+        .iter()
+        .filter(|instruction| instruction.function == function)
+        .find(|instruction| error.contains(&format!("({})", instruction.instruction)))
+}
@@ -21,20 +21,27 @@ use std::{
     rc::Rc,
 };
 
-use crate::{Compiler, OutputOptions};
+use crate::{
+    abi::{AbiType, Visibility, ABI_VERSION},
+    source_map::{find_entry, source_map},
+    Compiler, OutputOptions,
+};
 
 use leo_errors::{
-    emitter::{Buffer, Emitter, Handler},
+    emitter::{Buffer, Emitter, Handler, LintLevel, ALL_WARNINGS},
     LeoError, LeoWarning,
 };
 use leo_passes::SymbolTable;
-use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then, Symbol};
 use leo_test_framework::{
+    generator::{run_generated_tests, Config},
     runner::{Namespace, ParseType, Runner},
     Test,
 };
 use serde::{Deserialize, Serialize};
 use serde_yaml::Value;
+use snarkvm::prelude::{Program as AleoProgram, Testnet3};
+use std::str::FromStr;
 
 fn new_compiler(handler: &Handler, main_file_path: PathBuf) -> Compiler<'_> {
     let output_dir = PathBuf::from("/tmp/output/");
@@ -51,6 +58,8 @@ fn new_compiler(handler: &Handler, main_file_path: PathBuf) -> Compiler<'_> {
             initial_input_ast: true,
             initial_ast: true,
             unrolled_ast: true,
+            source_comments: false,
+            test_functions: false,
         }),
     )
 }
@@ -138,6 +147,7 @@ fn collect_all_inputs(test: &Test) -> Result<Vec<PathBuf>, String> {
 fn compile_and_process<'a>(parsed: &'a mut Compiler<'a>) -> Result<SymbolTable, LeoError> {
     let st = parsed.symbol_table_pass()?;
     let st = parsed.type_checker_pass(st)?;
+    parsed.lint_pass(&st)?;
     let st = parsed.loop_unrolling_pass(st)?;
     Ok(st)
 }
@@ -194,6 +204,19 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
         cwd.join(&val.as_str().unwrap())
     });
 
+    // Check for the warnings option:
+    // ``` warnings: deny ```
+    // When set, sets the lint level of all warnings, so that denied warnings are reported as errors.
+    if let Some(level) = test.config.get("warnings").and_then(|val| val.as_str()) {
+        let level = match level {
+            "allow" => LintLevel::Allow,
+            "warn" => LintLevel::Warn,
+            "deny" => LintLevel::Deny,
+            _ => panic!("unknown lint level `{}`", level),
+        };
+        handler.set_lint_level(ALL_WARNINGS, level).unwrap();
+    }
+
     let mut parsed = handler.extend_if_error(parse_program(handler, &test.content, cwd))?;
 
     // (name, content)
@@ -232,11 +255,87 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
     Ok(serde_yaml::to_value(&final_output).expect("serialization failed"))
 }
 
+/// Generates the Aleo instructions of a program and the source map of their instructions.
+/// The instructions are interleaved with the lines of Leo code if the test sets `source_comments: true`.
+struct BytecodeNamespace;
+
+impl Namespace for BytecodeNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        let (handler, buf) = Handler::new_with_buf();
+        let source_comments = test.config.get("source_comments").and_then(Value::as_bool) == Some(true);
+
+        create_session_if_not_set_then(|_| {
+            // snarkVM only accepts programs of the `aleo` network.
+            let mut compiler = Compiler::new(
+                String::from("test"),
+                String::from("aleo"),
+                &handler,
+                test.path.clone(),
+                PathBuf::from("/tmp/output/"),
+                Some(OutputOptions {
+                    source_comments,
+                    ..Default::default()
+                }),
+            );
+            let generated = compiler
+                .parse_program_from_string(&test.content, FileName::Custom("bytecode-test".into()))
+                .and_then(|_| compiler.generate_instructions());
+            let (bytecode, instruction_spans) = match generated {
+                Ok((_, bytecode, instruction_spans)) => (bytecode, instruction_spans),
+                Err(error) => {
+                    if !matches!(error, LeoError::LastErrorCode(_)) {
+                        handler.emit_err(error);
+                    }
+                    return Err(buf.extract_errs().to_string());
+                }
+            };
+
+            if bytecode.contains('\0') {
+                return Err(format!("the bytecode contains span markers\n{}", bytecode));
+            }
+            let lines = bytecode.lines().collect::<Vec<_>>();
+            for instruction in &instruction_spans {
+                if lines.get(instruction.line - 1).map(|line| line.trim()) != Some(instruction.instruction.as_str()) {
+                    return Err(format!(
+                        "`{}` is not on line {}",
+                        instruction.instruction, instruction.line
+                    ));
+                }
+            }
+
+            let source_map = source_map(&instruction_spans);
+            for entry in &source_map {
+                if find_entry(&source_map, &entry.function, entry.index) != Some(entry) {
+                    return Err(format!("`{}` {} is not a unique key", entry.function, entry.index));
+                }
+            }
+            let source_map = source_map
+                .iter()
+                .map(|entry| serde_json::to_string(entry).expect("failed to serialize the source map"))
+                .collect();
+            let output = BytecodeOutput { bytecode, source_map };
+            Ok(serde_yaml::to_value(&output).expect("serialization failed"))
+        })
+    }
+}
+
+#[derive(Deserialize, PartialEq, Eq, Serialize)]
+struct BytecodeOutput {
+    pub bytecode: String,
+    /// The entries of the source map, as JSON.
+    pub source_map: Vec<String>,
+}
+
 struct TestRunner;
 
 impl Runner for TestRunner {
     fn resolve_namespace(&self, name: &str) -> Option<Box<dyn Namespace>> {
         Some(match name {
+            "Bytecode" => Box::new(BytecodeNamespace),
             "Compile" => Box::new(CompileNamespace),
             _ => return None,
         })
@@ -247,3 +346,259 @@ impl Runner for TestRunner {
 pub fn compiler_tests() {
     leo_test_framework::run_tests(&TestRunner, "compiler");
 }
+
+const ABI_IMPORT: &str = "circuit Point {
+    x: u32,
+    y: u32,
+}
+";
+
+const ABI_PROGRAM: &str = "import shapes.leo;
+
+/// A token.
+record Token {
+    owner: address,
+    gates: u64,
+    origin: Point,
+}
+
+function mint(public owner: address, amount: u64, const scale: u8, origin: Point) -> (Token, u64) {
+    return (Token { owner: owner, gates: amount, origin: origin }, amount * 2u64);
+}
+
+@test
+function test_mint() -> bool {
+    return true;
+}
+";
+
+#[test]
+pub fn abi() {
+    let handler = Handler::default();
+    create_session_if_not_set_then(|_| {
+        let mut import = parse_program(&handler, ABI_IMPORT, None).unwrap();
+        import.compiler_stages().unwrap();
+        let mut program = parse_program(&handler, ABI_PROGRAM, None).unwrap();
+        let shapes = Symbol::intern("shapes");
+        program.resolve_imports(&[(shapes, import.ast.into_repr())].into_iter().collect());
+        let symbol_table = program.compiler_stages().unwrap();
+
+        let abi = program.abi(&symbol_table);
+        assert_eq!(abi.version, ABI_VERSION);
+        assert_eq!(abi.program, "test.testnet3");
+        assert_eq!(abi.imports, ["shapes.testnet3"]);
+        assert!(abi.circuits.is_empty());
+
+        let point = AbiType::Circuit {
+            name: "Point".to_string(),
+            program: "shapes.testnet3".to_string(),
+        };
+        let members = &abi.records[0].members;
+        assert_eq!(members.len(), 3);
+        assert_eq!(members[2].type_, point);
+        assert!(members
+            .iter()
+            .all(|member| member.visibility == Some(Visibility::Private)));
+
+        // The `@test` function is not part of the interface.
+        assert_eq!(abi.functions.len(), 1);
+        let mint = &abi.functions[0];
+        let inputs = mint
+            .inputs
+            .iter()
+            .map(|input| (input.name.as_str(), input.mode.as_str(), input.visibility))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            inputs,
+            [
+                ("owner", "public", Visibility::Public),
+                ("amount", "private", Visibility::Private),
+                ("scale", "const", Visibility::Constant),
+                ("origin", "private", Visibility::Private),
+            ]
+        );
+        assert_eq!(mint.inputs[3].type_, point);
+        let outputs = mint.outputs.iter().map(|output| output.visibility).collect::<Vec<_>>();
+        assert_eq!(outputs, [Visibility::Record, Visibility::Private]);
+        assert_eq!(
+            mint.outputs[0].type_,
+            AbiType::Record {
+                name: "Token".to_string(),
+                program: "test.testnet3".to_string(),
+            }
+        );
+    });
+}
+
+#[test]
+pub fn imports() {
+    let package = std::env::temp_dir().join(format!("leo-compiler-imports-{}", std::process::id()));
+    fs::create_dir_all(package.join("src")).unwrap();
+    fs::create_dir_all(package.join("imports")).unwrap();
+    fs::write(package.join("imports").join("shapes.leo"), ABI_IMPORT).unwrap();
+    let main_file_path = package.join("src").join("main.leo");
+    fs::write(&main_file_path, ABI_PROGRAM).unwrap();
+
+    let handler = Handler::default();
+    create_session_if_not_set_then(|_| {
+        // An import the caller did not resolve is an error, rather than an empty program.
+        let mut program = parse_program(&handler, ABI_PROGRAM, None).unwrap();
+        let error = program.compiler_stages().unwrap_err();
+        assert!(error.to_string().contains("The import `shapes.leo` was not resolved"));
+
+        // `compile` resolves the imports from the `imports/` directory of the package.
+        let mut program = new_compiler(&handler, main_file_path);
+        program.compile().unwrap();
+        let shapes = program.ast.as_repr().imports.values().next().unwrap();
+        assert_eq!(shapes.circuits.keys().next().unwrap().to_string(), "Point");
+
+        // Cyclic imports are reported.
+        fs::write(package.join("imports").join("shapes.leo"), "import test.leo;").unwrap();
+        fs::write(package.join("imports").join("test.leo"), ABI_PROGRAM).unwrap();
+        let error = program.compile().unwrap_err();
+        assert!(error.to_string().contains("shapes.leo -> test.leo -> shapes.leo"));
+    });
+
+    fs::remove_dir_all(&package).unwrap();
+}
+
+const LIBRARY: &str = "function double(a: u8) -> u8 {
+    return a + a;
+}
+";
+
+#[test]
+pub fn libraries() {
+    let handler = Handler::default();
+    create_session_if_not_set_then(|_| {
+        let math = Symbol::intern("math");
+        let mut library = parse_program(&handler, LIBRARY, None).unwrap();
+        library.compiler_stages().unwrap();
+        let library = library.ast.into_repr();
+
+        // The functions of a library are closures of the importing program, which are not part of its interface.
+        let source = "import math.leo;\n\nfunction main(a: u8) -> u8 {\n    return double(a);\n}\n";
+        let mut program = parse_program(&handler, source, None).unwrap();
+        program.include_library(math, &library).unwrap();
+        let (symbol_table, instructions, _) = program.generate_instructions().unwrap();
+        assert!(instructions.contains("closure double:\n    input r0 as u8;"));
+        assert!(instructions.contains("call double r0 into r1;"));
+        let abi = program.abi(&symbol_table);
+        assert_eq!(
+            abi.functions.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(),
+            ["main"]
+        );
+
+        // A program may not redefine the names it includes from a library.
+        let source = "import math.leo;\n\nfunction double(a: u8) -> u8 {\n    return a;\n}\n";
+        let mut program = parse_program(&handler, source, None).unwrap();
+        let error = program.include_library(math, &library).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("`double` is already defined by the imported library `math.leo`"));
+    });
+}
+
+/// Compiles a generated program to Aleo instructions, which snarkVM must parse.
+fn compile_generated_program(source: &str) -> Result<(), String> {
+    let (handler, buf) = Handler::new_with_buf();
+
+    // Each run writes its programs to its own directory, so that concurrent runs do not overwrite them.
+    let directory = std::env::temp_dir().join(format!("leo-generated-tests-{}", std::process::id()));
+    fs::create_dir_all(&directory).expect("failed to create the directory of generated programs");
+    let main_file_path = directory.join("main.leo");
+    fs::write(&main_file_path, source).expect("failed to write a generated program");
+
+    let result = create_session_if_not_set_then(|_| {
+        // snarkVM only accepts programs of the `aleo` network.
+        let mut compiler = Compiler::new(
+            String::from("generated"),
+            String::from("aleo"),
+            &handler,
+            main_file_path,
+            directory.clone(),
+            None,
+        );
+        let (instructions, instruction_spans) = match compiler.compile_and_generate_instructions() {
+            Ok((_, instructions, instruction_spans)) => (instructions, instruction_spans),
+            Err(error) => {
+                if !matches!(error, LeoError::LastErrorCode(_)) {
+                    handler.emit_err(error);
+                }
+                let errors = buf.extract_errs().to_string();
+                let code = errors.split(|c| c == '[' || c == ']').nth(1).unwrap_or_default();
+                return Err(format!("the compiler rejected the program with {}\n{}", code, errors));
+            }
+        };
+
+        match compiler.check_instructions::<Testnet3>(&instructions, &instruction_spans) {
+            Ok(_) => Ok(()),
+            Err(error) => {
+                handler.emit_err(error);
+                Err(format!(
+                    "snarkVM rejected the generated instructions\n{}\n{}",
+                    buf.extract_errs(),
+                    instructions
+                ))
+            }
+        }
+    });
+
+    let _ = fs::remove_dir_all(&directory);
+    result
+}
+
+#[test]
+pub fn generated_tests() {
+    // Code generation does not implement assignments, conditional statements and calls yet.
+    let config = Config {
+        assignments: false,
+        conditionals: false,
+        calls: false,
+        ..Default::default()
+    };
+    run_generated_tests(&config, 64, compile_generated_program);
+}
+
+const INVALID_INSTRUCTION_PROGRAM: &str = "function main(a: u8, b: u8) -> u8 {
+    let c: u8 = a + b;
+    return c;
+}
+";
+
+#[test]
+pub fn invalid_instruction() {
+    let (handler, buf) = Handler::new_with_buf();
+    create_session_if_not_set_then(|_| {
+        // snarkVM only accepts programs of the `aleo` network.
+        let mut compiler = Compiler::new(
+            String::from("test"),
+            String::from("aleo"),
+            &handler,
+            PathBuf::from("compiler-test"),
+            PathBuf::from("/tmp/output/"),
+            None,
+        );
+        compiler
+            .parse_program_from_string(INVALID_INSTRUCTION_PROGRAM, FileName::Custom("compiler-test".into()))
+            .unwrap();
+        let (_, instructions, instruction_spans) = compiler.generate_instructions().unwrap();
+        assert!(compiler
+            .check_instructions::<Testnet3>(&instructions, &instruction_spans)
+            .is_ok());
+
+        // An instruction that snarkVM cannot parse points to the Leo code it was generated for.
+        let instructions = instructions.replacen("add ", "INVALID ", 1);
+        let error = compiler
+            .check_instructions::<Testnet3>(&instructions, &instruction_spans)
+            .unwrap_err();
+        handler.emit_err(error);
+        let error = buf.extract_errs().to_string();
+        assert!(error.contains("The Aleo instruction `INVALID r0 r1 into r2;`"));
+        assert!(error.contains("--> compiler-test:2:17"));
+
+        // The reason is the one snarkVM gives.
+        let reason = AleoProgram::<Testnet3>::from_str(&instructions).unwrap_err();
+        assert!(error.contains(&format!("SnarkVM Error: {}", reason)));
+    });
+}
@@ -52,28 +52,31 @@ pub enum CoreInstruction {
 }
 
 impl CoreInstruction {
+    /// Every core instruction, with the symbols of its circuit and method.
+    pub const ALL: &'static [(Symbol, Symbol, Self)] = &[
+        (sym::BHP256, sym::commit, Self::BHP256Commit),
+        (sym::BHP256, sym::hash, Self::BHP256Hash),
+        (sym::BHP512, sym::commit, Self::BHP512Commit),
+        (sym::BHP512, sym::hash, Self::BHP512Hash),
+        (sym::BHP768, sym::commit, Self::BHP768Commit),
+        (sym::BHP768, sym::hash, Self::BHP768Hash),
+        (sym::BHP1024, sym::commit, Self::BHP1024Commit),
+        (sym::BHP1024, sym::hash, Self::BHP1024Hash),
+        (sym::Pedersen64, sym::commit, Self::Pedersen64Commit),
+        (sym::Pedersen64, sym::hash, Self::Pedersen64Hash),
+        (sym::Pedersen128, sym::commit, Self::Pedersen128Commit),
+        (sym::Pedersen128, sym::hash, Self::Pedersen128Hash),
+        (sym::Poseidon2, sym::hash, Self::Poseidon2Hash),
+        (sym::Poseidon4, sym::hash, Self::Poseidon4Hash),
+        (sym::Poseidon8, sym::hash, Self::Poseidon8Hash),
+    ];
+
     /// Returns a `CoreInstruction` from the given circuit and method symbols.
     pub fn from_symbols(circuit: Symbol, function: Symbol) -> Option<Self> {
-        Some(match (circuit, function) {
-            (sym::BHP256, sym::commit) => Self::BHP256Commit,
-            (sym::BHP256, sym::hash) => Self::BHP256Hash,
-            (sym::BHP512, sym::commit) => Self::BHP512Commit,
-            (sym::BHP512, sym::hash) => Self::BHP512Hash,
-            (sym::BHP768, sym::commit) => Self::BHP768Commit,
-            (sym::BHP768, sym::hash) => Self::BHP768Hash,
-            (sym::BHP1024, sym::commit) => Self::BHP1024Commit,
-            (sym::BHP1024, sym::hash) => Self::BHP1024Hash,
-
-            (sym::Pedersen64, sym::commit) => Self::Pedersen64Commit,
-            (sym::Pedersen64, sym::hash) => Self::Pedersen64Hash,
-            (sym::Pedersen128, sym::commit) => Self::Pedersen128Commit,
-            (sym::Pedersen128, sym::hash) => Self::Pedersen128Hash,
-
-            (sym::Poseidon2, sym::hash) => Self::Poseidon2Hash,
-            (sym::Poseidon4, sym::hash) => Self::Poseidon4Hash,
-            (sym::Poseidon8, sym::hash) => Self::Poseidon8Hash,
-            _ => return None,
-        })
+        Self::ALL
+            .iter()
+            .find(|(c, f, _)| *c == circuit && *f == function)
+            .map(|(_, _, instruction)| instruction.clone())
     }
 
     /// Returns the number of arguments required by the instruction.
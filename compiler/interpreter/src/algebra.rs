@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{BinaryOperation, GroupCoordinate, GroupLiteral, GroupTuple, UnaryOperation, Value};
+use leo_errors::{InterpreterError, Result};
+use leo_span::Span;
+
+use snarkvm::prelude::{Compare, Double, Field, Group, Inverse, Pow, Scalar, Square, SquareRoot, Testnet3, Zero};
+use std::{fmt, str::FromStr};
+
+pub(crate) type Network = Testnet3;
+
+/// A field, group or scalar value, whose arithmetic is computed by snarkVM.
+#[derive(Clone)]
+pub(crate) enum Element {
+    Field(Field<Network>),
+    Group(Group<Network>),
+    Scalar(Scalar<Network>),
+}
+
+impl Element {
+    /// Returns the element of a field, group or scalar value.
+    pub(crate) fn from_value(value: &Value, span: Span) -> Result<Self> {
+        let invalid = |error: anyhow::Error| InterpreterError::invalid_value(value, error, span);
+        Ok(match value {
+            Value::Field(string, _) => Self::Field(Field::from_str(&format!("{string}field")).map_err(invalid)?),
+            Value::Scalar(string, _) => Self::Scalar(Scalar::from_str(&format!("{string}scalar")).map_err(invalid)?),
+            Value::Group(literal) => match &**literal {
+                GroupLiteral::Single(string, _) => {
+                    Self::Group(Group::from_str(&format!("{string}group")).map_err(invalid)?)
+                }
+                GroupLiteral::Tuple(GroupTuple {
+                    x: GroupCoordinate::Number(x, _),
+                    y: GroupCoordinate::Number(y, _),
+                    ..
+                }) => {
+                    let x = Field::from_str(&format!("{x}field")).map_err(invalid)?;
+                    let y = Field::from_str(&format!("{y}field")).map_err(invalid)?;
+                    Self::Group(Group::from_xy_coordinates(x, y).map_err(invalid)?)
+                }
+                GroupLiteral::Tuple(_) => {
+                    return Err(InterpreterError::unsupported("group coordinates given by a sign", span).into())
+                }
+            },
+            _ => unreachable!("only fields, groups and scalars are elements"),
+        })
+    }
+
+    /// Returns the value of the element.
+    pub(crate) fn into_value(self, span: Span) -> Value {
+        match self {
+            Self::Field(field) => Value::Field(digits(&field), span),
+            Self::Group(group) => Value::Group(Box::new(GroupLiteral::Tuple(GroupTuple {
+                x: GroupCoordinate::Number(digits(&group.to_x_coordinate()), span),
+                y: GroupCoordinate::Number(digits(&group.to_y_coordinate()), span),
+                span,
+            }))),
+            Self::Scalar(scalar) => Value::Scalar(digits(&scalar), span),
+        }
+    }
+}
+
+impl fmt::Display for Element {
+    /// Formats the element as an Aleo literal, e.g. `1field`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Field(field) => write!(f, "{field}"),
+            Self::Group(group) => write!(f, "{group}"),
+            Self::Scalar(scalar) => write!(f, "{scalar}"),
+        }
+    }
+}
+
+/// Returns the digits of an Aleo literal without its type, e.g. `1` for `1field`.
+fn digits(literal: &impl fmt::Display) -> String {
+    literal
+        .to_string()
+        .trim_end_matches(|c: char| c.is_ascii_alphabetic())
+        .to_string()
+}
+
+/// Returns whether `value` is a field, group or scalar.
+pub(crate) fn is_element(value: &Value) -> bool {
+    matches!(value, Value::Field(..) | Value::Group(_) | Value::Scalar(..))
+}
+
+/// Returns whether the field, group or scalar values `left` and `right` are equal.
+pub(crate) fn equal(left: &Value, right: &Value, span: Span) -> Result<bool> {
+    use Element::*;
+
+    Ok(
+        match (Element::from_value(left, span)?, Element::from_value(right, span)?) {
+            (Field(left), Field(right)) => left == right,
+            (Group(left), Group(right)) => left == right,
+            (Scalar(left), Scalar(right)) => left == right,
+            _ => return Err(mismatch(left, "==", right, span)),
+        },
+    )
+}
+
+/// Applies the binary operation `op` to field, group or scalar values.
+pub(crate) fn binary(op: BinaryOperation, left: &Value, right: &Value, span: Span) -> Result<Value> {
+    use BinaryOperation::*;
+    use Element::*;
+
+    let boolean = |value: bool| Ok(Value::Boolean(value, span));
+    let element = |element: Element| Ok(element.into_value(span));
+    match (op, Element::from_value(left, span)?, Element::from_value(right, span)?) {
+        (Eq, ..) => boolean(equal(left, right, span)?),
+        (Neq, ..) => boolean(!equal(left, right, span)?),
+
+        (Add, Field(l), Field(r)) => element(Field(l + r)),
+        (Add, Group(l), Group(r)) => element(Group(l + r)),
+        (Add, Scalar(l), Scalar(r)) => element(Scalar(l + r)),
+        (Sub, Field(l), Field(r)) => element(Field(l - r)),
+        (Sub, Group(l), Group(r)) => element(Group(l - r)),
+        (Sub, Scalar(l), Scalar(r)) => element(Scalar(l - r)),
+        (Mul, Field(l), Field(r)) => element(Field(l * r)),
+        (Mul, Group(l), Scalar(r)) => element(Group(l * r)),
+        (Mul, Scalar(l), Group(r)) => element(Group(l * r)),
+        (Div, Field(_), Field(r)) if r.is_zero() => Err(InterpreterError::division_by_zero(left, span).into()),
+        (Div, Field(l), Field(r)) => element(Field(l / r)),
+        (Pow, Field(l), Field(r)) => element(Field(l.pow(r))),
+
+        (Gt, Field(l), Field(r)) => boolean(*l.is_greater_than(&r)),
+        (Gte, Field(l), Field(r)) => boolean(*l.is_greater_than_or_equal(&r)),
+        (Lt, Field(l), Field(r)) => boolean(*l.is_less_than(&r)),
+        (Lte, Field(l), Field(r)) => boolean(*l.is_less_than_or_equal(&r)),
+        (Gt, Scalar(l), Scalar(r)) => boolean(*l.is_greater_than(&r)),
+        (Gte, Scalar(l), Scalar(r)) => boolean(*l.is_greater_than_or_equal(&r)),
+        (Lt, Scalar(l), Scalar(r)) => boolean(*l.is_less_than(&r)),
+        (Lte, Scalar(l), Scalar(r)) => boolean(*l.is_less_than_or_equal(&r)),
+
+        _ => Err(mismatch(left, op, right, span)),
+    }
+}
+
+/// Applies the unary operation `op` to a field, group or scalar value.
+pub(crate) fn unary(op: UnaryOperation, operand: &Value, span: Span) -> Result<Value> {
+    use Element::*;
+    use UnaryOperation::*;
+
+    let element = match (op, Element::from_value(operand, span)?) {
+        (Double, Field(field)) => Field(field.double()),
+        (Double, Group(group)) => Group(group.double()),
+        (Inverse, Field(field)) => Field(field.inverse().map_err(|_| InterpreterError::inverse_of_zero(span))?),
+        (Negate, Field(field)) => Field(-field),
+        (Negate, Group(group)) => Group(-group),
+        (Square, Field(field)) => Field(field.square()),
+        (SquareRoot, Field(field)) => Field(
+            field
+                .square_root()
+                .map_err(|_| InterpreterError::no_square_root(operand, span))?,
+        ),
+        _ => {
+            return Err(InterpreterError::unsupported(
+                format!("`{}` on `{}`", op.as_str(), leo_ast::Type::from(operand)),
+                span,
+            )
+            .into())
+        }
+    };
+    Ok(element.into_value(span))
+}
+
+/// Returns the error of an operation that the interpreter does not compute for the types of its operands.
+fn mismatch(left: &Value, op: impl fmt::Display, right: &Value, span: Span) -> leo_errors::LeoError {
+    let (left, right) = (leo_ast::Type::from(left), leo_ast::Type::from(right));
+    InterpreterError::unsupported(format!("`{op}` on `{left}` and `{right}`"), span).into()
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::algebra::{Element, Network};
+
+use leo_ast::Value;
+use leo_core::CoreInstruction;
+use leo_errors::{InterpreterError, Result};
+use leo_span::Span;
+
+use snarkvm::prelude::{Field, Network as _, Plaintext, ToBits, ToFields};
+use std::str::FromStr;
+
+/// Evaluates the core function `instruction`, called `name` in the source, on `arguments`.
+pub(crate) fn evaluate(instruction: &CoreInstruction, name: &str, arguments: &[Value], span: Span) -> Result<Value> {
+    use CoreInstruction::*;
+
+    let failed = |error: anyhow::Error| InterpreterError::core_function_failed(name, error, span);
+    let input = plaintext(&arguments[0], span)?;
+    let randomizer = || match arguments.get(1).map(|argument| Element::from_value(argument, span)) {
+        Some(Ok(Element::Scalar(scalar))) => Ok(scalar),
+        Some(Err(error)) => Err(error),
+        _ => unreachable!("type checking only allows scalar randomizers"),
+    };
+
+    let output: Field<Network> = match instruction {
+        BHP256Commit => Network::commit_bhp256(&input.to_bits_le(), &randomizer()?).map_err(failed)?,
+        BHP256Hash => Network::hash_bhp256(&input.to_bits_le()).map_err(failed)?,
+        BHP512Commit => Network::commit_bhp512(&input.to_bits_le(), &randomizer()?).map_err(failed)?,
+        BHP512Hash => Network::hash_bhp512(&input.to_bits_le()).map_err(failed)?,
+        BHP768Commit => Network::commit_bhp768(&input.to_bits_le(), &randomizer()?).map_err(failed)?,
+        BHP768Hash => Network::hash_bhp768(&input.to_bits_le()).map_err(failed)?,
+        BHP1024Commit => Network::commit_bhp1024(&input.to_bits_le(), &randomizer()?).map_err(failed)?,
+        BHP1024Hash => Network::hash_bhp1024(&input.to_bits_le()).map_err(failed)?,
+
+        Pedersen64Commit => Network::commit_ped64(&input.to_bits_le(), &randomizer()?).map_err(failed)?,
+        Pedersen64Hash => Network::hash_ped64(&input.to_bits_le()).map_err(failed)?,
+        Pedersen128Commit => Network::commit_ped128(&input.to_bits_le(), &randomizer()?).map_err(failed)?,
+        Pedersen128Hash => Network::hash_ped128(&input.to_bits_le()).map_err(failed)?,
+
+        Poseidon2Hash => Network::hash_psd2(&input.to_fields().map_err(failed)?).map_err(failed)?,
+        Poseidon4Hash => Network::hash_psd4(&input.to_fields().map_err(failed)?).map_err(failed)?,
+        Poseidon8Hash => Network::hash_psd8(&input.to_fields().map_err(failed)?).map_err(failed)?,
+    };
+
+    // Like the generated instructions, every core function outputs a field.
+    Ok(Element::Field(output).into_value(span))
+}
+
+/// Returns `value` as the snarkVM plaintext that the generated instructions hash.
+fn plaintext(value: &Value, span: Span) -> Result<Plaintext<Network>> {
+    let string = aleo_string(value, span)?;
+    Plaintext::from_str(&string).map_err(|error| InterpreterError::invalid_value(value, error, span).into())
+}
+
+/// Returns `value` in the syntax of Aleo plaintexts, e.g. `1u8` or `{ x: 1field }`.
+pub(crate) fn aleo_string(value: &Value, span: Span) -> Result<String> {
+    Ok(match value {
+        Value::Address(address, _) => address.clone(),
+        Value::Boolean(boolean, _) => boolean.to_string(),
+        Value::Circuit(_, members) => {
+            let members = members
+                .iter()
+                .map(|(name, value)| Ok(format!("{name}: {}", aleo_string(value, span)?)))
+                .collect::<Result<Vec<_>>>()?;
+            format!("{{ {} }}", members.join(", "))
+        }
+        Value::Field(..) | Value::Group(_) | Value::Scalar(..) => Element::from_value(value, span)?.to_string(),
+        Value::I8(integer, _) => format!("{integer}i8"),
+        Value::I16(integer, _) => format!("{integer}i16"),
+        Value::I32(integer, _) => format!("{integer}i32"),
+        Value::I64(integer, _) => format!("{integer}i64"),
+        Value::I128(integer, _) => format!("{integer}i128"),
+        Value::U8(integer, _) => format!("{integer}u8"),
+        Value::U16(integer, _) => format!("{integer}u16"),
+        Value::U32(integer, _) => format!("{integer}u32"),
+        Value::U64(integer, _) => format!("{integer}u64"),
+        Value::U128(integer, _) => format!("{integer}u128"),
+        Value::Input(..) | Value::String(..) | Value::Tuple(_) => {
+            return Err(InterpreterError::unsupported(
+                format!("hashing values of type `{}`", leo_ast::Type::from(value)),
+                span,
+            )
+            .into())
+        }
+    })
+}
@@ -0,0 +1,284 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{algebra, core_functions, execute_statements::is_true, Interpreter};
+
+use leo_ast::{
+    AccessExpression, AssociatedFunction, BinaryExpression, BinaryOperation, CallExpression, CircuitExpression,
+    Expression, Literal, MemberAccess, TernaryExpression, TupleAccess, TupleExpression, Type, UnaryExpression,
+    UnaryOperation, Value,
+};
+use leo_core::CoreInstruction;
+use leo_errors::{InterpreterError, LeoError, Result};
+use leo_span::Span;
+
+use indexmap::IndexMap;
+
+impl<'a> Interpreter<'a> {
+    pub(crate) fn evaluate_expression(&mut self, input: &'a Expression) -> Result<Value> {
+        match input {
+            Expression::Access(expr) => self.evaluate_access(expr),
+            Expression::Binary(expr) => self.evaluate_binary_expression(expr),
+            Expression::Call(expr) => self.evaluate_call(expr),
+            Expression::Circuit(expr) => self.evaluate_circuit_init(expr),
+            Expression::Err(_) => unreachable!("`ErrExpression`s should not be in a type checked AST."),
+            Expression::Identifier(expr) => Ok(self.lookup(expr.name).clone()),
+            Expression::Literal(expr) => Ok(Value::from(expr)),
+            Expression::Ternary(expr) => self.evaluate_ternary(expr),
+            Expression::Tuple(expr) => self.evaluate_tuple(expr),
+            Expression::Unary(expr) => self.evaluate_unary_expression(expr),
+        }
+    }
+
+    fn evaluate_access(&mut self, input: &'a AccessExpression) -> Result<Value> {
+        match input {
+            AccessExpression::AssociatedConstant(constant) => {
+                Err(InterpreterError::unsupported("associated constants", constant.span).into())
+            }
+            AccessExpression::AssociatedFunction(function) => self.evaluate_associated_function(function),
+            AccessExpression::Member(access) => self.evaluate_member_access(access),
+            AccessExpression::Tuple(access) => self.evaluate_tuple_access(access),
+        }
+    }
+
+    fn evaluate_associated_function(&mut self, input: &'a AssociatedFunction) -> Result<Value> {
+        let instruction = match &input.ty {
+            Type::Identifier(circuit) => CoreInstruction::from_symbols(circuit.name, input.name.name),
+            _ => None,
+        }
+        .expect("type checking has resolved every core function");
+        let arguments = input
+            .args
+            .iter()
+            .map(|argument| self.evaluate_expression(argument))
+            .collect::<Result<Vec<_>>>()?;
+
+        core_functions::evaluate(
+            &instruction,
+            &format!("{}::{}", input.ty, input.name),
+            &arguments,
+            input.span,
+        )
+    }
+
+    fn evaluate_member_access(&mut self, input: &'a MemberAccess) -> Result<Value> {
+        match self.evaluate_expression(&input.inner)? {
+            Value::Circuit(_, mut members) => Ok(members
+                .remove(&input.name.name)
+                .expect("type checking has resolved every circuit member")),
+            _ => unreachable!("type checking only allows member accesses of circuits"),
+        }
+    }
+
+    fn evaluate_tuple_access(&mut self, input: &'a TupleAccess) -> Result<Value> {
+        match self.evaluate_expression(&input.tuple)? {
+            Value::Tuple(mut values) => Ok(values.swap_remove(input.index.to_usize())),
+            _ => unreachable!("type checking only allows tuple accesses of tuples"),
+        }
+    }
+
+    fn evaluate_binary_expression(&mut self, input: &'a BinaryExpression) -> Result<Value> {
+        // Both operands are evaluated, as in the generated instructions.
+        let left = self.evaluate_expression(&input.left)?;
+        let right = self.evaluate_expression(&input.right)?;
+        self.evaluate_binary(input.op, left, right, input.span)
+    }
+
+    /// Applies the binary operation `op` to the values `left` and `right`.
+    pub(crate) fn evaluate_binary(&self, op: BinaryOperation, left: Value, right: Value, span: Span) -> Result<Value> {
+        use BinaryOperation::*;
+
+        if algebra::is_element(&left) || algebra::is_element(&right) {
+            return algebra::binary(op, &left, &right, span);
+        }
+
+        let overflow =
+            |_: LeoError| -> LeoError { InterpreterError::binary_operation_overflow(&left, op, &right, span).into() };
+        let (l, r) = (left.clone(), right.clone());
+        match op {
+            Add => l.add(r, span).map_err(overflow),
+            AddWrapped => l.add_wrapped(r, span),
+            And | BitwiseAnd => l.bitand(r, span),
+            Div if is_zero(&right) => Err(InterpreterError::division_by_zero(&left, span).into()),
+            Div => l.div(r, span).map_err(overflow),
+            DivWrapped if is_zero(&right) => Err(InterpreterError::division_by_zero(&left, span).into()),
+            DivWrapped => l.div_wrapped(r, span),
+            Eq => Ok(Value::Boolean(equal(&left, &right, span)?, span)),
+            Gte => l.ge(r, span),
+            Gt => l.gt(r, span),
+            Lte => l.le(r, span),
+            Lt => l.lt(r, span),
+            Mul => l.mul(r, span).map_err(overflow),
+            MulWrapped => l.mul_wrapped(r, span),
+            Nand => l.bitand(r, span)?.not(span),
+            Neq => Ok(Value::Boolean(!equal(&left, &right, span)?, span)),
+            Nor => l.bitor(r, span)?.not(span),
+            Or | BitwiseOr => l.bitor(r, span),
+            Pow => l.pow(r, span).map_err(overflow),
+            PowWrapped => l.pow_wrapped(r, span),
+            Shl => l.shl(r, span).map_err(overflow),
+            ShlWrapped => l.shl_wrapped(r, span),
+            Shr => l.shr(r, span).map_err(overflow),
+            ShrWrapped => l.shr_wrapped(r, span),
+            Sub => l.sub(r, span).map_err(overflow),
+            SubWrapped => l.sub_wrapped(r, span),
+            Xor => l.xor(r, span),
+        }
+    }
+
+    fn evaluate_call(&mut self, input: &'a CallExpression) -> Result<Value> {
+        let function = match &*input.function {
+            Expression::Identifier(identifier) => self.function(identifier.name),
+            _ => None,
+        }
+        .expect("type checking has resolved every called function");
+        let arguments = input
+            .arguments
+            .iter()
+            .map(|argument| self.evaluate_expression(argument))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.call_function(function, arguments)
+    }
+
+    fn evaluate_circuit_init(&mut self, input: &'a CircuitExpression) -> Result<Value> {
+        let mut initializers = IndexMap::new();
+        for member in &input.members {
+            let value = match &member.expression {
+                Some(expression) => self.evaluate_expression(expression)?,
+                None => self.lookup(member.identifier.name).clone(),
+            };
+            initializers.insert(member.identifier.name, value);
+        }
+
+        // Keep the members in the order of the circuit declaration.
+        let circuit = self
+            .circuit(input.name.name)
+            .expect("type checking has resolved every circuit");
+        let members = circuit
+            .members
+            .iter()
+            .filter_map(|member| {
+                let name = member.name();
+                initializers.remove(&name).map(|value| (name, value))
+            })
+            .collect();
+
+        Ok(Value::Circuit(input.name, members))
+    }
+
+    fn evaluate_ternary(&mut self, input: &'a TernaryExpression) -> Result<Value> {
+        // Both branches are evaluated, as in the generated instructions.
+        let condition = self.evaluate_condition(&input.condition)?;
+        let if_true = self.evaluate_expression(&input.if_true)?;
+        let if_false = self.evaluate_expression(&input.if_false)?;
+        Ok(if condition { if_true } else { if_false })
+    }
+
+    fn evaluate_tuple(&mut self, input: &'a TupleExpression) -> Result<Value> {
+        input
+            .elements
+            .iter()
+            .map(|element| self.evaluate_expression(element))
+            .collect::<Result<Vec<_>>>()
+            .map(Value::Tuple)
+    }
+
+    fn evaluate_unary_expression(&mut self, input: &'a UnaryExpression) -> Result<Value> {
+        // A negated integer literal may be out of range without its sign, e.g. `-128i8`.
+        if let (UnaryOperation::Negate, Expression::Literal(literal)) = (input.op, &*input.receiver) {
+            if let Some(value) = negative_integer(literal) {
+                return Ok(value);
+            }
+        }
+
+        let operand = self.evaluate_expression(&input.receiver)?;
+        if algebra::is_element(&operand) {
+            return algebra::unary(input.op, &operand, input.span);
+        }
+
+        let overflow = |_: LeoError| -> LeoError {
+            InterpreterError::unary_operation_overflow(&operand, input.op.as_str(), input.span).into()
+        };
+        let value = operand.clone();
+        match input.op {
+            UnaryOperation::Abs => value.abs(input.span).map_err(overflow),
+            UnaryOperation::AbsWrapped => value.abs_wrapped(input.span),
+            UnaryOperation::Negate => value.neg(input.span).map_err(overflow),
+            UnaryOperation::Not => value.not(input.span),
+            UnaryOperation::Double | UnaryOperation::Inverse | UnaryOperation::Square | UnaryOperation::SquareRoot => {
+                unreachable!(
+                    "type checking only allows `{}` on fields, groups and scalars",
+                    input.op.as_str()
+                )
+            }
+        }
+    }
+}
+
+/// Returns whether `left` and `right` are equal, member by member for circuits and tuples.
+fn equal(left: &Value, right: &Value, span: Span) -> Result<bool> {
+    match (left, right) {
+        (Value::Address(left, _), Value::Address(right, _)) => Ok(left == right),
+        (Value::Circuit(_, left), Value::Circuit(_, right)) => all_equal(left.values(), right.values(), span),
+        (Value::Tuple(left), Value::Tuple(right)) => all_equal(left.iter(), right.iter(), span),
+        (left, right) if algebra::is_element(left) || algebra::is_element(right) => algebra::equal(left, right, span),
+        (left, right) => Ok(is_true(&left.clone().eq(right.clone(), span)?)),
+    }
+}
+
+/// Returns whether the values of `left` and `right` are pairwise equal.
+fn all_equal<'b>(
+    left: impl Iterator<Item = &'b Value>,
+    right: impl Iterator<Item = &'b Value>,
+    span: Span,
+) -> Result<bool> {
+    for (left, right) in left.zip(right) {
+        if !equal(left, right, span)? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Returns whether `value` is an integer zero.
+fn is_zero(value: &Value) -> bool {
+    matches!(
+        value,
+        Value::I8(0, _)
+            | Value::I16(0, _)
+            | Value::I32(0, _)
+            | Value::I64(0, _)
+            | Value::I128(0, _)
+            | Value::U8(0, _)
+            | Value::U16(0, _)
+            | Value::U32(0, _)
+            | Value::U64(0, _)
+            | Value::U128(0, _)
+    )
+}
+
+/// Returns the negation of a signed integer literal.
+fn negative_integer(literal: &Literal) -> Option<Value> {
+    Some(match literal {
+        Literal::I8(string, span) => Value::I8(format!("-{string}").parse().ok()?, *span),
+        Literal::I16(string, span) => Value::I16(format!("-{string}").parse().ok()?, *span),
+        Literal::I32(string, span) => Value::I32(format!("-{string}").parse().ok()?, *span),
+        Literal::I64(string, span) => Value::I64(format!("-{string}").parse().ok()?, *span),
+        Literal::I128(string, span) => Value::I128(format!("-{string}").parse().ok()?, *span),
+        _ => return None,
+    })
+}
@@ -0,0 +1,188 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Flow, Interpreter};
+
+use leo_ast::{
+    AssignStatement, BinaryOperation, Block, ConditionalStatement, ConsoleArgs, ConsoleFunction, ConsoleStatement,
+    DefinitionStatement, Expression, IterationStatement, Node, Printer, Statement, Value,
+};
+use leo_errors::{InterpreterError, Result};
+
+use indexmap::IndexMap;
+
+impl<'a> Interpreter<'a> {
+    pub(crate) fn execute_statement(&mut self, input: &'a Statement) -> Result<Flow> {
+        match input {
+            Statement::Return(stmt) => Ok(Flow::Return(self.evaluate_expression(&stmt.expression)?)),
+            Statement::Definition(stmt) => self.execute_definition(stmt),
+            Statement::Assign(stmt) => self.execute_assign(stmt),
+            Statement::Conditional(stmt) => self.execute_conditional(stmt),
+            Statement::Iteration(stmt) => self.execute_iteration(stmt),
+            Statement::Console(stmt) => self.execute_console(stmt),
+            Statement::Block(stmt) => self.execute_block(stmt),
+        }
+    }
+
+    pub(crate) fn execute_block(&mut self, input: &'a Block) -> Result<Flow> {
+        self.in_scope(IndexMap::new(), |interpreter| {
+            interpreter.execute_statements(&input.statements)
+        })
+    }
+
+    /// Executes the statements in the innermost scope, until one of them returns.
+    fn execute_statements(&mut self, statements: &'a [Statement]) -> Result<Flow> {
+        for statement in statements {
+            if let Flow::Return(value) = self.execute_statement(statement)? {
+                return Ok(Flow::Return(value));
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    fn execute_definition(&mut self, input: &'a DefinitionStatement) -> Result<Flow> {
+        let value = self.evaluate_expression(&input.value)?;
+        self.define(input.variable_name.name, value);
+        Ok(Flow::Next)
+    }
+
+    fn execute_assign(&mut self, input: &'a AssignStatement) -> Result<Flow> {
+        let value = self.evaluate_expression(&input.value)?;
+        match &input.place {
+            Expression::Identifier(identifier) => *self.lookup(identifier.name) = value,
+            _ => unreachable!("type checking only allows assignments to variables"),
+        }
+        Ok(Flow::Next)
+    }
+
+    fn execute_conditional(&mut self, input: &'a ConditionalStatement) -> Result<Flow> {
+        if self.evaluate_condition(&input.condition)? {
+            self.execute_block(&input.block)
+        } else if let Some(next) = &input.next {
+            self.execute_statement(next)
+        } else {
+            Ok(Flow::Next)
+        }
+    }
+
+    fn execute_iteration(&mut self, input: &'a IterationStatement) -> Result<Flow> {
+        let mut current = self.evaluate_expression(&input.start)?;
+        let stop = self.evaluate_expression(&input.stop)?;
+
+        loop {
+            let proceed = if input.inclusive {
+                current.clone().le(stop.clone(), input.span)?
+            } else {
+                current.clone().lt(stop.clone(), input.span)?
+            };
+            if !is_true(&proceed) {
+                return Ok(Flow::Next);
+            }
+
+            let bindings = IndexMap::from([(input.variable.name, current.clone())]);
+            if let Flow::Return(value) = self.in_scope(bindings, |interpreter| {
+                interpreter.execute_statements(&input.block.statements)
+            })? {
+                return Ok(Flow::Return(value));
+            }
+
+            // An inclusive loop may end at the largest value of its type, which has no successor.
+            if is_true(&current.clone().eq(stop.clone(), input.span)?) {
+                return Ok(Flow::Next);
+            }
+            current = current.add(one_like(&stop), input.span)?;
+        }
+    }
+
+    fn execute_console(&mut self, input: &'a ConsoleStatement) -> Result<Flow> {
+        match &input.function {
+            ConsoleFunction::Assert(expression) => self.execute_assert(expression)?,
+            ConsoleFunction::Error(args) | ConsoleFunction::Log(args) => {
+                let message = self.format(args)?;
+                self.log(message);
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    /// Fails if the `assertion` does not hold.
+    /// The error of a failing comparison shows the values of both of its sides.
+    fn execute_assert(&mut self, assertion: &'a Expression) -> Result<()> {
+        let printed = || Printer::default().print_expression(assertion);
+
+        match assertion {
+            Expression::Binary(binary) if is_comparison(binary.op) => {
+                let left = self.evaluate_expression(&binary.left)?;
+                let right = self.evaluate_expression(&binary.right)?;
+                if is_true(&self.evaluate_binary(binary.op, left.clone(), right.clone(), binary.span)?) {
+                    Ok(())
+                } else {
+                    Err(InterpreterError::comparison_assertion_failed(printed(), left, right, assertion.span()).into())
+                }
+            }
+            _ if self.evaluate_condition(assertion)? => Ok(()),
+            _ => Err(InterpreterError::assertion_failed(printed(), assertion.span()).into()),
+        }
+    }
+
+    /// Returns the string of `args` with each `{}` replaced by the value of the next parameter.
+    fn format(&mut self, args: &'a ConsoleArgs) -> Result<String> {
+        let string = args.string.to_string();
+        let mut pieces = string.split("{}");
+        let mut message = pieces.next().unwrap_or_default().to_string();
+        for (parameter, piece) in args.parameters.iter().zip(pieces) {
+            message.push_str(&self.evaluate_expression(parameter)?.to_string());
+            message.push_str(piece);
+        }
+        Ok(message)
+    }
+
+    /// Evaluates an expression of type `bool`.
+    pub(crate) fn evaluate_condition(&mut self, input: &'a Expression) -> Result<bool> {
+        match self.evaluate_expression(input)? {
+            Value::Boolean(value, _) => Ok(value),
+            _ => unreachable!("type checking only allows conditions of type bool"),
+        }
+    }
+}
+
+/// Returns whether `op` compares its operands.
+fn is_comparison(op: BinaryOperation) -> bool {
+    use BinaryOperation::*;
+    matches!(op, Eq | Neq | Gt | Gte | Lt | Lte)
+}
+
+/// Returns whether `value` is the boolean `true`.
+pub(crate) fn is_true(value: &Value) -> bool {
+    matches!(value, Value::Boolean(true, _))
+}
+
+/// Returns the integer one of the same type as `value`.
+fn one_like(value: &Value) -> Value {
+    match value {
+        Value::I8(_, span) => Value::I8(1, *span),
+        Value::I16(_, span) => Value::I16(1, *span),
+        Value::I32(_, span) => Value::I32(1, *span),
+        Value::I64(_, span) => Value::I64(1, *span),
+        Value::I128(_, span) => Value::I128(1, *span),
+        Value::U8(_, span) => Value::U8(1, *span),
+        Value::U16(_, span) => Value::U16(1, *span),
+        Value::U32(_, span) => Value::U32(1, *span),
+        Value::U64(_, span) => Value::U64(1, *span),
+        Value::U128(_, span) => Value::U128(1, *span),
+        _ => unreachable!("type checking only allows loops over integers"),
+    }
+}
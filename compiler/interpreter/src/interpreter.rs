@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Circuit, Function, Program, Value};
+use leo_errors::{InterpreterError, Result};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+/// How execution continues after a statement.
+pub(crate) enum Flow {
+    /// Execution continues with the next statement.
+    Next,
+    /// The current function returns the value.
+    Return(Value),
+}
+
+/// Evaluates the functions of a type checked program.
+pub struct Interpreter<'a> {
+    /// The program whose functions are evaluated.
+    program: &'a Program,
+    /// The scopes of the function being evaluated, innermost last.
+    scopes: Vec<IndexMap<Symbol, Value>>,
+    /// The messages of `console.log` and `console.error` statements, in order.
+    logs: Vec<String>,
+}
+
+impl<'a> Interpreter<'a> {
+    /// Returns a new interpreter of the given program.
+    pub fn new(program: &'a Program) -> Self {
+        Self {
+            program,
+            scopes: Vec::new(),
+            logs: Vec::new(),
+        }
+    }
+
+    /// Calls the function `name` of the program with the given arguments and returns its output.
+    pub fn call(&mut self, name: Symbol, arguments: Vec<Value>) -> Result<Value> {
+        let function = self
+            .function(name)
+            .ok_or_else(|| InterpreterError::unknown_function(name))?;
+        self.call_function(function, arguments)
+    }
+
+    /// Returns the messages logged so far.
+    pub fn logs(&self) -> &[String] {
+        &self.logs
+    }
+
+    /// Evaluates the body of `function` in a fresh set of scopes that binds its inputs to `arguments`.
+    pub(crate) fn call_function(&mut self, function: &'a Function, arguments: Vec<Value>) -> Result<Value> {
+        if function.input.len() != arguments.len() {
+            return Err(InterpreterError::wrong_number_of_arguments(
+                function.identifier,
+                function.input.len(),
+                arguments.len(),
+            )
+            .into());
+        }
+
+        let scope = function
+            .input
+            .iter()
+            .map(|input| input.get_variable().identifier.name)
+            .zip(arguments)
+            .collect();
+        let caller_scopes = std::mem::replace(&mut self.scopes, vec![scope]);
+        let flow = self.execute_block(&function.block);
+        self.scopes = caller_scopes;
+
+        match flow? {
+            Flow::Return(value) => Ok(value),
+            Flow::Next => Err(InterpreterError::missing_return(function.identifier, function.span).into()),
+        }
+    }
+
+    /// Returns the function `name` of the program or of one of its imports.
+    pub(crate) fn function(&self, name: Symbol) -> Option<&'a Function> {
+        let program = self.program;
+        program
+            .functions
+            .values()
+            .chain(program.imports.values().flat_map(|import| import.functions.values()))
+            .find(|function| function.name() == name)
+    }
+
+    /// Returns the circuit or record `name` of the program or of one of its imports.
+    pub(crate) fn circuit(&self, name: Symbol) -> Option<&'a Circuit> {
+        let program = self.program;
+        program
+            .circuits
+            .values()
+            .chain(program.imports.values().flat_map(|import| import.circuits.values()))
+            .find(|circuit| circuit.name() == name)
+    }
+
+    /// Evaluates `body` in a new innermost scope that binds `bindings`.
+    pub(crate) fn in_scope<T>(
+        &mut self,
+        bindings: IndexMap<Symbol, Value>,
+        body: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> Result<T> {
+        self.scopes.push(bindings);
+        let result = body(self);
+        self.scopes.pop();
+        result
+    }
+
+    /// Defines the variable `name` in the innermost scope.
+    pub(crate) fn define(&mut self, name: Symbol, value: Value) {
+        self.scopes
+            .last_mut()
+            .expect("a function is being evaluated")
+            .insert(name, value);
+    }
+
+    /// Returns the value of the variable `name`.
+    pub(crate) fn lookup(&mut self, name: Symbol) -> &mut Value {
+        self.scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.get_mut(&name))
+            .expect("type checking has resolved every variable")
+    }
+
+    /// Records a logged message.
+    pub(crate) fn log(&mut self, message: String) {
+        self.logs.push(message);
+    }
+}
@@ -0,0 +1,446 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::algebra::Network;
+use crate::core_functions::aleo_string;
+use crate::Interpreter;
+
+use indexmap::IndexMap;
+use leo_ast::{Identifier, InputValue, Program, Section, Value};
+use leo_compiler::{source_map::failed_instruction, Compiler, InstructionSpan};
+use leo_errors::{emitter::Handler, InterpreterError, LeoError, MessageName};
+use leo_span::{source_map::FileName, sym, symbol::create_session_if_not_set_then, Span};
+use leo_test_framework::{
+    runner::{Namespace, ParseType, Runner},
+    Test,
+};
+use serde_yaml::Value as Yaml;
+use snarkvm::circuit::AleoV0;
+use snarkvm::prelude::{Identifier as AleoIdentifier, PrivateKey, Process, Program as AleoProgram, Value as AleoValue};
+use std::cell::{Cell, RefCell};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Runs the `main` function of the test program on the `[main]` section of each of its input files.
+struct InterpretNamespace;
+
+impl Namespace for InterpretNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
+    }
+
+    fn run_test(&self, test: Test) -> Result<Yaml, String> {
+        let (handler, buf) = Handler::new_with_buf();
+
+        create_session_if_not_set_then(|_| {
+            run_test(test, &handler).map_err(|error| {
+                // Errors reported through the handler are only referred to by their exit code.
+                if !matches!(error, LeoError::LastErrorCode(_)) {
+                    handler.emit_err(error);
+                }
+                buf.extract_errs().to_string()
+            })
+        })
+    }
+}
+
+/// Returns the paths of the `input_file`s of the test.
+fn input_files(test: &Test) -> Vec<PathBuf> {
+    let directory = test.path.parent().expect("no test parent dir");
+    match test.config.get("input_file") {
+        Some(Yaml::String(name)) => vec![directory.join(name)],
+        Some(Yaml::Sequence(names)) => names
+            .iter()
+            .map(|name| directory.join(name.as_str().expect("input_file was not a string")))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Returns the values of an input section, in order.
+fn arguments(section: &Section) -> Result<Vec<Value>, LeoError> {
+    section
+        .definitions
+        .iter()
+        .map(|definition| {
+            let value = InputValue::try_from((definition.type_.clone(), definition.value.clone()))?;
+            Value::try_from(&value)
+        })
+        .collect()
+}
+
+fn run_test(test: Test, handler: &Handler) -> Result<Yaml, LeoError> {
+    let mut compiler = Compiler::new(
+        String::from("test"),
+        String::from("testnet3"),
+        handler,
+        test.path.clone(),
+        PathBuf::from("/tmp/output/"),
+        None,
+    );
+    compiler.parse_program_from_string(&test.content, FileName::Custom("interpreter-test".into()))?;
+    let symbol_table = compiler.symbol_table_pass()?;
+    compiler.type_checker_pass(symbol_table)?;
+    handler.last_err()?;
+
+    let mut inputs = input_files(&test);
+    if inputs.is_empty() {
+        inputs.push(PathBuf::new());
+    }
+
+    let mut outputs = Vec::with_capacity(inputs.len());
+    for input in inputs {
+        let mut compiler = compiler.clone();
+        compiler.parse_input(input)?;
+
+        // The values of the `[main]` section, in order.
+        let arguments = compiler
+            .input_ast
+            .iter()
+            .flat_map(|input_ast| &input_ast.sections)
+            .filter(|section| section.name == sym::main)
+            .map(arguments)
+            .collect::<Result<Vec<_>, LeoError>>()?
+            .concat();
+
+        let mut interpreter = Interpreter::new(compiler.ast.as_repr());
+        let output = interpreter.call(sym::main, arguments)?;
+
+        let mut execution = serde_yaml::Mapping::new();
+        execution.insert("output".into(), output.to_string().into());
+        if !interpreter.logs().is_empty() {
+            execution.insert("logs".into(), interpreter.logs().to_vec().into());
+        }
+        outputs.push(Yaml::Mapping(execution));
+    }
+
+    Ok(Yaml::Sequence(outputs))
+}
+
+/// Runs the functions named by the sections of the input files of a test program both with the interpreter,
+/// and with snarkVM evaluating the Aleo instructions generated for the program, and checks that their outputs agree.
+/// Programs that do not compile, or that have no input files, are not run.
+struct DifferentialNamespace;
+
+impl Namespace for DifferentialNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
+    }
+
+    fn run_test(&self, test: Test) -> Result<Yaml, String> {
+        let (handler, _) = Handler::new_with_buf();
+
+        create_session_if_not_set_then(|_| differential_test(test, &handler))
+    }
+}
+
+thread_local! {
+    /// The number of function calls compared by the differential tests.
+    static COMPARED: Cell<usize> = Cell::new(0);
+    /// The number of programs and function calls skipped by the differential tests, by reason.
+    static SKIPPED: RefCell<IndexMap<&'static str, usize>> = RefCell::new(IndexMap::new());
+}
+
+/// Counts a program or a function call skipped for `reason`.
+fn skip(reason: &'static str) {
+    SKIPPED.with(|skipped| *skipped.borrow_mut().entry(reason).or_default() += 1);
+}
+
+fn differential_test(test: Test, handler: &Handler) -> Result<Yaml, String> {
+    let inputs = input_files(&test);
+    if inputs.is_empty() {
+        return Ok(Yaml::Null);
+    }
+
+    // snarkVM only accepts programs of the `aleo` network.
+    let mut compiler = Compiler::new(
+        String::from("test"),
+        String::from("aleo"),
+        handler,
+        test.path.clone(),
+        PathBuf::from("/tmp/output/"),
+        None,
+    );
+    let compiled = compiler
+        .parse_program_from_string(&test.content, FileName::Custom("differential-test".into()))
+        .and_then(|_| compiler.generate_instructions());
+    let (instructions, instruction_spans) = match compiled {
+        Ok(_) if !compiler.ast.as_repr().imports.is_empty() => {
+            skip("programs with imports");
+            return Ok(Yaml::Null);
+        }
+        Ok((_, instructions, instruction_spans)) => (instructions, instruction_spans),
+        Err(_) => {
+            skip("programs that do not compile to Aleo instructions");
+            return Ok(Yaml::Null);
+        }
+    };
+
+    let program = compiler.ast.as_repr();
+    let process = Vm::new(&instructions).map_err(|error| format!("snarkVM rejected the program: {error}"))?;
+
+    let mut discrepancies = Vec::new();
+    for input in inputs {
+        let mut compiler = compiler.clone();
+        compiler.parse_input(input.clone()).map_err(|error| error.to_string())?;
+
+        for section in compiler.input_ast.iter().flat_map(|input_ast| &input_ast.sections) {
+            let arguments = arguments(section).map_err(|error| error.to_string())?;
+            let leo = Interpreter::new(program).call(section.name, arguments.clone());
+
+            // An argument that the interpreter cannot represent in Aleo is a limitation of the interpreter.
+            let inputs = match arguments
+                .iter()
+                .map(|argument| aleo_input(program, argument))
+                .collect::<Result<Vec<_>, _>>()
+            {
+                Ok(inputs) => inputs,
+                Err(_) => {
+                    skip("calls with arguments that the interpreter cannot write in Aleo");
+                    continue;
+                }
+            };
+            let aleo = process.evaluate(&section.name.to_string(), &inputs);
+
+            let leo = match leo {
+                Ok(output) => match aleo_outputs(&output) {
+                    Ok(outputs) => Ok(outputs),
+                    Err(_) => {
+                        skip("calls with outputs that the interpreter cannot write in Aleo");
+                        continue;
+                    }
+                },
+                Err(error) if error.error_code() == unsupported_code() => {
+                    skip("calls of code that the interpreter does not support");
+                    continue;
+                }
+                Err(error) => Err((Failure::of_leo(&error), error.to_string())),
+            };
+            let aleo = aleo.map_err(|error| {
+                let error = format!("{error:#}");
+                let instruction = failed_instruction(&instruction_spans, section.name, &error);
+                (Failure::of_aleo(instruction), error)
+            });
+            COMPARED.with(|compared| compared.set(compared.get() + 1));
+            if !agree(&leo, &aleo) {
+                discrepancies.push(format!(
+                    "`{}` disagrees on the inputs ({}) of {}\n  Leo:  {}\n  Aleo: {}",
+                    section.name,
+                    inputs.join(", "),
+                    input.display(),
+                    describe(&leo),
+                    describe(&aleo)
+                ));
+            }
+        }
+    }
+
+    if discrepancies.is_empty() {
+        Ok(Yaml::Null)
+    } else {
+        Err(format!(
+            "{}\nAleo instructions:\n{}",
+            discrepancies.join("\n"),
+            instructions
+        ))
+    }
+}
+
+/// How a function call failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Failure {
+    /// An assertion failed.
+    Assertion,
+    /// An arithmetic operation overflowed, or had no result, e.g., a division by zero.
+    Arithmetic,
+    /// Any other failure, e.g., invalid inputs.
+    Other,
+}
+
+impl Failure {
+    /// Classifies an error of the interpreter by its code.
+    fn of_leo(error: &LeoError) -> Self {
+        match MessageName::from_code(&error.error_code()).map(|name| name.name) {
+            Some("assertion_failed" | "comparison_assertion_failed") => Self::Assertion,
+            Some(
+                "binary_operation_overflow"
+                | "unary_operation_overflow"
+                | "division_by_zero"
+                | "inverse_of_zero"
+                | "no_square_root",
+            ) => Self::Arithmetic,
+            _ => Self::Other,
+        }
+    }
+
+    /// Classifies an error of snarkVM by the instruction that failed, if it is known.
+    fn of_aleo(instruction: Option<&InstructionSpan>) -> Self {
+        let opcode = instruction.and_then(|instruction| instruction.instruction.split_whitespace().next());
+        match opcode {
+            Some("assert.eq" | "assert.neq") => Self::Assertion,
+            Some("abs" | "add" | "div" | "inv" | "mul" | "neg" | "pow" | "shl" | "shr" | "sqrt" | "square" | "sub") => {
+                Self::Arithmetic
+            }
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Returns the code of the error for the constructs the interpreter does not support.
+fn unsupported_code() -> String {
+    LeoError::from(InterpreterError::unsupported("", Span::default())).error_code()
+}
+
+/// Returns `value` as the input of an Aleo function, where the members of records are private.
+fn aleo_input(program: &Program, value: &Value) -> Result<String, LeoError> {
+    let is_record = |name: &Identifier| {
+        program
+            .circuits
+            .values()
+            .any(|circuit| circuit.is_record && circuit.identifier.name == name.name)
+    };
+    match value {
+        Value::Circuit(name, members) if is_record(name) => {
+            let members = members
+                .iter()
+                .map(|(member, value)| Ok(format!("{member}: {}.private", aleo_string(value, Span::default())?)))
+                .collect::<Result<Vec<_>, LeoError>>()?;
+            Ok(format!("{{ {} }}", members.join(", ")))
+        }
+        _ => aleo_string(value, Span::default()),
+    }
+}
+
+/// Returns the outputs of the Aleo function compiled from a Leo function that returned `value`.
+fn aleo_outputs(value: &Value) -> Result<Vec<AleoValue<Network>>, String> {
+    let values = match value {
+        Value::Tuple(values) => values.iter().collect(),
+        value => vec![value],
+    };
+    values
+        .into_iter()
+        .map(|value| {
+            let string = aleo_string(value, Span::default()).map_err(|error| error.to_string())?;
+            AleoValue::from_str(&string).map_err(|error| error.to_string())
+        })
+        .collect()
+}
+
+/// The outputs of a function call, or how and why it failed.
+type Outcome = Result<Vec<AleoValue<Network>>, (Failure, String)>;
+
+/// Returns `true` if both sides failed in the same way, or if both succeeded with the same outputs.
+/// Records are not compared, as snarkVM gives them a fresh nonce.
+fn agree(leo: &Outcome, aleo: &Outcome) -> bool {
+    match (leo, aleo) {
+        (Ok(leo), Ok(aleo)) => {
+            leo.len() == aleo.len()
+                && leo.iter().zip(aleo).all(|(leo, aleo)| match (leo, aleo) {
+                    (_, AleoValue::Record(_)) => true,
+                    (leo, aleo) => leo == aleo,
+                })
+        }
+        (Err((leo, _)), Err((aleo, _))) => leo == aleo,
+        _ => false,
+    }
+}
+
+fn describe(outcome: &Outcome) -> String {
+    match outcome {
+        Ok(outputs) => outputs.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "),
+        Err((failure, error)) => format!("error ({failure:?}): {error}"),
+    }
+}
+
+/// An in-process snarkVM holding an Aleo program.
+struct Vm {
+    process: Process<Network>,
+    program: AleoProgram<Network>,
+}
+
+impl Vm {
+    fn new(instructions: &str) -> anyhow::Result<Self> {
+        let program = AleoProgram::<Network>::from_str(instructions)?;
+        let mut process = Process::load()?;
+        process.add_program(&program)?;
+        Ok(Self { process, program })
+    }
+
+    /// Evaluates the function `name` on `inputs` without proving it, and returns its outputs.
+    fn evaluate(&self, name: &str, inputs: &[String]) -> anyhow::Result<Vec<AleoValue<Network>>> {
+        let rng = &mut rand::thread_rng();
+        let private_key = PrivateKey::new(rng)?;
+        let inputs = inputs
+            .iter()
+            .map(|input| AleoValue::from_str(input))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let authorization = self.process.authorize::<AleoV0, _>(
+            &private_key,
+            self.program.id(),
+            AleoIdentifier::from_str(name)?,
+            &inputs,
+            rng,
+        )?;
+        let response = self.process.evaluate::<AleoV0>(authorization)?;
+        Ok(response.outputs().to_vec())
+    }
+}
+
+struct TestRunner;
+
+impl Runner for TestRunner {
+    fn resolve_namespace(&self, name: &str) -> Option<Box<dyn Namespace>> {
+        Some(match name {
+            "Interpret" => Box::new(InterpretNamespace),
+            _ => return None,
+        })
+    }
+}
+
+#[test]
+pub fn interpreter_tests() {
+    leo_test_framework::run_tests(&TestRunner, "interpreter");
+}
+
+struct DifferentialRunner;
+
+impl Runner for DifferentialRunner {
+    fn resolve_namespace(&self, name: &str) -> Option<Box<dyn Namespace>> {
+        Some(match name {
+            "Compile" | "Interpret" => Box::new(DifferentialNamespace),
+            _ => return None,
+        })
+    }
+}
+
+#[test]
+pub fn differential_tests() {
+    leo_test_framework::run_checks(&DifferentialRunner, &["compiler", "interpreter"]);
+
+    let compared = COMPARED.with(Cell::get);
+    println!("compared {compared} function calls");
+    SKIPPED.with(|skipped| {
+        for (reason, count) in skipped.borrow().iter() {
+            println!("skipped {count} {reason}");
+        }
+    });
+    // A change that skips most function calls fails the tests.
+    let skipped = SKIPPED.with(|skipped| skipped.borrow().values().sum::<usize>());
+    assert!(
+        compared > skipped,
+        "only {compared} function calls were compared, and {skipped} programs and calls skipped"
+    );
+}
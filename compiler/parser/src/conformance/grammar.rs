@@ -0,0 +1,509 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use abnf::types::{Node, TerminalValues};
+use indexmap::{IndexMap, IndexSet};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::collections::{BTreeSet, HashMap};
+
+/// The ABNF grammar of Leo.
+const GRAMMAR: &str = include_str!("../../../../docs/grammar/abnf-grammar.txt");
+
+/// The comment that starts the syntactic section of the grammar.
+/// Rules before it describe characters, rules after it describe tokens.
+const SYNTACTIC_SECTION: &str = "; Syntactic Grammar";
+
+/// Derivations deeper than this always take the shortest way out.
+const MAX_DEPTH: usize = 40;
+
+/// Samples longer than this always take the shortest way out.
+const MAX_LENGTH: usize = 120;
+
+/// The most extra repetitions a sample takes beyond the minimum of a repetition.
+const MAX_EXTRA_REPETITIONS: usize = 3;
+
+/// Characters inserted by mutations, chosen to straddle the boundaries of the lexical rules.
+const MUTATION_CHARACTERS: &[char] = &[
+    'a', 'f', 'x', 'u', 'Z', '0', '7', '9', '_', '.', '{', '}', '*', '/', '"', '\'', '\\', ' ', '\t', '\n', '\r',
+    '\x0c', 'é', '\u{202e}', '\u{2066}',
+];
+
+/// The rules of the grammar, with what the sampler and the matcher need to know about them.
+pub(super) struct Grammar {
+    /// The definition of each rule.
+    rules: IndexMap<String, Node>,
+    /// The rules of the lexical grammar, whose samples are sequences of characters rather than of tokens.
+    lexical: IndexSet<String>,
+    /// The length of the shortest derivation of each rule.
+    shortest: IndexMap<String, usize>,
+}
+
+impl Grammar {
+    /// Parses the grammar in `docs/grammar/abnf-grammar.txt`.
+    pub(super) fn load() -> Self {
+        let parse = |text| abnf::rulelist(text).unwrap_or_else(|e| panic!("the Leo ABNF grammar should parse: {}", e));
+        let rules: IndexMap<String, Node> = parse(GRAMMAR)
+            .into_iter()
+            .map(|rule| (rule.name().to_owned(), rule.node().clone()))
+            .collect();
+        let lexical_end = GRAMMAR
+            .find(SYNTACTIC_SECTION)
+            .expect("the grammar should have a syntactic section");
+        let lexical = parse(&GRAMMAR[..lexical_end])
+            .into_iter()
+            .map(|rule| rule.name().to_owned())
+            .collect();
+
+        // The shortest derivations are the least fixed point of `shortest_node`, reached from above.
+        let mut grammar = Self {
+            shortest: rules.keys().map(|name| (name.clone(), usize::MAX)).collect(),
+            rules,
+            lexical,
+        };
+        loop {
+            let shortest: IndexMap<String, usize> = grammar
+                .rules
+                .iter()
+                .map(|(name, node)| (name.clone(), grammar.shortest_node(node)))
+                .collect();
+            if shortest == grammar.shortest {
+                return grammar;
+            }
+            grammar.shortest = shortest;
+        }
+    }
+
+    /// Returns `true` if the grammar defines `rule`.
+    pub(super) fn has_rule(&self, rule: &str) -> bool {
+        self.rules.contains_key(rule)
+    }
+
+    /// Returns `true` if `input` is derivable from `rule`.
+    ///
+    /// Only meant for the lexical grammar, since matching does not terminate on left-recursive rules.
+    pub(super) fn matches(&self, rule: &str, input: &str) -> bool {
+        let input: Vec<char> = input.chars().collect();
+        self.match_rule(rule, &input, 0).contains(&input.len())
+    }
+
+    /// Returns `true` if the sequence of `tokens` is derivable from the syntactic `rule`.
+    ///
+    /// Left-recursive rules are matched by repeating the match until the matches of every rule at every position
+    /// stop growing, starting from none, where a rule that is already being matched at a position matches
+    /// what it matched in the previous round.
+    pub(super) fn matches_tokens(&self, rule: &str, tokens: &[String]) -> bool {
+        let mut matcher = TokenMatcher {
+            grammar: self,
+            tokens,
+            round: 0,
+            grew: false,
+            matches: HashMap::new(),
+            lexemes: HashMap::new(),
+        };
+        loop {
+            matcher.round += 1;
+            matcher.grew = false;
+            let ends = matcher.match_rule(rule, 0);
+            if !matcher.grew {
+                return ends.contains(&tokens.len());
+            }
+        }
+    }
+
+    /// Returns `true` if `text` is ruled out of `rule` by a restriction the grammar only states in prose.
+    fn excluded(&self, rule: &str, text: &str) -> bool {
+        // identifier = letter *( letter / decimal-digit / "_" )
+        //              ; but not a keyword or a boolean literal or aleo1...
+        rule == "identifier"
+            && (self.matches("keyword", text) || self.matches("boolean-literal", text) || text.starts_with("aleo1"))
+    }
+
+    /// Returns the length of the shortest derivation of `node`, given the current estimate for each rule.
+    fn shortest_node(&self, node: &Node) -> usize {
+        match node {
+            Node::Alternatives(nodes) => nodes.iter().map(|node| self.shortest_node(node)).min().unwrap_or(0),
+            Node::Concatenation(nodes) => nodes
+                .iter()
+                .fold(0, |length, node| length.saturating_add(self.shortest_node(node))),
+            Node::Repetition { repeat, node } => match repeat.min().unwrap_or(0) {
+                0 => 0,
+                min => min.saturating_mul(self.shortest_node(node)),
+            },
+            Node::Rulename(name) => self.shortest[name.as_str()],
+            Node::Group(node) => self.shortest_node(node),
+            Node::Optional(_) => 0,
+            Node::String(string) => string.value().chars().count(),
+            Node::TerminalValues(TerminalValues::Range(..)) => 1,
+            Node::TerminalValues(TerminalValues::Concatenation(values)) => values.len(),
+            Node::Prose(_) => usize::MAX,
+        }
+    }
+
+    /// Returns the positions at which a derivation of `rule` starting at `start` can end.
+    fn match_rule(&self, rule: &str, input: &[char], start: usize) -> BTreeSet<usize> {
+        let mut ends = self.match_node(&self.rules[rule], input, start);
+        ends.retain(|end| !self.excluded(rule, &input[start..*end].iter().collect::<String>()));
+        ends
+    }
+
+    /// Returns the positions at which a derivation of `node` starting at `start` can end.
+    fn match_node(&self, node: &Node, input: &[char], start: usize) -> BTreeSet<usize> {
+        let rest = &input[start..];
+        match node {
+            Node::Alternatives(nodes) => nodes
+                .iter()
+                .flat_map(|node| self.match_node(node, input, start))
+                .collect(),
+            Node::Concatenation(nodes) => nodes.iter().fold(BTreeSet::from([start]), |starts, node| {
+                starts
+                    .into_iter()
+                    .flat_map(|start| self.match_node(node, input, start))
+                    .collect()
+            }),
+            Node::Repetition { repeat, node } => {
+                let min = repeat.min().unwrap_or(0);
+                // Every repetition past the length of the input matches nothing new.
+                let max = repeat.max().unwrap_or(usize::MAX).min(min.max(rest.len() + 1));
+                let mut ends = BTreeSet::new();
+                let mut frontier = BTreeSet::from([start]);
+                for count in 0..=max {
+                    if count >= min {
+                        ends.extend(frontier.iter().copied());
+                    }
+                    if count == max || frontier.is_empty() {
+                        break;
+                    }
+                    frontier = frontier
+                        .into_iter()
+                        .flat_map(|start| self.match_node(node, input, start))
+                        .collect();
+                }
+                ends
+            }
+            Node::Rulename(name) => self.match_rule(name, input, start),
+            Node::Group(node) => self.match_node(node, input, start),
+            Node::Optional(node) => {
+                let mut ends = self.match_node(node, input, start);
+                ends.insert(start);
+                ends
+            }
+            Node::String(string) => {
+                let expected: Vec<char> = string.value().chars().collect();
+                let matched = rest.len() >= expected.len()
+                    && rest.iter().zip(&expected).all(|(actual, expected)| {
+                        actual == expected || (!string.is_case_sensitive() && actual.eq_ignore_ascii_case(expected))
+                    });
+                matched.then(|| start + expected.len()).into_iter().collect()
+            }
+            Node::TerminalValues(TerminalValues::Range(low, high)) => match rest.first() {
+                Some(c) if (*low..=*high).contains(&(*c as u32)) => BTreeSet::from([start + 1]),
+                _ => BTreeSet::new(),
+            },
+            Node::TerminalValues(TerminalValues::Concatenation(values)) => {
+                let matched =
+                    rest.len() >= values.len() && rest.iter().zip(values).all(|(c, value)| *c as u32 == *value);
+                matched.then(|| start + values.len()).into_iter().collect()
+            }
+            Node::Prose(prose) => panic!("cannot match the prose rule <{}>", prose),
+        }
+    }
+}
+
+/// The ends of the matches of a rule at a position, and the round they were last matched in.
+struct RuleMatches {
+    ends: BTreeSet<usize>,
+    round: usize,
+}
+
+/// Matches sequences of tokens against the syntactic rules of a [`Grammar`].
+struct TokenMatcher<'a> {
+    grammar: &'a Grammar,
+    tokens: &'a [String],
+    /// The current round of matching.
+    round: usize,
+    /// Whether the matches of a rule grew in the current round.
+    grew: bool,
+    /// The matches of each rule at each position so far.
+    matches: HashMap<(&'a str, usize), RuleMatches>,
+    /// Whether each lexical rule derives the token at each position.
+    lexemes: HashMap<(&'a str, usize), bool>,
+}
+
+impl<'a> TokenMatcher<'a> {
+    /// Returns the positions at which a derivation of `rule` starting at the token `start` can end.
+    fn match_rule(&mut self, rule: &'a str, start: usize) -> BTreeSet<usize> {
+        if self.grammar.lexical.contains(rule) {
+            let (grammar, tokens) = (self.grammar, self.tokens);
+            let matched = *self
+                .lexemes
+                .entry((rule, start))
+                .or_insert_with(|| tokens.get(start).map_or(false, |token| grammar.matches(rule, token)));
+            return matched.then(|| start + 1).into_iter().collect();
+        }
+
+        // A rule is matched once per round at each position, later matches in the round reuse its ends so far.
+        let round = self.round;
+        match self.matches.get_mut(&(rule, start)) {
+            Some(matches) if matches.round == round => return matches.ends.clone(),
+            Some(matches) => matches.round = round,
+            None => {
+                self.matches.insert(
+                    (rule, start),
+                    RuleMatches {
+                        ends: BTreeSet::new(),
+                        round,
+                    },
+                );
+            }
+        }
+
+        let ends = self.match_node(&self.grammar.rules[rule], start);
+        let matches = self.matches.get_mut(&(rule, start)).unwrap();
+        if !ends.is_subset(&matches.ends) {
+            matches.ends.extend(ends);
+            self.grew = true;
+        }
+        matches.ends.clone()
+    }
+
+    /// Returns the positions at which a derivation of `node` starting at the token `start` can end.
+    fn match_node(&mut self, node: &'a Node, start: usize) -> BTreeSet<usize> {
+        let token = self.tokens.get(start).map(String::as_str);
+        match node {
+            Node::Alternatives(nodes) => nodes.iter().flat_map(|node| self.match_node(node, start)).collect(),
+            Node::Concatenation(nodes) => nodes.iter().fold(BTreeSet::from([start]), |starts, node| {
+                starts
+                    .into_iter()
+                    .flat_map(|start| self.match_node(node, start))
+                    .collect()
+            }),
+            Node::Repetition { repeat, node } => {
+                let min = repeat.min().unwrap_or(0);
+                // Every repetition past the number of tokens left matches nothing new.
+                let max = repeat
+                    .max()
+                    .unwrap_or(usize::MAX)
+                    .min(min.max(self.tokens.len() - start + 1));
+                let mut ends = BTreeSet::new();
+                let mut frontier = BTreeSet::from([start]);
+                for count in 0..=max {
+                    if count >= min {
+                        ends.extend(frontier.iter().copied());
+                    }
+                    if count == max || frontier.is_empty() {
+                        break;
+                    }
+                    frontier = frontier
+                        .into_iter()
+                        .flat_map(|start| self.match_node(node, start))
+                        .collect();
+                }
+                ends
+            }
+            Node::Rulename(name) => self.match_rule(name, start),
+            Node::Group(node) => self.match_node(node, start),
+            Node::Optional(node) => {
+                let mut ends = self.match_node(node, start);
+                ends.insert(start);
+                ends
+            }
+            // Each string of a syntactic rule is a token of its own.
+            Node::String(string) => {
+                let matched = token.map_or(false, |token| {
+                    token == string.value()
+                        || (!string.is_case_sensitive() && token.eq_ignore_ascii_case(string.value()))
+                });
+                matched.then(|| start + 1).into_iter().collect()
+            }
+            Node::TerminalValues(_) => panic!("the syntactic grammar should not use terminal values"),
+            Node::Prose(prose) => panic!("cannot match the prose rule <{}>", prose),
+        }
+    }
+}
+
+/// Derives random samples from the rules of a [`Grammar`].
+pub(super) struct Sampler<'a> {
+    grammar: &'a Grammar,
+    rng: StdRng,
+    /// The positions at which the tokens of the current sample start.
+    token_starts: Vec<usize>,
+}
+
+impl<'a> Sampler<'a> {
+    /// Returns a sampler whose samples are determined by `seed`.
+    pub(super) fn new(grammar: &'a Grammar, seed: u64) -> Self {
+        Self {
+            grammar,
+            rng: StdRng::seed_from_u64(seed),
+            token_starts: Vec::new(),
+        }
+    }
+
+    /// Returns a random string derivable from `rule`.
+    /// The tokens of syntactic rules are separated by single spaces.
+    pub(super) fn sample(&mut self, rule: &str) -> String {
+        let mut sample = String::new();
+        self.sample_rule(rule, 0, &mut sample);
+        sample
+    }
+
+    /// Returns the tokens of a random sequence of tokens derivable from the syntactic `rule`.
+    pub(super) fn sample_tokens(&mut self, rule: &str) -> Vec<String> {
+        self.token_starts.clear();
+        let sample = self.sample(rule);
+        let mut ends: Vec<usize> = self.token_starts.iter().skip(1).map(|start| start - 1).collect();
+        ends.push(sample.len());
+        self.token_starts
+            .iter()
+            .zip(ends)
+            .map(|(start, end)| sample[*start..end].to_string())
+            .collect()
+    }
+
+    /// Returns `tokens` with one random token inserted, removed, replaced or swapped with the next one,
+    /// or with its tail cut off. Inserted tokens are copied from `tokens`.
+    pub(super) fn mutate_tokens(&mut self, tokens: &[String]) -> Vec<String> {
+        let mut tokens = tokens.to_vec();
+        let position = self.rng.gen_range(0..=tokens.len());
+        let token = match tokens.choose(&mut self.rng) {
+            Some(token) => token.clone(),
+            None => return tokens,
+        };
+        match self.rng.gen_range(0..5) {
+            0 => tokens.insert(position, token),
+            1 if position < tokens.len() => {
+                tokens.remove(position);
+            }
+            2 if position < tokens.len() => tokens[position] = token,
+            3 if position + 1 < tokens.len() => tokens.swap(position, position + 1),
+            _ => tokens.truncate(position),
+        }
+        tokens
+    }
+
+    /// Returns `sample` with one random character inserted, removed or replaced, or with its tail cut off.
+    pub(super) fn mutate(&mut self, sample: &str) -> String {
+        let mut chars: Vec<char> = sample.chars().collect();
+        let position = self.rng.gen_range(0..=chars.len());
+        let c = *MUTATION_CHARACTERS.choose(&mut self.rng).unwrap();
+        match self.rng.gen_range(0..4) {
+            0 => chars.insert(position, c),
+            1 if position < chars.len() => {
+                chars.remove(position);
+            }
+            2 if position < chars.len() => chars[position] = c,
+            _ => chars.truncate(position),
+        }
+        chars.into_iter().collect()
+    }
+
+    /// Appends a derivation of `rule` to `out`.
+    fn sample_rule(&mut self, rule: &str, depth: usize, out: &mut String) {
+        if self.grammar.lexical.contains(rule) {
+            // Resample until the prose restrictions of the rule hold.
+            let lexeme = loop {
+                let mut lexeme = String::new();
+                self.sample_node(&self.grammar.rules[rule], depth + 1, true, &mut lexeme);
+                if !self.grammar.excluded(rule, &lexeme) {
+                    break lexeme;
+                }
+            };
+            out.push_str(&lexeme);
+        } else {
+            self.sample_node(&self.grammar.rules[rule], depth + 1, false, out);
+        }
+    }
+
+    /// Appends a derivation of `node` to `out`.
+    /// Within a `lexical` rule, strings are appended as they are rather than as separate tokens.
+    fn sample_node(&mut self, node: &Node, depth: usize, lexical: bool, out: &mut String) {
+        let shortest = depth > MAX_DEPTH || out.len() > MAX_LENGTH;
+        match node {
+            Node::Alternatives(nodes) => {
+                let node = if shortest {
+                    nodes
+                        .iter()
+                        .min_by_key(|node| self.grammar.shortest_node(node))
+                        .unwrap()
+                } else {
+                    nodes.choose(&mut self.rng).unwrap()
+                };
+                self.sample_node(node, depth, lexical, out);
+            }
+            Node::Concatenation(nodes) => {
+                for node in nodes {
+                    self.sample_node(node, depth, lexical, out);
+                }
+            }
+            Node::Repetition { repeat, node } => {
+                let min = repeat.min().unwrap_or(0);
+                let mut count = min;
+                if !shortest {
+                    count += self.rng.gen_range(0..=MAX_EXTRA_REPETITIONS);
+                }
+                for _ in 0..count.min(repeat.max().unwrap_or(usize::MAX)) {
+                    self.sample_node(node, depth, lexical, out);
+                }
+            }
+            Node::Rulename(name) if lexical || self.grammar.lexical.contains(name.as_str()) => {
+                if !lexical {
+                    self.separate(out);
+                }
+                self.sample_rule(name, depth, out);
+            }
+            Node::Rulename(name) => self.sample_rule(name, depth, out),
+            Node::Group(node) => self.sample_node(node, depth, lexical, out),
+            Node::Optional(node) => {
+                if !shortest && self.rng.gen() {
+                    self.sample_node(node, depth, lexical, out);
+                }
+            }
+            Node::String(string) => {
+                if !lexical {
+                    self.separate(out);
+                }
+                for c in string.value().chars() {
+                    // Case-insensitive strings match either case.
+                    if !string.is_case_sensitive() && self.rng.gen() {
+                        out.push(c.to_ascii_uppercase());
+                    } else {
+                        out.push(c);
+                    }
+                }
+            }
+            Node::TerminalValues(TerminalValues::Range(low, high)) => {
+                let c = loop {
+                    if let Some(c) = char::from_u32(self.rng.gen_range(*low..=*high)) {
+                        break c;
+                    }
+                };
+                out.push(c);
+            }
+            Node::TerminalValues(TerminalValues::Concatenation(values)) => {
+                out.extend(values.iter().filter_map(|value| char::from_u32(*value)));
+            }
+            Node::Prose(prose) => panic!("cannot sample the prose rule <{}>", prose),
+        }
+    }
+
+    /// Separates the next token from the previous one, if any, and records where it starts.
+    fn separate(&mut self, out: &mut String) {
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        self.token_starts.push(out.len());
+    }
+}
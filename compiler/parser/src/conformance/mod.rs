@@ -0,0 +1,459 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Checks that the tokenizer and the parser accept exactly what the ABNF grammar in `docs/grammar` describes.
+//!
+//! Samples are derived at random from the rules of the grammar, and mutated: samples of lexical rules by a character,
+//! samples of syntactic rules by a token. The grammar decides whether each mutant is still derivable,
+//! so that the tokenizer and the parser are checked on input they should reject as well.
+//!
+//! Where Leo knowingly departs from the grammar, the disagreement is listed as known along with the reason.
+//! A known disagreement that no longer shows is reported too, so that the list stays current.
+
+mod grammar;
+use grammar::{Grammar, Sampler};
+
+use crate::{tokenizer, ParserContext, SpannedToken, Token};
+use leo_ast::{Expression, GroupLiteral, Literal};
+use leo_errors::{emitter::Handler, Result};
+use leo_span::{
+    span::{BytePos, Pos},
+    symbol::create_session_if_not_set_then,
+};
+
+use indexmap::{IndexMap, IndexSet};
+use std::fmt::Write;
+
+/// The seed of the random samples.
+const SEED: u64 = 0;
+
+/// The number of samples derived from each rule.
+const SAMPLES: usize = 200;
+
+/// The number of mutants derived from each sample.
+const MUTANTS: usize = 4;
+
+/// The number of disagreements shown for each rule.
+const SHOWN_DISAGREEMENTS: usize = 3;
+
+/// How the tokenizer or the parser decides whether a string belongs to a rule.
+#[derive(Clone, Copy)]
+enum Check {
+    /// The string lexes as a single token satisfying the predicate.
+    Token(fn(&Token) -> bool),
+    /// The string lexes as whitespace only.
+    Whitespace,
+    /// The string parses as a literal satisfying the predicate.
+    Literal(fn(&Literal) -> bool),
+    /// The string parses as a type.
+    Type,
+    /// The string parses as an expression.
+    Expression,
+    /// The string parses as a statement.
+    Statement,
+    /// The string parses as a program.
+    File,
+}
+
+/// The checked rules of the lexical grammar.
+const LEXICAL_RULES: &[(&str, Check)] = &[
+    ("whitespace", Check::Whitespace),
+    (
+        "comment",
+        Check::Token(|t| matches!(t, Token::CommentBlock(_) | Token::CommentLine(_))),
+    ),
+    ("block-comment", Check::Token(|t| matches!(t, Token::CommentBlock(_)))),
+    (
+        "end-of-line-comment",
+        Check::Token(|t| matches!(t, Token::CommentLine(_))),
+    ),
+    (
+        "keyword",
+        Check::Token(|t| t.is_keyword() && !matches!(t, Token::True | Token::False)),
+    ),
+    ("identifier", Check::Token(|t| matches!(t, Token::Identifier(_)))),
+    ("numeral", Check::Token(|t| matches!(t, Token::Integer(_)))),
+    (
+        "boolean-literal",
+        Check::Token(|t| matches!(t, Token::True | Token::False)),
+    ),
+    ("address-literal", Check::Token(|t| matches!(t, Token::AddressLit(_)))),
+    ("string-literal", Check::Token(|t| matches!(t, Token::StaticString(_)))),
+    ("unsigned-literal", Check::Literal(is_unsigned)),
+    ("signed-literal", Check::Literal(is_signed)),
+    ("field-literal", Check::Literal(|l| matches!(l, Literal::Field(..)))),
+    ("product-group-literal", Check::Literal(is_product_group)),
+    ("scalar-literal", Check::Literal(|l| matches!(l, Literal::Scalar(..)))),
+    (
+        "atomic-literal",
+        Check::Literal(|l| !matches!(l, Literal::Group(g) if matches!(**g, GroupLiteral::Tuple(_)))),
+    ),
+];
+
+/// The checked rules of the syntactic grammar.
+const SYNTACTIC_RULES: &[(&str, Check)] = &[
+    ("type", Check::Type),
+    ("literal", Check::Literal(|_| true)),
+    ("expression", Check::Expression),
+    ("statement", Check::Statement),
+    ("file", Check::File),
+];
+
+/// A disagreement between the grammar and Leo that is known and accepted.
+struct KnownDisagreement {
+    /// The rules whose samples may show it.
+    rules: &'static [&'static str],
+    /// Returns `true` if a sample shows it.
+    shows_on: fn(&str) -> bool,
+    /// Why Leo disagrees with the grammar.
+    reason: &'static str,
+}
+
+const KNOWN_DISAGREEMENTS: &[KnownDisagreement] = &[
+    KnownDisagreement {
+        rules: &["whitespace"],
+        shows_on: |sample| sample == "\u{c}",
+        reason: "the tokenizer also eats form feeds as whitespace",
+    },
+    KnownDisagreement {
+        rules: &["comment", "end-of-line-comment"],
+        shows_on: |sample| sample.starts_with("//") && (sample.contains('\r') || sample.ends_with('\n')),
+        reason: "the tokenizer only ends line comments at line feeds, which it includes in the comment",
+    },
+    KnownDisagreement {
+        rules: &["address-literal"],
+        shows_on: |sample| matches!(Token::eat(sample), Ok((length, Token::AddressLit(_))) if length == sample.len()),
+        reason: "the tokenizer lexes every identifier starting with `aleo1` as an address, which the parser validates",
+    },
+    KnownDisagreement {
+        rules: &["atomic-literal", "literal", "expression", "statement", "file"],
+        shows_on: |sample| {
+            let (code, _) = split_strings(sample);
+            code.split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .any(is_address)
+        },
+        reason: "the parser also checks the bech32m checksum of addresses",
+    },
+    KnownDisagreement {
+        rules: &[
+            "string-literal",
+            "atomic-literal",
+            "literal",
+            "expression",
+            "statement",
+            "file",
+        ],
+        shows_on: |sample| {
+            let (_, strings) = split_strings(sample);
+            strings.iter().any(|string| string.contains(&['\\', '\n', '\r'][..]))
+        },
+        reason: "the tokenizer does not interpret escapes, and ends strings at their first double quote",
+    },
+    KnownDisagreement {
+        rules: &["type", "expression", "statement", "file"],
+        shows_on: |sample| {
+            let tokens = code_tokens(sample);
+            (0..tokens.len().saturating_sub(1)).any(|i| {
+                tokens[i] == "("
+                    && tokens[i + 1] == ")"
+                    && (i == 0 || [":", "->", "(", ","].contains(&tokens[i - 1].as_str()))
+            })
+        },
+        reason: "there is no unit type",
+    },
+    KnownDisagreement {
+        rules: &["file"],
+        shows_on: |sample| code_tokens(sample).windows(2).any(|w| w[0] == "@" && w[1] != "test"),
+        reason: "`@test` is the only annotation",
+    },
+    KnownDisagreement {
+        rules: &["expression", "statement", "file"],
+        shows_on: |sample| {
+            code_tokens(sample).windows(3).any(|w| {
+                w[0] == "."
+                    && w[2] == "("
+                    && !w[1].starts_with(|c: char| c.is_ascii_digit())
+                    && !OPERATORS.contains(&w[1].as_str())
+            })
+        },
+        reason: "operator calls name one of the operators",
+    },
+    KnownDisagreement {
+        rules: &["file"],
+        shows_on: |sample| {
+            code_tokens(sample).windows(3).any(|w| {
+                w[2] == ":"
+                    && ![
+                        "{", "}", ",", ";", "(", "?", "let", "const", "constant", "public", "private", "for",
+                    ]
+                    .contains(&w[0].as_str())
+            })
+        },
+        reason: "the members of circuits and records need no separators",
+    },
+];
+
+/// The names of the operators that can be called as methods, e.g. `a.add(b)`.
+const OPERATORS: &[&str] = &[
+    "abs",
+    "abs_wrapped",
+    "double",
+    "inv",
+    "neg",
+    "not",
+    "square",
+    "square_root",
+    "add",
+    "add_wrapped",
+    "and",
+    "div",
+    "div_wrapped",
+    "eq",
+    "gte",
+    "gt",
+    "lte",
+    "lt",
+    "mul",
+    "mul_wrapped",
+    "nand",
+    "neq",
+    "nor",
+    "or",
+    "pow",
+    "pow_wrapped",
+    "shl",
+    "shl_wrapped",
+    "shr",
+    "shr_wrapped",
+    "sub",
+    "sub_wrapped",
+    "xor",
+];
+
+/// Returns the tokens of a sample of a syntactic rule outside of comments and string literals.
+fn code_tokens(sample: &str) -> Vec<String> {
+    let (code, _) = split_strings(sample);
+    code.split_whitespace().map(str::to_string).collect()
+}
+
+/// Returns `true` if `word` has the form of an address literal.
+fn is_address(word: &str) -> bool {
+    word.strip_prefix("aleo1").map_or(false, |rest| {
+        rest.len() == 58 && rest.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    })
+}
+
+/// Splits a sample into its code outside of comments and string literals, and the contents of its string literals.
+/// As in the grammar, a string literal ends at the first double quote that is not escaped.
+fn split_strings(sample: &str) -> (String, Vec<String>) {
+    let mut code = String::new();
+    let mut strings = Vec::new();
+    let mut chars = sample.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('"', _) => {
+                let mut string = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => string.extend(std::iter::once(c).chain(chars.next())),
+                        c => string.push(c),
+                    }
+                }
+                strings.push(string);
+                code.push(' ');
+            }
+            ('/', Some('/')) => {
+                chars.by_ref().find(|c| *c == '\n');
+                code.push(' ');
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = None;
+                chars.by_ref().find(|c| prev.replace(*c) == Some('*') && *c == '/');
+                code.push(' ');
+            }
+            (c, _) => code.push(c),
+        }
+    }
+    (code, strings)
+}
+
+fn is_unsigned(literal: &Literal) -> bool {
+    matches!(
+        literal,
+        Literal::U8(..) | Literal::U16(..) | Literal::U32(..) | Literal::U64(..) | Literal::U128(..)
+    )
+}
+
+fn is_signed(literal: &Literal) -> bool {
+    matches!(
+        literal,
+        Literal::I8(..) | Literal::I16(..) | Literal::I32(..) | Literal::I64(..) | Literal::I128(..)
+    )
+}
+
+fn is_product_group(literal: &Literal) -> bool {
+    matches!(literal, Literal::Group(group) if matches!(**group, GroupLiteral::Single(..)))
+}
+
+/// Returns `true` if the tokenizer and the parser accept `input` according to `check`.
+fn accepts(check: Check, input: &str) -> bool {
+    let tokens = match check {
+        Check::Token(is_token) => {
+            return matches!(Token::eat(input), Ok((length, token)) if length == input.len() && is_token(&token));
+        }
+        Check::Whitespace => {
+            // The tokenizer eats CR LF as two whitespace lexemes, which the grammar derives as one line terminator.
+            return input == "\r\n"
+                || matches!(Token::eat(input), Ok((length, Token::WhiteSpace)) if length == input.len());
+        }
+        _ => match tokenizer::tokenize(input, BytePos(0)) {
+            Ok(tokens) => tokens,
+            Err(_) => return false,
+        },
+    };
+    // Syntactic rules derive neither leading nor trailing whitespace and comments.
+    let is_trivia = |token: &SpannedToken| matches!(token.token, Token::CommentBlock(_) | Token::CommentLine(_));
+    match (tokens.first(), tokens.last()) {
+        (None, None) if input.is_empty() => {}
+        (Some(first), Some(last))
+            if first.span.lo == BytePos(0)
+                && last.span.hi == BytePos::from_usize(input.len())
+                && !is_trivia(first)
+                && !is_trivia(last) => {}
+        _ => return false,
+    }
+    match check {
+        Check::Literal(is_literal) => match parse(tokens, |p| p.parse_expression()) {
+            Some(Expression::Literal(literal)) => is_literal(&literal),
+            _ => false,
+        },
+        Check::Type => parse(tokens, |p| p.parse_type()).is_some(),
+        Check::Expression => parse(tokens, |p| p.parse_expression()).is_some(),
+        Check::Statement => parse(tokens, |p| p.parse_statement()).is_some(),
+        Check::File => parse(tokens, |p| p.parse_program()).is_some(),
+        Check::Token(_) | Check::Whitespace => unreachable!(),
+    }
+}
+
+/// Runs `logic` on `tokens`, returning its output if it consumed every token without errors.
+fn parse<T>(tokens: Vec<SpannedToken>, logic: impl FnOnce(&mut ParserContext<'_>) -> Result<T>) -> Option<T> {
+    let (handler, _) = Handler::new_with_buf();
+    let mut context = ParserContext::new(&handler, tokens);
+    let parsed = logic(&mut context).ok()?;
+    (!context.has_next() && !handler.had_errors()).then(|| parsed)
+}
+
+/// The strings on which the grammar and the tokenizer or the parser disagree, by rule.
+#[derive(Default)]
+struct Disagreements {
+    unknown: IndexMap<&'static str, Vec<(String, bool)>>,
+    /// The indices of the known disagreements that showed.
+    known: IndexSet<usize>,
+}
+
+impl Disagreements {
+    /// Checks `input` against `rule`, given whether the grammar derives it.
+    fn check(&mut self, rule: &'static str, check: Check, input: String, derivable: bool) {
+        if accepts(check, &input) == derivable {
+            return;
+        }
+        let known = KNOWN_DISAGREEMENTS
+            .iter()
+            .position(|known| known.rules.contains(&rule) && (known.shows_on)(&input));
+        match known {
+            Some(index) => {
+                self.known.insert(index);
+            }
+            None => self.unknown.entry(rule).or_default().push((input, derivable)),
+        }
+    }
+
+    /// Describes the unknown disagreements, showing the shortest few for each rule,
+    /// and the known disagreements that no longer show.
+    fn report(&mut self) -> String {
+        let mut report = String::new();
+        for (rule, disagreements) in &mut self.unknown {
+            writeln!(report, "rule `{}` disagrees on {} samples:", rule, disagreements.len()).unwrap();
+            disagreements.sort_by_key(|(input, _)| input.len());
+            for (input, derivable) in disagreements.iter().take(SHOWN_DISAGREEMENTS) {
+                let (grammar, parser) = if *derivable {
+                    ("accepts", "rejects")
+                } else {
+                    ("rejects", "accepts")
+                };
+                writeln!(report, "    the grammar {} but Leo {} {:?}", grammar, parser, input).unwrap();
+            }
+        }
+        for (index, known) in KNOWN_DISAGREEMENTS.iter().enumerate() {
+            if !self.known.contains(&index) {
+                writeln!(report, "the known disagreement that {} no longer shows", known.reason).unwrap();
+            }
+        }
+        report
+    }
+}
+
+#[test]
+fn grammar_conformance() {
+    create_session_if_not_set_then(|_| {
+        let grammar = Grammar::load();
+        let mut sampler = Sampler::new(&grammar, SEED);
+        let mut disagreements = Disagreements::default();
+
+        for (rule, check) in LEXICAL_RULES.iter().copied() {
+            assert!(grammar.has_rule(rule), "the grammar has no rule `{}`", rule);
+            for _ in 0..SAMPLES {
+                let sample = sampler.sample(rule);
+                for _ in 0..MUTANTS {
+                    let mutant = sampler.mutate(&sample);
+                    // The empty string lexes as nothing, which tells nothing about any rule.
+                    if !mutant.is_empty() {
+                        let derivable = grammar.matches(rule, &mutant);
+                        disagreements.check(rule, check, mutant, derivable);
+                    }
+                }
+                disagreements.check(rule, check, sample, true);
+            }
+        }
+
+        for (rule, check) in SYNTACTIC_RULES.iter().copied() {
+            assert!(grammar.has_rule(rule), "the grammar has no rule `{}`", rule);
+            for _ in 0..SAMPLES {
+                let sample = sampler.sample_tokens(rule);
+                assert!(
+                    grammar.matches_tokens(rule, &sample),
+                    "the grammar should derive its sample {:?}",
+                    sample.join(" ")
+                );
+                for _ in 0..MUTANTS {
+                    let mutant = sampler.mutate_tokens(&sample);
+                    if !mutant.is_empty() {
+                        let derivable = grammar.matches_tokens(rule, &mutant);
+                        disagreements.check(rule, check, mutant.join(" "), derivable);
+                    }
+                }
+                disagreements.check(rule, check, sample.join(" "), true);
+            }
+        }
+
+        let report = disagreements.report();
+        if !report.is_empty() {
+            panic!("the ABNF grammar and Leo disagree:\n{}", report);
+        }
+    })
+}
@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The formatter to print Leo code text in its canonical form.
+//!
+//! This module contains the [`format()`] method which parses the code text
+//! and prints the resulting [`Program`] AST back, together with the comments of the text.
+
+use crate::{tokenizer::*, ParserContext, Token};
+
+use leo_ast::{Comment, Printer, Program};
+use leo_errors::emitter::Handler;
+use leo_errors::Result;
+use leo_span::span::BytePos;
+
+/// Formats the given source code text.
+///
+/// The text is only formatted if it parses without errors, which are reported to the handler.
+pub fn format(handler: &Handler, source: &str, start_pos: BytePos) -> Result<String> {
+    let tokens = crate::tokenize(source, start_pos)?;
+    let comments = tokens
+        .iter()
+        .filter_map(|SpannedToken { token, span }| match token {
+            Token::CommentLine(text) | Token::CommentBlock(text) => Some(Comment {
+                text: text.trim_end().to_string(),
+                span: *span,
+            }),
+            _ => None,
+        })
+        .collect();
+
+    let errors = handler.err_count();
+    let program: Program = ParserContext::new(handler, tokens).parse_program()?;
+    handler.last_err_after(errors)?;
+
+    Ok(Printer::with_comments(source, start_pos, comments).print_program(&program))
+}
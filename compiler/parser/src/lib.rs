@@ -30,6 +30,9 @@ pub(crate) use tokenizer::*;
 pub mod parser;
 pub use parser::*;
 
+pub mod formatter;
+pub use formatter::*;
+
 use leo_ast::{Ast, Input, ProgramInput};
 use leo_errors::emitter::Handler;
 use leo_errors::Result;
@@ -37,6 +40,9 @@ use leo_errors::Result;
 #[cfg(test)]
 mod test;
 
+#[cfg(test)]
+mod conformance;
+
 /// Creates a new AST from a given file path and source code text.
 pub fn parse_ast(handler: &Handler, source: &str, start_pos: BytePos) -> Result<Ast> {
     Ok(Ast::new(parser::parse(handler, source, start_pos)?))
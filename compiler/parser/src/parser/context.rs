@@ -19,8 +19,9 @@ use crate::{tokenizer::*, Token};
 use leo_ast::*;
 use leo_errors::emitter::Handler;
 use leo_errors::{ParserError, ParserWarning, Result};
-use leo_span::{Span, Symbol};
+use leo_span::{span::BytePos, Span, Symbol};
 
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::mem;
 
@@ -39,6 +40,8 @@ pub(crate) struct ParserContext<'a> {
     pub(crate) prev_token: SpannedToken,
     /// true if parsing an expression for if and loop statements -- means circuit inits are not legal
     pub(crate) disallow_circuit_construction: bool,
+    /// The doc comments, by the start of the token they precede.
+    docs: HashMap<BytePos, Vec<String>>,
 }
 
 /// Dummy span used to appease borrow checker.
@@ -50,8 +53,22 @@ const DUMMY_EOF: SpannedToken = SpannedToken {
 impl<'a> ParserContext<'a> {
     /// Returns a new [`ParserContext`] type given a vector of tokens.
     pub fn new(handler: &'a Handler, mut tokens: Vec<SpannedToken>) -> Self {
-        // Strip out comments.
-        tokens.retain(|x| !matches!(x.token, Token::CommentLine(_) | Token::CommentBlock(_)));
+        // Strip out comments, keeping doc comments for the token they precede.
+        let mut docs = HashMap::new();
+        let mut pending = Vec::new();
+        tokens.retain(|x| match &x.token {
+            Token::CommentLine(comment) => {
+                pending.extend(doc_comment(comment));
+                false
+            }
+            Token::CommentBlock(_) => false,
+            _ => {
+                if !pending.is_empty() {
+                    docs.insert(x.span.lo, mem::take(&mut pending));
+                }
+                true
+            }
+        });
         // For performance we reverse so that we get cheap `.pop()`s.
         tokens.reverse();
 
@@ -59,6 +76,7 @@ impl<'a> ParserContext<'a> {
         let mut p = Self {
             handler,
             disallow_circuit_construction: false,
+            docs,
             prev_token: token.clone(),
             token,
             tokens,
@@ -87,6 +105,16 @@ impl<'a> ParserContext<'a> {
         self.prev_token = mem::replace(&mut self.token, next_token);
     }
 
+    /// Removes and returns the doc comments preceding the current token.
+    pub(super) fn take_docs(&mut self) -> Vec<String> {
+        self.docs.remove(&self.token.span.lo).unwrap_or_default()
+    }
+
+    /// Returns the number of tokens after the current one.
+    pub(super) fn tokens_remaining(&self) -> usize {
+        self.tokens.len()
+    }
+
     /// Checks whether the current token is `tok`.
     pub(super) fn check(&self, tok: &Token) -> bool {
         &self.token.token == tok
@@ -246,3 +274,10 @@ impl<'a> ParserContext<'a> {
         matches!(self.token.token, Token::LeftParen)
     }
 }
+
+/// Returns the text of `comment` if it is a doc comment, i.e., starts with `///` but not `////`.
+fn doc_comment(comment: &str) -> Option<String> {
+    let text = comment.strip_prefix("///").filter(|text| !text.starts_with('/'))?;
+    let text = text.trim_end_matches(&['\n', '\r'][..]);
+    Some(text.strip_prefix(' ').unwrap_or(text).to_owned())
+}
@@ -15,41 +15,44 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use crate::parse_ast;
-use leo_errors::{CompilerError, ParserError, ParserWarning, Result};
-use leo_span::source_map::FileName;
+use leo_errors::{ParserError, ParserWarning, Result};
 use leo_span::sym;
-use leo_span::symbol::with_session_globals;
-
-use std::fs;
 
 impl ParserContext<'_> {
     /// Returns a [`Program`] AST if all tokens can be consumed and represent a valid Leo program.
+    /// Malformed items and statements are reported to the handler and skipped,
+    /// so that the returned program contains the well-formed parts of the file.
     pub fn parse_program(&mut self) -> Result<Program> {
         let mut imports = IndexMap::new();
         let mut functions = IndexMap::new();
         let mut circuits = IndexMap::new();
 
         while self.has_next() {
-            match &self.token.token {
-                Token::Import => {
-                    let (id, import) = self.parse_import()?;
+            let remaining = self.tokens_remaining();
+            let item = match &self.token.token {
+                Token::Import => self.parse_import().map(|(id, import)| {
                     imports.insert(id, import);
-                }
-                Token::Circuit | Token::Record => {
-                    let (id, circuit) = self.parse_circuit()?;
+                }),
+                Token::Circuit | Token::Record => self.parse_circuit().map(|(id, circuit)| {
                     circuits.insert(id, circuit);
-                }
-                Token::Const if self.peek_is_function() => {
-                    let (id, function) = self.parse_function()?;
+                }),
+                Token::Const if self.peek_is_function() => self.parse_function().map(|(id, function)| {
                     functions.insert(id, function);
-                }
-                Token::Identifier(sym::test) => return Err(ParserError::test_function(self.token.span).into()),
-                Token::Function => {
-                    let (id, function) = self.parse_function()?;
+                }),
+                Token::Identifier(sym::test) => Err(ParserError::test_function(self.token.span).into()),
+                Token::At | Token::Function => self.parse_function().map(|(id, function)| {
                     functions.insert(id, function);
+                }),
+                _ => Err(Self::unexpected_item(&self.token).into()),
+            };
+
+            if let Err(err) = item {
+                self.handler.emit_err(err);
+                // Always make progress, even if the item failed on its first token.
+                if self.tokens_remaining() == remaining && self.has_next() {
+                    self.bump();
                 }
-                _ => return Err(Self::unexpected_item(&self.token).into()),
+                self.recover_to_item();
             }
         }
         Ok(Program {
@@ -62,6 +65,21 @@ impl ParserContext<'_> {
         })
     }
 
+    /// Returns `true` if the current token starts an import, circuit, record, or function.
+    pub(super) fn peek_is_item(&self) -> bool {
+        matches!(
+            &self.token.token,
+            Token::Import | Token::Circuit | Token::Record | Token::At | Token::Function
+        ) || self.peek_is_function()
+    }
+
+    /// Skips tokens up to the start of the next item, or the end of the file.
+    fn recover_to_item(&mut self) {
+        while self.has_next() && !self.peek_is_item() {
+            self.bump();
+        }
+    }
+
     fn unexpected_item(token: &SpannedToken) -> ParserError {
         ParserError::unexpected(
             &token.token,
@@ -75,6 +93,7 @@ impl ParserContext<'_> {
     }
 
     /// Parses an import statement `import foo.leo;`.
+    /// The imported file itself is not read, see [`Program::imports`].
     pub(super) fn parse_import(&mut self) -> Result<(Identifier, Program)> {
         // Parse `import`.
         let _start = self.expect(&Token::Import)?;
@@ -92,33 +111,9 @@ impl ParserContext<'_> {
         }
         let _end = self.expect(&Token::Semicolon)?;
 
-        // Tokenize and parse import file.
-        // Todo: move this to a different module.
-        let mut import_file_path =
-            std::env::current_dir().map_err(|err| CompilerError::cannot_open_cwd(err, self.token.span))?;
-        import_file_path.push("imports");
-        import_file_path.push(format!("{}.leo", import_name.name));
-
-        // Throw an error if the import file doesn't exist.
-        if !import_file_path.exists() {
-            return Err(CompilerError::import_not_found(import_file_path.display(), self.token.span).into());
-        }
-
-        // Read the import file into string.
-        // Todo: protect against cyclic imports.
-        let program_string =
-            fs::read_to_string(&import_file_path).map_err(|e| CompilerError::file_read_error(&import_file_path, e))?;
-
-        // Create import file name.
-        let name: FileName = FileName::Real(import_file_path);
-
-        // Register the source (`program_string`) in the source map.
-        let prg_sf = with_session_globals(|s| s.source_map.new_source(&program_string, name));
-
-        // Use the parser to construct the imported abstract syntax tree (ast).
-        let program_ast = parse_ast(self.handler, &prg_sf.src, prg_sf.start_pos)?;
-
-        Ok((import_name, program_ast.into_repr()))
+        // The imported program is parsed separately by the caller, who resolves it from the import name.
+        // Record an empty program in its place.
+        Ok((import_name, Program::default()))
     }
 
     /// Returns a [`Vec<CircuitMember>`] AST node if the next tokens represent a circuit member variable
@@ -190,9 +185,10 @@ impl ParserContext<'_> {
 
     /// Returns a [`CircuitMember`] AST node if the next tokens represent a circuit member variable.
     fn parse_member_variable_declaration(&mut self) -> Result<CircuitMember> {
+        let docs = self.take_docs();
         let (name, type_) = self.parse_typed_ident()?;
 
-        Ok(CircuitMember::CircuitVariable(name, type_))
+        Ok(CircuitMember::CircuitVariable(name, type_, docs))
     }
 
     /// Returns a [`CircuitMember`] AST node if the next tokens represent a circuit member function.
@@ -210,6 +206,7 @@ impl ParserContext<'_> {
 
     /// Parses a circuit or record definition, e.g., `circit Foo { ... }` or `record Foo { ... }`.
     pub(super) fn parse_circuit(&mut self) -> Result<(Identifier, Circuit)> {
+        let docs = self.take_docs();
         let is_record = matches!(&self.token.token, Token::Record);
         let start = self.expect_any(&[Token::Circuit, Token::Record])?;
         let circuit_name = self.expect_identifier()?;
@@ -220,6 +217,7 @@ impl ParserContext<'_> {
         Ok((
             circuit_name,
             Circuit {
+                docs,
                 identifier: circuit_name,
                 members,
                 is_record,
@@ -261,24 +259,90 @@ impl ParserContext<'_> {
         )))
     }
 
+    /// Skips tokens up to the `,` or `)` that ends the current parameter.
+    /// Returns `false` if the parameter list ends first, e.g. at the `{` of the function body.
+    fn recover_to_parameter(&mut self) -> bool {
+        let mut depth = 0usize;
+        loop {
+            match &self.token.token {
+                Token::Eof | Token::LeftCurly | Token::Function => return false,
+                Token::Comma | Token::RightParen if depth == 0 => return true,
+                Token::LeftParen => depth += 1,
+                Token::RightParen => depth -= 1,
+                _ => {}
+            }
+            self.bump();
+        }
+    }
+
     /// Returns `true` if the next token is Function or if it is a Const followed by Function.
     /// Returns `false` otherwise.
-    fn peek_is_function(&self) -> bool {
+    pub(super) fn peek_is_function(&self) -> bool {
         matches!(
             (&self.token.token, self.look_ahead(1, |t| &t.token)),
             (Token::Function, _) | (Token::Const, Token::Function)
         )
     }
 
+    /// Returns an [`Annotation`] AST node if the next tokens represent an annotation, e.g. `@test`.
+    /// Unknown annotations are reported, but kept, so that the annotated function is still parsed.
+    fn parse_annotation(&mut self) -> Result<Annotation> {
+        // Parse `@IDENT`.
+        let start = self.expect(&Token::At)?;
+        let identifier = self.expect_identifier()?;
+        let annotation = Annotation {
+            identifier,
+            span: start + identifier.span,
+        };
+
+        if !annotation.is_test() {
+            self.emit_err(ParserError::unknown_annotation(&annotation, annotation.span));
+
+            // Skip the arguments of the unknown annotation, e.g. `(a, b)`, up to the annotated function.
+            if self.check(&Token::LeftParen) {
+                let mut depth = 0usize;
+                loop {
+                    match &self.token.token {
+                        Token::Eof => break,
+                        Token::LeftParen => depth += 1,
+                        Token::RightParen if depth == 1 => {
+                            self.bump();
+                            break;
+                        }
+                        Token::RightParen => depth -= 1,
+                        _ => {}
+                    }
+                    self.bump();
+                }
+            }
+        }
+        Ok(annotation)
+    }
+
     /// Returns an [`(Identifier, Function)`] AST node if the next tokens represent a function name
     /// and function definition.
     fn parse_function(&mut self) -> Result<(Identifier, Function)> {
+        // Parse the annotations, e.g. `@test`.
+        let docs = self.take_docs();
+        let start = self.token.span;
+        let mut annotations = Vec::new();
+        while self.check(&Token::At) {
+            annotations.push(self.parse_annotation()?);
+        }
+
         // Parse `function IDENT`.
-        let start = self.expect(&Token::Function)?;
+        self.expect(&Token::Function)?;
         let name = self.expect_identifier()?;
 
-        // Parse parameters.
-        let (inputs, ..) = self.parse_paren_comma_list(|p| p.parse_function_parameter().map(Some))?;
+        // Parse parameters. A malformed parameter is reported and skipped, so that the others are parsed.
+        let (inputs, ..) = self.parse_paren_comma_list(|p| match p.parse_function_parameter() {
+            Ok(input) => Ok(Some(input)),
+            Err(err) if p.recover_to_parameter() => {
+                p.handler.emit_err(err);
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        })?;
 
         // Parse return type.
         self.expect(&Token::Arrow)?;
@@ -292,6 +356,8 @@ impl ParserContext<'_> {
         Ok((
             name,
             Function {
+                docs,
+                annotations,
                 identifier: name,
                 input: inputs,
                 output,
@@ -59,9 +59,50 @@ impl ParserContext<'_> {
     }
 
     /// Returns a [`Block`] AST node if the next tokens represent a block of statements.
+    /// Malformed statements are reported to the handler and left out of the block.
     pub(super) fn parse_block(&mut self) -> Result<Block> {
-        self.parse_list(Delimiter::Brace, None, |p| p.parse_statement().map(Some))
-            .map(|(statements, _, span)| Block { statements, span })
+        let open_span = self.expect(&Token::LeftCurly)?;
+        let mut statements = Vec::new();
+
+        while !self.check(&Token::RightCurly) {
+            let remaining = self.tokens_remaining();
+            match self.parse_statement() {
+                Ok(statement) => statements.push(statement),
+                // Recovery stops at the end of the block, errors that run past it are left to the caller.
+                Err(err) if !self.recover_to_statement(remaining) => return Err(err),
+                Err(err) => self.handler.emit_err(err),
+            }
+        }
+
+        let span = open_span + self.expect(&Token::RightCurly)?;
+        Ok(Block { statements, span })
+    }
+
+    /// Skips tokens past the `;` ending the current statement, or up to the `}` ending the current block.
+    /// The statement started with `remaining` tokens left.
+    /// Returns `false` if the end of the file or the start of an item is reached first.
+    fn recover_to_statement(&mut self, remaining: usize) -> bool {
+        // The statement may have failed on its own `;`.
+        if self.tokens_remaining() < remaining && self.prev_token.token == Token::Semicolon {
+            return true;
+        }
+
+        let mut depth = 0usize;
+        loop {
+            match &self.token.token {
+                Token::Eof => return false,
+                _ if self.peek_is_item() => return false,
+                Token::LeftCurly => depth += 1,
+                Token::RightCurly if depth == 0 => return true,
+                Token::RightCurly => depth -= 1,
+                Token::Semicolon if depth == 0 => {
+                    self.bump();
+                    return true;
+                }
+                _ => {}
+            }
+            self.bump();
+        }
     }
 
     /// Returns a [`ReturnStatement`] AST node if the next tokens represent a return statement.
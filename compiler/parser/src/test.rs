@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{tokenizer, ParserContext, SpannedToken};
-use leo_ast::Statement;
+use leo_ast::{Identifier, Printer, Statement};
 use leo_errors::{emitter::Handler, LeoError};
 use leo_span::{
     source_map::FileName,
@@ -218,6 +218,32 @@ impl Namespace for InputNamespace {
     }
 }
 
+struct FormatNamespace;
+
+impl Namespace for FormatNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        create_session_if_not_set_then(|s| {
+            let format = |source: &str| {
+                let sf = s.source_map.new_source(source, FileName::Custom("test".into()));
+                let (handler, buf) = Handler::new_with_buf();
+                handler
+                    .extend_if_error(crate::format(&handler, &sf.src, sf.start_pos))
+                    .map_err(|_| buf.extract_errs().to_string())
+            };
+
+            let formatted = format(&test.content)?;
+            if format(&formatted)? != formatted {
+                return Err(format!("formatting is not idempotent:\n{}", formatted));
+            }
+            Ok(Value::String(formatted))
+        })
+    }
+}
+
 struct TestRunner;
 
 impl Runner for TestRunner {
@@ -229,6 +255,7 @@ impl Runner for TestRunner {
             "Serialize" => Box::new(SerializeNamespace),
             "Input" => Box::new(InputNamespace),
             "Token" => Box::new(TokenNamespace),
+            "Format" => Box::new(FormatNamespace),
             _ => return None,
         })
     }
@@ -238,3 +265,119 @@ impl Runner for TestRunner {
 pub fn parser_tests() {
     leo_test_framework::run_tests(&TestRunner, "parser");
 }
+
+/// Removes all spans from AST JSON: the `span` keys, and the spans within the strings that identifiers are serialized to.
+fn remove_spans_from_json(value: serde_json::Value) -> serde_json::Value {
+    // An identifier is serialized to a string holding its name and span, and is replaced by its name.
+    let remove_span =
+        |string: String| match serde_json::from_value::<Identifier>(serde_json::Value::String(string.clone())) {
+            Ok(identifier) => identifier.name.to_string(),
+            Err(_) => string,
+        };
+    match value {
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.into_iter()
+                .filter(|(k, _)| k != "span")
+                .map(|(k, v)| (remove_span(k), remove_spans_from_json(v)))
+                .collect(),
+        ),
+        serde_json::Value::Array(values) => values.into_iter().map(remove_spans_from_json).collect(),
+        serde_json::Value::String(string) => serde_json::Value::String(remove_span(string)),
+        _ => value,
+    }
+}
+
+/// Checks that printing the AST of each test and parsing the printed code yields the same AST, apart from spans.
+/// Tests that do not parse are skipped.
+struct RoundTripNamespace(ParseType);
+
+impl RoundTripNamespace {
+    fn round_trip<T: Serialize>(
+        s: &SessionGlobals,
+        source: &str,
+        parse: impl Fn(&mut ParserContext<'_>) -> Result<T, LeoError>,
+        print: impl Fn(&T) -> String,
+    ) -> Result<Value, String> {
+        let parse = |source: &str| {
+            let sf = s.source_map.new_source(source, FileName::Custom("test".into()));
+            let tokens = tokenizer::tokenize(&sf.src, sf.start_pos).map_err(|x| x.to_string())?;
+            with_handler(tokens, &parse)
+        };
+
+        let ast = match parse(source) {
+            Ok(ast) => ast,
+            Err(_) => return Ok(Value::Null),
+        };
+        let printed = print(&ast);
+        let reparsed = parse(&printed).map_err(|e| format!("the printed code does not parse:\n{}\n{}", printed, e))?;
+
+        let without_spans =
+            |ast: &T| remove_spans_from_json(serde_json::to_value(ast).expect("failed to convert to json value"));
+        if without_spans(&ast) != without_spans(&reparsed) {
+            return Err(format!("the printed code parses into a different AST:\n{}", printed));
+        }
+        Ok(Value::Null)
+    }
+}
+
+impl Namespace for RoundTripNamespace {
+    fn parse_type(&self) -> ParseType {
+        self.0
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        create_session_if_not_set_then(|s| match self.0 {
+            ParseType::Line => Self::round_trip(
+                s,
+                &test.content,
+                |p| p.parse_expression(),
+                |expression| Printer::default().print_expression(expression),
+            ),
+            ParseType::ContinuousLines => Self::round_trip(
+                s,
+                &test.content,
+                |p| p.parse_statement(),
+                |statement| Printer::default().print_statement(statement),
+            ),
+            ParseType::Whole => Self::round_trip(
+                s,
+                &test.content,
+                |p| p.parse_program(),
+                |program| Printer::default().print_program(program),
+            ),
+        })
+    }
+}
+
+struct RoundTripRunner;
+
+impl Runner for RoundTripRunner {
+    fn resolve_namespace(&self, name: &str) -> Option<Box<dyn Namespace>> {
+        Some(Box::new(RoundTripNamespace(match name {
+            "Parse" | "Serialize" | "Format" | "Compile" | "Bench" => ParseType::Whole,
+            "ParseStatement" => ParseType::ContinuousLines,
+            "ParseExpression" => ParseType::Line,
+            _ => return None,
+        })))
+    }
+}
+
+#[test]
+pub fn round_trip_tests() {
+    leo_test_framework::run_checks(&RoundTripRunner, &["compiler", "parser"]);
+}
+
+#[test]
+pub fn display_recovered_program() {
+    create_session_if_not_set_then(|s| {
+        let source = "function main(a: u8) -> u8 {\n    return a.frobnicate();\n}\n";
+        let sf = s.source_map.new_source(source, FileName::Custom("test".into()));
+        let tokens = tokenizer::tokenize(&sf.src, sf.start_pos).unwrap();
+        let (handler, _) = Handler::new_with_buf();
+        let program = ParserContext::new(&handler, tokens).parse_program().unwrap();
+        assert!(handler.had_errors());
+
+        // The parser recovers from the invalid method call, whose expression is displayed as a placeholder.
+        assert!(program.to_string().contains("return <error>;"));
+    })
+}
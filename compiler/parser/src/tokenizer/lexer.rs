@@ -230,6 +230,7 @@ impl Token {
             x if x.is_ascii_digit() => return Self::eat_integer(&mut input),
             '!' => return followed_by(&mut input, '=', Token::NotEq, Token::Not),
             '?' => return single(&mut input, Token::Question),
+            '@' => return single(&mut input, Token::At),
             '&' => return followed_by(&mut input, '&', Token::And, Token::BitwiseAnd),
             '(' => return single(&mut input, Token::LeftParen),
             ')' => return single(&mut input, Token::RightParen),
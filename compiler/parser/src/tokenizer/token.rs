@@ -65,6 +65,7 @@ pub enum Token {
     Colon,
     DoubleColon,
     Question,
+    At,
     Arrow,
     Shl,
     Shr,
@@ -248,6 +249,7 @@ impl fmt::Display for Token {
             Colon => write!(f, ":"),
             DoubleColon => write!(f, "::"),
             Question => write!(f, "?"),
+            At => write!(f, "@"),
             Arrow => write!(f, "->"),
             Shl => write!(f, "<<"),
             Shr => write!(f, ">>"),
@@ -16,9 +16,14 @@
 
 use leo_ast::Function;
 use leo_errors::emitter::Handler;
-use leo_span::Symbol;
+use leo_span::{Span, Symbol};
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
+
+/// Ends each generated instruction, followed by the index of the instruction's span in `CodeGenerator::spans`.
+/// Instructions are generated bottom-up and some are dropped, e.g. those of imported programs,
+/// so their spans are carried along with them until the program is complete.
+pub(crate) const SPAN_MARKER: char = '\0';
 
 pub struct CodeGenerator<'a> {
     _handler: &'a Handler,
@@ -32,6 +37,12 @@ pub struct CodeGenerator<'a> {
     /// The first element of the tuple indicate whether the composite is a record or not.
     /// The second element of the tuple is a string modifier used for code generation.
     pub(crate) composite_mapping: IndexMap<&'a Symbol, (bool, String)>,
+    /// The spans of the AST nodes that instructions were generated for, indexed by the instructions' markers.
+    pub(crate) spans: Vec<Span>,
+    /// Whether to generate the `@test` functions, which are left out of programs by default.
+    pub(crate) test_functions: bool,
+    /// The functions generated as closures, which can only be called from within the program.
+    pub(crate) closures: IndexSet<Symbol>,
 }
 
 impl<'a> CodeGenerator<'a> {
@@ -43,6 +54,21 @@ impl<'a> CodeGenerator<'a> {
             current_function: None,
             variable_mapping: IndexMap::new(),
             composite_mapping: IndexMap::new(),
+            spans: Vec::new(),
+            test_functions: false,
+            closures: IndexSet::new(),
         }
     }
+
+    /// Returns whether the current function is generated as a closure.
+    pub(crate) fn in_closure(&self) -> bool {
+        self.current_function
+            .map_or(false, |function| self.closures.contains(&function.name()))
+    }
+
+    /// Returns the marker that ends an instruction generated for the AST node at `span`.
+    pub(crate) fn mark(&mut self, span: Span) -> String {
+        self.spans.push(span);
+        format!("{}{}", SPAN_MARKER, self.spans.len() - 1)
+    }
 }
@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{CodeGenerator, SPAN_MARKER};
+
+use leo_span::{symbol::with_session_globals, Span, Symbol};
+
+use std::path::Path;
+
+/// An instruction of a generated Aleo program, and the span of the Leo code it was generated for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionSpan {
+    /// The function or closure containing the instruction.
+    pub function: Symbol,
+    /// The index of the instruction in the body of the function, starting at 0.
+    /// The inputs and outputs of the function are counted with its instructions, in the order of the Aleo program.
+    pub index: usize,
+    /// The line of the instruction in the Aleo program, starting at 1.
+    pub line: usize,
+    /// The instruction, e.g. `add r0 r1 into r2;`.
+    pub instruction: String,
+    /// The span of the Leo code.
+    pub span: Span,
+}
+
+impl<'a> CodeGenerator<'a> {
+    /// Removes the span markers from `bytecode`, returning the bytecode and the spans of its instructions.
+    /// With `source_comments`, an instruction generated for a new line of Leo code is preceded by that line as a comment,
+    /// e.g. `// main.leo:12: let x = a + b;`.
+    pub(crate) fn resolve_spans(&self, bytecode: &str, source_comments: bool) -> (String, Vec<InstructionSpan>) {
+        let mut resolved = String::with_capacity(bytecode.len());
+        let mut instructions = Vec::new();
+        let mut line = 1;
+        // The Leo line of the last comment, which is not repeated for the following instructions.
+        let mut commented = None;
+        // The function or closure being resolved, and the number of instructions in its body so far.
+        let mut function: Option<(Symbol, usize)> = None;
+
+        for text in bytecode.split_inclusive('\n') {
+            let (text, newline) = match text.strip_suffix('\n') {
+                Some(text) => (text, "\n"),
+                None => (text, ""),
+            };
+
+            let (text, span) = match text.split_once(SPAN_MARKER) {
+                Some((text, index)) => (text, index.parse::<usize>().ok().map(|index| self.spans[index])),
+                None => (text, None),
+            };
+
+            // The instructions of a function are indented below its header.
+            let trimmed = text.trim();
+            let index = if trimmed.is_empty() || trimmed.starts_with("//") {
+                None
+            } else if text.starts_with(' ') {
+                function.as_mut().map(|(_, count)| {
+                    *count += 1;
+                    *count - 1
+                })
+            } else {
+                function = definition(trimmed).map(|name| (Symbol::intern(name), 0));
+                None
+            };
+
+            match (span, function, index) {
+                (Some(span), Some((function, _)), Some(index)) => {
+                    if source_comments {
+                        if let Some((location, source)) = source_line(span) {
+                            if commented.as_ref() != Some(&location) {
+                                let indentation = &text[..text.len() - text.trim_start().len()];
+                                resolved.push_str(&format!("{indentation}// {location}: {source}\n"));
+                                line += 1;
+                                commented = Some(location);
+                            }
+                        }
+                    }
+                    instructions.push(InstructionSpan {
+                        function,
+                        index,
+                        line,
+                        instruction: text.trim().to_string(),
+                        span,
+                    });
+                }
+                // Instructions of different definitions are commented separately.
+                _ => commented = None,
+            }
+
+            resolved.push_str(text);
+            resolved.push_str(newline);
+            line += 1;
+        }
+
+        (resolved, instructions)
+    }
+}
+
+/// Returns the name of the function or closure that `header` starts, e.g. `main` for `function main:`.
+fn definition(header: &str) -> Option<&str> {
+    let (keyword, name) = header.strip_suffix(':')?.split_once(' ')?;
+    match keyword {
+        "function" | "closure" => Some(name),
+        _ => None,
+    }
+}
+
+/// Returns the location of `span` as `file:line`, and the trimmed line of Leo code that it starts on.
+fn source_line(span: Span) -> Option<(String, String)> {
+    with_session_globals(|s| {
+        let location = s.source_map.span_to_location(span)?;
+        let file = location.source_file.name.to_string();
+        let file = Path::new(&file)
+            .file_name()
+            .map_or(file.clone(), |name| name.to_string_lossy().into_owned());
+        let source = s.source_map.line_contents_of_span(span)?;
+        let source = source.lines().next().unwrap_or_default().trim().to_string();
+        Some((format!("{}:{}", file, location.line_start), source))
+    })
+}
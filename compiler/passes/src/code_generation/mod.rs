@@ -17,6 +17,9 @@
 pub mod generator;
 pub use generator::*;
 
+pub mod instruction_spans;
+pub use instruction_spans::*;
+
 mod visit_expressions;
 
 mod visit_program;
@@ -30,16 +33,23 @@ use crate::Pass;
 use leo_ast::Ast;
 use leo_errors::emitter::Handler;
 use leo_errors::Result;
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
 
 impl<'a> Pass for CodeGenerator<'a> {
-    type Input = (&'a Ast, &'a Handler);
-    type Output = Result<String>;
+    /// The AST, the handler, whether to interleave the Leo source lines as comments,
+    /// whether to generate the `@test` functions, and the functions to generate as closures.
+    type Input = (&'a Ast, &'a Handler, bool, bool, &'a IndexSet<Symbol>);
+    type Output = Result<(String, Vec<InstructionSpan>)>;
 
-    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+    fn do_pass((ast, handler, source_comments, test_functions, closures): Self::Input) -> Self::Output {
         let mut generator = Self::new(handler);
+        generator.test_functions = test_functions;
+        generator.closures = closures.clone();
         let bytecode = generator.visit_program(ast.as_repr());
         handler.last_err()?;
 
-        Ok(bytecode)
+        Ok(generator.resolve_spans(&bytecode, source_comments))
     }
 }
@@ -88,8 +88,12 @@ impl<'a> CodeGenerator<'a> {
 
         let destination_register = format!("r{}", self.next_register);
         let binary_instruction = format!(
-            "    {} {} {} into {};\n",
-            opcode, left_operand, right_operand, destination_register
+            "    {} {} {} into {};{}\n",
+            opcode,
+            left_operand,
+            right_operand,
+            destination_register,
+            self.mark(input.span)
         );
 
         // Increment the register counter.
@@ -118,7 +122,13 @@ impl<'a> CodeGenerator<'a> {
         };
 
         let destination_register = format!("r{}", self.next_register);
-        let unary_instruction = format!("    {} {} into {};\n", opcode, expression_operand, destination_register);
+        let unary_instruction = format!(
+            "    {} {} into {};{}\n",
+            opcode,
+            expression_operand,
+            destination_register,
+            self.mark(input.span)
+        );
 
         // Increment the register counter.
         self.next_register += 1;
@@ -137,8 +147,12 @@ impl<'a> CodeGenerator<'a> {
 
         let destination_register = format!("r{}", self.next_register);
         let ternary_instruction = format!(
-            "    ternary {} {} {} into {};\n",
-            condition_operand, if_true_operand, if_false_operand, destination_register
+            "    ternary {} {} {} into {};{}\n",
+            condition_operand,
+            if_true_operand,
+            if_false_operand,
+            destination_register,
+            self.mark(input.span)
         );
 
         // Increment the register counter.
@@ -196,9 +210,10 @@ impl<'a> CodeGenerator<'a> {
         let destination_register = format!("r{}", self.next_register);
         writeln!(
             circuit_init_instruction,
-            "into {dest} as {name};",
+            "into {dest} as {name};{marker}",
             dest = destination_register,
             name = name,
+            marker = self.mark(input.span),
         )
         .expect("failed to write to string");
 
@@ -250,8 +265,13 @@ impl<'a> CodeGenerator<'a> {
 
         // Push destination register to associated function call instruction.
         let destination_register = format!("r{}", self.next_register);
-        writeln!(associated_function_call, "into {};", destination_register)
-            .expect("failed to write dest register for associated function");
+        writeln!(
+            associated_function_call,
+            "into {};{}",
+            destination_register,
+            self.mark(input.span)
+        )
+        .expect("failed to write dest register for associated function");
         instructions.push_str(&associated_function_call);
 
         // Increment the register counter.
@@ -270,7 +290,13 @@ impl<'a> CodeGenerator<'a> {
     }
 
     fn visit_call(&mut self, input: &'a CallExpression) -> (String, String) {
-        let mut call_instruction = format!("    {} ", input.function);
+        // Closures are called with the `call` instruction.
+        let mut call_instruction = match &*input.function {
+            Expression::Identifier(function) if self.closures.contains(&function.name) => {
+                format!("    call {} ", function)
+            }
+            function => format!("    {} ", function),
+        };
         let mut instructions = String::new();
 
         for argument in input.arguments.iter() {
@@ -281,7 +307,13 @@ impl<'a> CodeGenerator<'a> {
 
         // Push destination register to call instruction.
         let destination_register = format!("r{}", self.next_register);
-        writeln!(call_instruction, "into {};", destination_register).expect("failed to write to string");
+        writeln!(
+            call_instruction,
+            "into {};{}",
+            destination_register,
+            self.mark(input.span)
+        )
+        .expect("failed to write to string");
         instructions.push_str(&call_instruction);
 
         // Increment the register counter.
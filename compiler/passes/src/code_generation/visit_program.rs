@@ -16,7 +16,7 @@
 
 use crate::CodeGenerator;
 
-use leo_ast::{Circuit, CircuitMember, Function, Identifier, Program};
+use leo_ast::{Circuit, CircuitMember, Function, Identifier, Node, Program};
 
 use indexmap::IndexMap;
 use itertools::Itertools;
@@ -61,10 +61,13 @@ impl<'a> CodeGenerator<'a> {
         program_string.push('\n');
 
         // Visit each `Function` in the Leo AST and produce a Aleo function instruction.
+        // The `@test` functions are left out, unless they are to be executed.
+        let test_functions = self.test_functions;
         program_string.push_str(
             &input
                 .functions
                 .values()
+                .filter(|function| test_functions || !function.is_test())
                 .map(|function| self.visit_function(function))
                 .join("\n"),
         );
@@ -99,7 +102,7 @@ impl<'a> CodeGenerator<'a> {
         // Construct and append the record variables.
         for var in circuit.members.iter() {
             let (name, type_) = match var {
-                CircuitMember::CircuitVariable(name, type_) => (name, type_),
+                CircuitMember::CircuitVariable(name, type_, _) => (name, type_),
             };
 
             writeln!(output_string, "    {} as {};", name, type_,).expect("failed to write to string");
@@ -119,7 +122,7 @@ impl<'a> CodeGenerator<'a> {
         // Construct and append the record variables.
         for var in record.members.iter() {
             let (name, type_) = match var {
-                CircuitMember::CircuitVariable(name, type_) => (name, type_),
+                CircuitMember::CircuitVariable(name, type_, _) => (name, type_),
             };
 
             writeln!(
@@ -139,8 +142,9 @@ impl<'a> CodeGenerator<'a> {
         self.variable_mapping = IndexMap::new();
         self.current_function = Some(function);
 
-        // Construct the header of the function.
-        let mut function_string = format!("function {}:\n", function.identifier);
+        // Construct the header of the function or closure.
+        let keyword = if self.in_closure() { "closure" } else { "function" };
+        let mut function_string = format!("{} {}:\n", keyword, function.identifier);
 
         // Construct and append the input declarations of the function.
         for input in function.input.iter() {
@@ -152,8 +156,14 @@ impl<'a> CodeGenerator<'a> {
 
             let type_string =
                 self.visit_type_with_visibility(&input.get_variable().type_, Some(input.get_variable().mode()));
-            writeln!(function_string, "    input {} as {};", register_string, type_string,)
-                .expect("failed to write to string");
+            writeln!(
+                function_string,
+                "    input {} as {};{}",
+                register_string,
+                type_string,
+                self.mark(input.span())
+            )
+            .expect("failed to write to string");
         }
 
         //  Construct and append the function body.
@@ -17,8 +17,8 @@
 use crate::CodeGenerator;
 
 use leo_ast::{
-    AssignStatement, Block, ConditionalStatement, ConsoleStatement, DefinitionStatement, IterationStatement,
-    ReturnStatement, Statement,
+    AssignStatement, BinaryOperation, Block, ConditionalStatement, ConsoleFunction, ConsoleStatement,
+    DefinitionStatement, Expression, IterationStatement, ReturnStatement, Statement, Type,
 };
 
 use itertools::Itertools;
@@ -44,10 +44,16 @@ impl<'a> CodeGenerator<'a> {
             .split('\n')
             .into_iter()
             .zip(types.iter())
-            .map(|(operand, type_)| format!("    output {} as {};", operand, type_))
+            .map(|(operand, type_)| format!("    output {} as {};{}", operand, type_, self.mark(input.span)))
             .join("\n");
         instructions.push('\n');
 
+        // A test executed by snarkVM fails when it returns `false`.
+        let function = self.current_function.unwrap();
+        if self.test_functions && function.is_test() && function.output == Type::Boolean {
+            expression_instructions.push_str(&format!("    assert.eq {} true;{}\n", operand, self.mark(input.span)));
+        }
+
         expression_instructions.push_str(&instructions);
 
         expression_instructions
@@ -79,9 +85,39 @@ impl<'a> CodeGenerator<'a> {
         unreachable!("`IterationStatement`s should not be in the AST at this phase of compilation.");
     }
 
-    fn visit_console(&mut self, _input: &'a ConsoleStatement) -> String {
-        // `ConsoleStatement`s do not need to be included in the bytecode.
-        String::new()
+    fn visit_console(&mut self, input: &'a ConsoleStatement) -> String {
+        // Assertions are only checked by the tests executed with snarkVM.
+        // Otherwise, `ConsoleStatement`s do not need to be included in the bytecode.
+        let assertion = match &input.function {
+            ConsoleFunction::Assert(assertion) if self.test_functions => assertion,
+            _ => return String::new(),
+        };
+
+        match assertion {
+            Expression::Binary(binary) if matches!(binary.op, BinaryOperation::Eq | BinaryOperation::Neq) => {
+                let (left_operand, mut instructions) = self.visit_expression(&binary.left);
+                let (right_operand, right_instructions) = self.visit_expression(&binary.right);
+                instructions.push_str(&right_instructions);
+
+                let opcode = match binary.op {
+                    BinaryOperation::Eq => "assert.eq",
+                    _ => "assert.neq",
+                };
+                instructions.push_str(&format!(
+                    "    {} {} {};{}\n",
+                    opcode,
+                    left_operand,
+                    right_operand,
+                    self.mark(input.span)
+                ));
+                instructions
+            }
+            _ => {
+                let (operand, mut instructions) = self.visit_expression(assertion);
+                instructions.push_str(&format!("    assert.eq {} true;{}\n", operand, self.mark(input.span)));
+                instructions
+            }
+        }
     }
 
     pub(crate) fn visit_block(&mut self, input: &'a Block) -> String {
@@ -53,6 +53,16 @@ impl<'a> CodeGenerator<'a> {
     }
 
     pub(crate) fn visit_type_with_visibility(&mut self, input: &'a Type, visibility: Option<ParamMode>) -> String {
+        // The inputs and outputs of closures have no visibility, and circuits are referred to by their name alone.
+        if self.in_closure() {
+            return match input {
+                Type::Identifier(ident) if !matches!(self.composite_mapping.get(&ident.name), Some((true, _))) => {
+                    ident.to_string().to_lowercase()
+                }
+                _ => self.visit_type(input),
+            };
+        }
+
         let mut type_string = self.visit_type(input);
 
         if let Type::Identifier(_) = input {
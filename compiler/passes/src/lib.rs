@@ -22,6 +22,9 @@ pub use code_generation::*;
 pub mod pass;
 pub use self::pass::*;
 
+pub mod linting;
+pub use self::linting::*;
+
 pub mod loop_unrolling;
 pub use self::loop_unrolling::*;
 
@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::Linter;
+
+use leo_ast::*;
+
+impl<'a> ExpressionVisitor<'a> for Linter<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        if let Expression::Identifier(identifier) = &*input.function {
+            if self.symbol_table.lookup_fn_symbol(identifier.name).is_some() {
+                self.use_item(identifier.name);
+            }
+        }
+        input.arguments.iter().for_each(|argument| {
+            self.visit_expression(argument, additional);
+        });
+    }
+
+    fn visit_circuit_init(&mut self, input: &'a CircuitExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.use_item(input.name.name);
+        input.members.iter().for_each(|member| match &member.expression {
+            Some(expression) => self.visit_expression(expression, additional),
+            // The shorthand `Circuit { x }` reads the variable `x`.
+            None => {
+                self.read(member.identifier.name);
+            }
+        });
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        if !self.read(input.name) && self.symbol_table.lookup_circuit(input.name).is_some() {
+            self.use_item(input.name);
+        }
+    }
+}
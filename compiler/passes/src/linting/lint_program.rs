@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::Linter;
+
+use leo_ast::*;
+use leo_errors::LintWarning;
+
+impl<'a> ProgramVisitor<'a> for Linter<'a> {
+    fn visit_program(&mut self, input: &'a Program) {
+        // Imported programs are linted on their own.
+        input
+            .functions
+            .values()
+            .for_each(|function| self.visit_function(function));
+        input.circuits.values().for_each(|circuit| self.visit_circuit(circuit));
+
+        // Every function is a function of the Aleo program, which may be called from outside of it,
+        // so only circuits and records can be unused.
+        let reachable = self.reachable(input.functions.keys().map(|identifier| identifier.name));
+        for (identifier, circuit) in input.circuits.iter() {
+            if !reachable.contains(&identifier.name) && !self.included.contains(&identifier.name) {
+                self.emit_warning(match circuit.is_record {
+                    true => LintWarning::unused_record(identifier.name, identifier.span),
+                    false => LintWarning::unused_circuit(identifier.name, identifier.span),
+                });
+            }
+        }
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.item = Some(input.name());
+        self.enter_scope();
+
+        input.input.iter().for_each(|input| {
+            let variable = input.get_variable();
+            self.use_type(&variable.type_);
+            self.declare(&variable.identifier, true);
+        });
+        self.use_type(&input.output);
+        self.visit_block(&input.block);
+
+        self.exit_scope();
+        self.item = None;
+    }
+
+    fn visit_circuit(&mut self, input: &'a Circuit) {
+        self.item = Some(input.name());
+        input
+            .members
+            .iter()
+            .for_each(|CircuitMember::CircuitVariable(_, type_, _)| self.use_type(type_));
+        self.item = None;
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::Linter;
+
+use leo_ast::*;
+use leo_errors::LintWarning;
+
+impl<'a> StatementVisitor<'a> for Linter<'a> {
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.visit_expression(&input.value, &Default::default());
+        self.use_type(&input.type_);
+        self.declare(&input.variable_name, false);
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.visit_expression(&input.start, &Default::default());
+        self.visit_expression(&input.stop, &Default::default());
+        self.use_type(&input.type_);
+        self.visit_block(&input.block);
+    }
+
+    fn visit_block(&mut self, input: &'a Block) {
+        self.enter_scope();
+
+        // Only the first statement after a return is reported, as the rest are unreachable for the same reason.
+        let mut returned = None;
+        for statement in input.statements.iter() {
+            match returned {
+                Some(Some(return_span)) => {
+                    self.emit_warning(LintWarning::unreachable_statement(return_span, statement.span()));
+                    returned = Some(None);
+                }
+                None => {
+                    if let Statement::Return(statement) = statement {
+                        returned = Some(Some(statement.span));
+                    }
+                }
+                Some(None) => {}
+            }
+            self.visit_statement(statement);
+        }
+
+        self.exit_scope();
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::SymbolTable;
+
+use leo_ast::{Identifier, Type};
+use leo_errors::{emitter::Handler, LintWarning};
+use leo_span::{Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// A variable or function input declared in the function being linted.
+pub(crate) struct Binding {
+    /// The `Span` of the name of the binding.
+    pub(crate) span: Span,
+    /// Whether the binding is a function input.
+    pub(crate) is_input: bool,
+    /// Whether the binding has been read.
+    pub(crate) used: bool,
+}
+
+pub struct Linter<'a> {
+    /// The symbol table of the program.
+    pub(crate) symbol_table: &'a SymbolTable,
+    /// An error handler used for the lint warnings.
+    pub(crate) handler: &'a Handler,
+    /// The functions and circuits defined outside of the program, which are not warned about.
+    pub(crate) included: &'a IndexSet<Symbol>,
+    /// The bindings of the scopes enclosing the current statement, innermost last.
+    pub(crate) scopes: Vec<IndexMap<Symbol, Binding>>,
+    /// The function or circuit being linted.
+    pub(crate) item: Option<Symbol>,
+    /// The functions called and the circuits referred to by each function and circuit of the program.
+    pub(crate) uses: IndexMap<Symbol, IndexSet<Symbol>>,
+}
+
+impl<'a> Linter<'a> {
+    /// Returns a new linter given a symbol table, error handler and the definitions included from elsewhere.
+    pub fn new(symbol_table: &'a SymbolTable, handler: &'a Handler, included: &'a IndexSet<Symbol>) -> Self {
+        Self {
+            symbol_table,
+            handler,
+            included,
+            scopes: Vec::new(),
+            item: None,
+            uses: IndexMap::new(),
+        }
+    }
+
+    /// Emits a lint warning, unless the current function or circuit is included from elsewhere.
+    pub(crate) fn emit_warning(&self, warning: LintWarning) {
+        if !self.item.map_or(false, |item| self.included.contains(&item)) {
+            self.handler.emit_warning(warning.into());
+        }
+    }
+
+    /// Enters a new scope for bindings.
+    pub(crate) fn enter_scope(&mut self) {
+        self.scopes.push(IndexMap::new());
+    }
+
+    /// Exits the innermost scope and warns about the bindings in it that were never read.
+    pub(crate) fn exit_scope(&mut self) {
+        for (name, binding) in self.scopes.pop().unwrap_or_default() {
+            if binding.used {
+                continue;
+            }
+            self.emit_warning(match binding.is_input {
+                true => LintWarning::unused_input(name, binding.span),
+                false => LintWarning::unused_variable(name, binding.span),
+            });
+        }
+    }
+
+    /// Declares a variable or function input in the innermost scope.
+    pub(crate) fn declare(&mut self, identifier: &Identifier, is_input: bool) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(
+                identifier.name,
+                Binding {
+                    span: identifier.span,
+                    is_input,
+                    used: false,
+                },
+            );
+        }
+    }
+
+    /// Marks the binding `name` as read, returning `false` if there is no such binding in scope.
+    pub(crate) fn read(&mut self, name: Symbol) -> bool {
+        match self.scopes.iter_mut().rev().find_map(|scope| scope.get_mut(&name)) {
+            Some(binding) => {
+                binding.used = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Records that the current function or circuit uses the function or circuit `name`.
+    pub(crate) fn use_item(&mut self, name: Symbol) {
+        if let Some(item) = self.item {
+            self.uses.entry(item).or_default().insert(name);
+        }
+    }
+
+    /// Records the circuits referred to by `type_`.
+    pub(crate) fn use_type(&mut self, type_: &Type) {
+        match type_ {
+            Type::Identifier(identifier) => self.use_item(identifier.name),
+            Type::Tuple(tuple) => tuple.0.iter().for_each(|type_| self.use_type(type_)),
+            _ => {}
+        }
+    }
+
+    /// Returns the functions and circuits used, directly or indirectly, by `roots`, including the roots themselves.
+    pub(crate) fn reachable(&self, roots: impl IntoIterator<Item = Symbol>) -> IndexSet<Symbol> {
+        let mut reached: IndexSet<Symbol> = roots.into_iter().collect();
+        let mut index = 0;
+        while let Some(item) = reached.get_index(index).copied() {
+            if let Some(uses) = self.uses.get(&item) {
+                reached.extend(uses.iter().copied());
+            }
+            index += 1;
+        }
+        reached
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+pub mod lint_expressions;
+pub use lint_expressions::*;
+
+pub mod lint_program;
+pub use lint_program::*;
+
+pub mod lint_statements;
+pub use lint_statements::*;
+
+pub mod linter;
+pub use linter::*;
+
+use crate::{Pass, SymbolTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+impl<'a> Pass for Linter<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a SymbolTable, &'a IndexSet<Symbol>);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, st, included): Self::Input) -> Self::Output {
+        // Lints are warnings, unless the handler denies them.
+        let errors = handler.err_count();
+        let mut visitor = Linter::new(st, handler, included);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err_after(errors)
+    }
+}
@@ -34,6 +34,8 @@ impl ProgramReconstructor for Unroller<'_> {
 
         // Reconstruct the function block.
         let reconstructed_function = Function {
+            docs: function.docs,
+            annotations: function.annotations,
             identifier: function.identifier,
             input: function.input,
             output: function.output,
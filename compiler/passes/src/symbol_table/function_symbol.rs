@@ -25,11 +25,11 @@ pub struct FunctionSymbol {
     /// The index associated with the scope in the parent symbol table.
     pub(crate) id: usize,
     /// The output type of the function.
-    pub(crate) output: Type,
+    pub output: Type,
     /// The `Span` associated with the function.
-    pub(crate) span: Span,
+    pub span: Span,
     /// The inputs to the function.
-    pub(crate) input: Vec<FunctionInput>,
+    pub input: Vec<FunctionInput>,
 }
 
 impl SymbolTable {
@@ -37,9 +37,11 @@ impl<'a> Pass for CreateSymbolTable<'a> {
 
     /// Runs the compiler pass.
     fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        // Errors from before this pass, such as the syntax errors the parser recovered from, do not stop it.
+        let errors = handler.err_count();
         let mut visitor = CreateSymbolTable::new(handler);
         visitor.visit_program(ast.as_repr());
-        handler.last_err()?;
+        handler.last_err_after(errors)?;
 
         Ok(visitor.symbol_table)
     }
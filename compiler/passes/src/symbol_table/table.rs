@@ -48,14 +48,14 @@ impl SymbolTable {
     /// Recursively checks if the symbol table contains an entry for the given symbol.
     /// Leo does not allow any variable shadowing or overlap between different symbols.
     pub fn check_shadowing(&self, symbol: Symbol, span: Span) -> Result<()> {
-        if self.variables.contains_key(&symbol) {
-            Err(AstError::shadowed_variable(symbol, span).into())
-        } else if self.functions.contains_key(&symbol) {
-            Err(AstError::shadowed_function(symbol, span).into())
+        if let Some(existing) = self.variables.get(&symbol) {
+            Err(AstError::shadowed_variable(symbol, existing.span, span).into())
+        } else if let Some(existing) = self.functions.get(&symbol) {
+            Err(AstError::shadowed_function(symbol, existing.span, span).into())
         } else if let Some(existing) = self.circuits.get(&symbol) {
             match existing.is_record {
-                true => Err(AstError::shadowed_record(symbol, span).into()),
-                false => Err(AstError::shadowed_circuit(symbol, span).into()),
+                true => Err(AstError::shadowed_record(symbol, existing.span, span).into()),
+                false => Err(AstError::shadowed_circuit(symbol, existing.span, span).into()),
             }
         } else if let Some(parent) = self.parent.as_ref() {
             parent.check_shadowing(symbol, span)
@@ -134,6 +134,46 @@ impl SymbolTable {
         }
     }
 
+    /// Returns the names of the variables visible in this scope.
+    pub fn variable_names(&self) -> Vec<Symbol> {
+        let mut names: Vec<Symbol> = self.variables.keys().copied().collect();
+        if let Some(parent) = self.parent.as_ref() {
+            names.extend(parent.variable_names());
+        }
+        names
+    }
+
+    /// Returns the names of the functions visible in this scope.
+    pub fn function_names(&self) -> Vec<Symbol> {
+        let mut names: Vec<Symbol> = self.functions.keys().copied().collect();
+        if let Some(parent) = self.parent.as_ref() {
+            names.extend(parent.function_names());
+        }
+        names
+    }
+
+    /// Returns the names of the circuits and records visible in this scope.
+    pub fn circuit_names(&self) -> Vec<Symbol> {
+        let mut names: Vec<Symbol> = self.circuits.keys().copied().collect();
+        if let Some(parent) = self.parent.as_ref() {
+            names.extend(parent.circuit_names());
+        }
+        names
+    }
+
+    /// Returns the variables defined in this scope and in all of its sub-scopes.
+    pub fn nested_variables(&self) -> Vec<(Symbol, VariableSymbol)> {
+        let mut variables: Vec<(Symbol, VariableSymbol)> = self
+            .variables
+            .iter()
+            .map(|(name, variable)| (*name, variable.clone()))
+            .collect();
+        for scope in self.scopes.iter() {
+            variables.extend(scope.borrow().nested_variables());
+        }
+        variables
+    }
+
     /// Returns true if the variable exists in the local scope
     pub fn variable_in_local_scope(&self, symbol: Symbol) -> bool {
         self.variables.contains_key(&symbol)
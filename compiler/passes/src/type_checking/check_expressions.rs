@@ -17,7 +17,7 @@
 use leo_ast::*;
 use leo_errors::TypeCheckerError;
 
-use crate::TypeChecker;
+use crate::{closest_match, with_suggestion, TypeChecker};
 
 fn return_incorrect_type(t1: Option<Type>, t2: Option<Type>, expected: &Option<Type>) -> Option<Type> {
     match (t1, t2) {
@@ -135,13 +135,17 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                                 .find(|circuit_member| circuit_member.name() == access.name.name)
                             {
                                 // Case where `access.name` is a member of the circuit.
-                                Some(CircuitMember::CircuitVariable(_, type_)) => return Some(type_.clone()),
+                                Some(CircuitMember::CircuitVariable(_, type_, _)) => return Some(type_.clone()),
                                 // Case where `access.name` is not a member of the circuit.
                                 None => {
-                                    self.emit_err(TypeCheckerError::invalid_circuit_variable(
-                                        &access.name,
-                                        &circ,
-                                        access.name.span(),
+                                    self.emit_err(with_suggestion(
+                                        TypeCheckerError::invalid_circuit_variable(
+                                            &access.name,
+                                            &circ,
+                                            access.name.span(),
+                                        ),
+                                        access.name.name,
+                                        circ.members.iter().map(|member| member.name()),
                                     ));
                                 }
                             }
@@ -183,27 +187,39 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             // Check circuit member types.
             circ.members
                 .iter()
-                .for_each(|CircuitMember::CircuitVariable(name, ty)| {
+                .for_each(|CircuitMember::CircuitVariable(name, ty, _)| {
                     // Lookup circuit variable name.
                     if let Some(actual) = input.members.iter().find(|member| member.identifier.name == name.name) {
                         if let Some(expr) = &actual.expression {
                             self.visit_expression(expr, &Some(ty.clone()));
                         }
                     } else {
-                        self.emit_err(TypeCheckerError::missing_circuit_member(
-                            circ.identifier,
-                            name,
-                            input.span(),
-                        ));
+                        // Suggest a misspelled member of the initialization expression.
+                        let unknown = input
+                            .members
+                            .iter()
+                            .map(|member| member.identifier.name)
+                            .filter(|member| {
+                                !circ
+                                    .members
+                                    .iter()
+                                    .any(|circuit_member| circuit_member.name() == *member)
+                            });
+                        let err = TypeCheckerError::missing_circuit_member(circ.identifier, name, input.span());
+                        self.emit_err(match closest_match(name.name, unknown) {
+                            Some(member) => err.with_help(format!("Did you mean `{name}` instead of `{member}`?")),
+                            None => err,
+                        });
                     };
                 });
 
             Some(ret)
         } else {
-            self.emit_err(TypeCheckerError::unknown_sym(
-                "circuit",
-                &input.name.name,
-                input.name.span(),
+            let circuits = self.symbol_table.borrow().circuit_names();
+            self.emit_err(with_suggestion(
+                TypeCheckerError::unknown_sym("circuit", &input.name.name, input.name.span()),
+                input.name.name,
+                circuits,
             ));
             None
         }
@@ -215,7 +231,17 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         } else if let Some(var) = self.symbol_table.borrow().lookup_variable(var.name) {
             Some(self.assert_and_return_type(var.type_.clone(), expected, var.span))
         } else {
-            self.emit_err(TypeCheckerError::unknown_sym("variable", var.name, var.span()));
+            let candidates = {
+                let st = self.symbol_table.borrow();
+                let mut names = st.variable_names();
+                names.extend(st.circuit_names());
+                names
+            };
+            self.emit_err(with_suggestion(
+                TypeCheckerError::unknown_sym("variable", var.name, var.span()),
+                var.name,
+                candidates,
+            ));
             None
         }
     }
@@ -506,7 +532,8 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 // Assert right type is a magnitude (u8, u16, u32).
                 self.assert_magnitude_type(&t2, input.right.span());
 
-                return_incorrect_type(t1, t2, destination)
+                // Operation returns left type.
+                t1
             }
         }
     }
@@ -534,12 +561,27 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                         .iter()
                         .zip(input.arguments.iter())
                         .for_each(|(expected, argument)| {
-                            self.visit_expression(argument, &Some(expected.get_variable().type_.clone()));
+                            let parameter = expected.get_variable();
+                            let message = format!(
+                                "expected due to the parameter `{}` of `{}`",
+                                parameter.identifier, ident
+                            );
+                            self.visit_expression_expecting(
+                                argument,
+                                parameter.type_.clone(),
+                                Some(parameter.span),
+                                message,
+                            );
                         });
 
                     Some(ret)
                 } else {
-                    self.emit_err(TypeCheckerError::unknown_sym("function", &ident.name, ident.span()));
+                    let functions = self.symbol_table.borrow().function_names();
+                    self.emit_err(with_suggestion(
+                        TypeCheckerError::unknown_sym("function", &ident.name, ident.span()),
+                        ident.name,
+                        functions,
+                    ));
                     None
                 }
             }
@@ -52,6 +52,11 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
         });
         self.visit_block(&input.block);
 
+        // Tests are run without inputs.
+        if input.is_test() && !input.input.is_empty() {
+            self.emit_err(TypeCheckerError::test_function_with_inputs(input.name(), input.span()));
+        }
+
         if !self.has_return {
             self.emit_err(TypeCheckerError::function_has_no_return(input.name(), input.span()));
         }
@@ -84,7 +89,7 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             let check_has_field = |need, expected_ty: Type| match input
                 .members
                 .iter()
-                .find_map(|CircuitMember::CircuitVariable(v, t)| (v.name == need).then(|| (v, t)))
+                .find_map(|CircuitMember::CircuitVariable(v, t, _)| (v.name == need).then(|| (v, t)))
             {
                 Some((_, actual_ty)) if expected_ty.eq_flat(actual_ty) => {} // All good, found + right type!
                 Some((field, _)) => {
@@ -107,8 +112,12 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
         }
 
         // Ensure there are no tuple typed members.
-        for CircuitMember::CircuitVariable(v, type_) in input.members.iter() {
+        for CircuitMember::CircuitVariable(v, type_, _) in input.members.iter() {
             self.assert_not_tuple(v.span, type_);
         }
     }
+
+    fn visit_import(&mut self, _input: &'a Program) {
+        // Imported programs are type checked on their own, before they are resolved into the importing program.
+    }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{TypeChecker, VariableSymbol, VariableType};
+use crate::{with_suggestion, TypeChecker, VariableSymbol, VariableType};
 
 use leo_ast::*;
 use leo_errors::TypeCheckerError;
@@ -35,7 +35,16 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
 
         self.has_return = true;
 
-        self.visit_expression(&input.expression, return_type);
+        match return_type {
+            // The span of the function covers its body, so the return type is explained by a note.
+            Some(return_type) => {
+                let message = format!("`{}` is declared to return `{}`", parent, return_type);
+                self.visit_expression_expecting(&input.expression, return_type.clone(), None, message);
+            }
+            None => {
+                self.visit_expression(&input.expression, return_type);
+            }
+        }
     }
 
     fn visit_definition(&mut self, input: &'a DefinitionStatement) {
@@ -47,7 +56,13 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
 
         self.check_core_type_conflict(&Some(input.type_.clone()));
 
-        self.visit_expression(&input.value, &Some(input.type_.clone()));
+        let message = format!("expected due to the type of `{}`", input.variable_name);
+        self.visit_expression_expecting(
+            &input.value,
+            input.type_.clone(),
+            Some(input.variable_name.span),
+            message,
+        );
 
         if let Err(err) = self.symbol_table.borrow_mut().insert_variable(
             input.variable_name.name,
@@ -70,7 +85,9 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             }
         };
 
-        let var_type = if let Some(var) = self.symbol_table.borrow_mut().lookup_variable(var_name.name) {
+        let var = self.symbol_table.borrow().lookup_variable(var_name.name).cloned();
+        let var_origin = var.as_ref().map(|var| var.span);
+        let var_type = if let Some(var) = var {
             match &var.declaration {
                 VariableType::Const => self.emit_err(TypeCheckerError::cannot_assign_to_const_var(var_name, var.span)),
                 VariableType::Input(ParamMode::Const) => {
@@ -81,14 +98,20 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
 
             Some(var.type_.clone())
         } else {
-            self.emit_err(TypeCheckerError::unknown_sym("variable", var_name.name, var_name.span));
+            let variables = self.symbol_table.borrow().variable_names();
+            self.emit_err(with_suggestion(
+                TypeCheckerError::unknown_sym("variable", var_name.name, var_name.span),
+                var_name.name,
+                variables,
+            ));
 
             None
         };
 
-        if var_type.is_some() {
-            self.check_core_type_conflict(&var_type);
-            self.visit_expression(&input.value, &var_type);
+        if let Some(var_type) = var_type {
+            self.check_core_type_conflict(&Some(var_type.clone()));
+            let message = format!("`{}` is declared with type `{}` here", var_name, var_type);
+            self.visit_expression_expecting(&input.value, var_type, var_origin, message);
         }
     }
 
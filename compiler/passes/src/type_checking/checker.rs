@@ -14,9 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::SymbolTable;
+use crate::{with_suggestion, SymbolTable};
 
-use leo_ast::{Identifier, Node, Type};
+use leo_ast::{Expression, ExpressionVisitor, Identifier, Node, Type};
 use leo_core::*;
 use leo_errors::{emitter::Handler, TypeCheckerError};
 use leo_span::{Span, Symbol};
@@ -31,10 +31,23 @@ pub struct TypeChecker<'a> {
     pub(crate) parent: Option<Symbol>,
     pub(crate) has_return: bool,
     pub(crate) negate: bool,
+    /// The type expected of the expression being checked, with the code it is expected due to, if known.
+    pub(crate) expected_origin: Option<ExpectedOrigin>,
     pub(crate) account_types: IndexSet<Symbol>,
     pub(crate) algorithms_types: IndexSet<Symbol>,
 }
 
+/// The code that an expected type is due to, e.g. the type of a variable declaration.
+#[derive(Clone)]
+pub(crate) struct ExpectedOrigin {
+    /// The expected type.
+    pub(crate) type_: Type,
+    /// The span of the code, if it is short enough to label.
+    pub(crate) span: Option<Span>,
+    /// The text of the label, or else of the note, explaining the expected type.
+    pub(crate) message: String,
+}
+
 const BOOLEAN_TYPE: Type = Type::Boolean;
 
 const FIELD_TYPE: Type = Type::Field;
@@ -69,11 +82,17 @@ impl<'a> TypeChecker<'a> {
             parent: None,
             has_return: false,
             negate: false,
+            expected_origin: None,
             account_types: Account::types(),
             algorithms_types: Algorithms::types(),
         }
     }
 
+    /// Returns the symbol table, with the variables of every scope that has been checked.
+    pub fn into_symbol_table(self) -> SymbolTable {
+        self.symbol_table.into_inner()
+    }
+
     /// Emits a type checker error.
     pub(crate) fn emit_err(&self, err: TypeCheckerError) {
         self.handler.emit_err(err);
@@ -104,13 +123,45 @@ impl<'a> TypeChecker<'a> {
     pub(crate) fn assert_and_return_type(&self, actual: Type, expected: &Option<Type>, span: Span) -> Type {
         if let Some(expected) = expected {
             if !actual.eq_flat(expected) {
-                self.emit_err(TypeCheckerError::type_should_be(actual.clone(), expected, span));
+                let err = TypeCheckerError::type_should_be(actual.clone(), expected, span);
+                self.emit_err(self.with_expected_origin(err, expected));
             }
         }
 
         actual
     }
 
+    /// Checks the `expression` against the `expected` type, which is due to the code described by `origin`.
+    /// The errors of the expression that expect the type point to the code.
+    pub(crate) fn visit_expression_expecting(
+        &mut self,
+        expression: &'a Expression,
+        expected: Type,
+        origin: Option<Span>,
+        message: String,
+    ) -> Option<Type> {
+        let origin = ExpectedOrigin {
+            type_: expected.clone(),
+            span: origin,
+            message,
+        };
+        let previous = self.expected_origin.replace(origin);
+        let type_ = self.visit_expression(expression, &Some(expected));
+        self.expected_origin = previous;
+        type_
+    }
+
+    /// Labels the code that the `expected` type of a type error is due to, if it is known.
+    fn with_expected_origin(&self, err: TypeCheckerError, expected: &Type) -> TypeCheckerError {
+        match &self.expected_origin {
+            Some(origin) if origin.type_.eq_flat(expected) => match origin.span {
+                Some(span) => err.with_label(span, &origin.message),
+                None => err.with_note(&origin.message),
+            },
+            _ => err,
+        }
+    }
+
     /// Emits an error to the error handler if the `actual` type is not equal to the `expected` type.
     pub(crate) fn assert_type(&self, actual: &Option<Type>, expected: &Type, span: Span) {
         self.check_type(
@@ -287,11 +338,16 @@ impl<'a> TypeChecker<'a> {
             match CoreInstruction::from_symbols(ident.name, function.name) {
                 None => {
                     // Not a core library circuit.
-                    self.emit_err(TypeCheckerError::invalid_core_instruction(
-                        &ident.name,
-                        function.name,
-                        ident.span(),
-                    ));
+                    let err = TypeCheckerError::invalid_core_instruction(&ident.name, function.name, ident.span());
+                    let symbols = CoreInstruction::ALL.iter();
+                    let err = if symbols.clone().any(|(circuit, _, _)| *circuit == ident.name) {
+                        // The circuit exists, so the function must be misspelled.
+                        let functions = symbols.filter(|(circuit, _, _)| *circuit == ident.name);
+                        with_suggestion(err, function.name, functions.map(|(_, function, _)| *function))
+                    } else {
+                        with_suggestion(err, ident.name, symbols.map(|(circuit, _, _)| *circuit).unique())
+                    };
+                    self.emit_err(err);
                 }
                 Some(core_circuit) => return Some(core_circuit),
             }
@@ -303,7 +359,8 @@ impl<'a> TypeChecker<'a> {
     pub(crate) fn check_expected_circuit(&mut self, circuit: Identifier, expected: &Option<Type>, span: Span) -> Type {
         if let Some(Type::Identifier(expected)) = expected {
             if !circuit.matches(expected) {
-                self.emit_err(TypeCheckerError::type_should_be(circuit.name, expected.name, span));
+                let err = TypeCheckerError::type_should_be(circuit.name, expected.name, span);
+                self.emit_err(self.with_expected_origin(err, &Type::Identifier(*expected)));
             }
         }
 
@@ -27,6 +27,9 @@ pub use check_statements::*;
 pub mod checker;
 pub use checker::*;
 
+pub mod suggestions;
+pub use suggestions::*;
+
 use crate::{Pass, SymbolTable};
 
 use leo_ast::{Ast, ProgramVisitor};
@@ -37,9 +40,11 @@ impl<'a> Pass for TypeChecker<'a> {
     type Output = Result<SymbolTable>;
 
     fn do_pass((ast, handler, st): Self::Input) -> Self::Output {
+        // Errors from before this pass, such as the syntax errors the parser recovered from, do not stop it.
+        let errors = handler.err_count();
         let mut visitor = TypeChecker::new(st, handler);
         visitor.visit_program(ast.as_repr());
-        handler.last_err()?;
+        handler.last_err_after(errors)?;
 
         Ok(visitor.symbol_table.take())
     }
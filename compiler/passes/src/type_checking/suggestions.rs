@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_errors::TypeCheckerError;
+use leo_span::Symbol;
+
+/// Returns the candidate closest to `name` by edit distance, if any is close enough to be a likely misspelling.
+/// Differences in case are not counted, so `bhp256` suggests `BHP256`.
+pub fn closest_match(name: Symbol, candidates: impl IntoIterator<Item = Symbol>) -> Option<Symbol> {
+    let name = name.to_string();
+    let lowercase = name.to_lowercase();
+    // Allow one edit for every three characters, as rustc does.
+    let max_distance = std::cmp::max(name.len(), 3) / 3;
+
+    candidates
+        .into_iter()
+        .map(|candidate| (candidate, candidate.to_string()))
+        .filter(|(_, candidate)| *candidate != name)
+        .map(|(symbol, candidate)| {
+            let distance = edit_distance(&lowercase, &candidate.to_lowercase());
+            (distance, candidate.len(), symbol)
+        })
+        // A candidate that shares no characters with `name` is not a misspelling of it.
+        .filter(|(distance, len, _)| *distance <= max_distance && *distance < std::cmp::max(name.len(), *len))
+        .min_by_key(|(distance, _, _)| *distance)
+        .map(|(_, _, symbol)| symbol)
+}
+
+/// Returns the help message suggesting `candidate`.
+pub fn did_you_mean(candidate: Symbol) -> String {
+    format!("Did you mean `{candidate}`?")
+}
+
+/// Adds a "did you mean" help message to `err` if one of the `candidates` is close to `name`.
+pub(crate) fn with_suggestion(
+    err: TypeCheckerError,
+    name: Symbol,
+    candidates: impl IntoIterator<Item = Symbol>,
+) -> TypeCheckerError {
+    match closest_match(name, candidates) {
+        Some(candidate) => err.with_help(did_you_mean(candidate)),
+        None => err,
+    }
+}
+
+/// Returns the optimal string alignment distance between `a` and `b`.
+/// This is the Levenshtein distance where swapping two adjacent characters counts as a single edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    // `distances[i][j]` is the distance between the first `i` characters of `a` and the first `j` of `b`.
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitution = distances[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = substitution.min(distances[i - 1][j] + 1).min(distances[i][j - 1] + 1);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
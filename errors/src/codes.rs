@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+
+use std::fmt;
+
+/// Looks up a message number among the messages of the given types, returning from the caller if one matches.
+macro_rules! find_message {
+    ($prefix:expr, $number:expr, $($type_:ident),*) => {
+        $(
+            if $type_::message_type() == $prefix {
+                let index = $number - 10_000 * i32::from($type_::code_identifier()) - $type_::code_mask();
+                let name = usize::try_from(index).ok().and_then(|index| $type_::MESSAGE_NAMES.get(index));
+                if let Some(name) = name {
+                    return Some(MessageName { kind: stringify!($type_), name });
+                }
+            }
+        )*
+    };
+}
+
+/// The name of the message with a given code, e.g. `ParserError::unexpected_eof` for `EPAR0370003`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MessageName {
+    /// The type defining the message, e.g. `ParserError`.
+    pub kind: &'static str,
+    /// The function creating the message, e.g. `unexpected_eof`.
+    pub name: &'static str,
+}
+
+impl MessageName {
+    /// Returns the name of the message with the code `code`, which may omit its leading `E` or `W`.
+    /// As denied warnings are reported with an `E` code, error codes fall back to warnings.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim().to_ascii_uppercase();
+        if !code.is_ascii() {
+            return None;
+        }
+        let (errors, code) = match code.len() {
+            11 if code.starts_with('E') => (true, &code[1..]),
+            11 if code.starts_with('W') => (false, &code[1..]),
+            10 => (true, code.as_str()),
+            _ => return None,
+        };
+        let (prefix, number) = code.split_at(3);
+        if !number.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+        let number = number.parse::<i32>().ok()?;
+
+        if errors {
+            find_message!(
+                prefix,
+                number,
+                AstError,
+                CliError,
+                CompilerError,
+                FlattenError,
+                InputError,
+                InterpreterError,
+                PackageError,
+                ParserError,
+                SnarkVMError,
+                TypeCheckerError
+            );
+        }
+        find_message!(prefix, number, LintWarning, ParserWarning);
+        None
+    }
+}
+
+impl fmt::Display for MessageName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}::{}", self.kind, self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn message_names() {
+        let unexpected_eof = Some(MessageName {
+            kind: "ParserError",
+            name: "unexpected_eof",
+        });
+        assert_eq!(MessageName::from_code("EPAR0370003"), unexpected_eof);
+        assert_eq!(MessageName::from_code("par0370003"), unexpected_eof);
+        assert_eq!(MessageName::from_code("WPAR0370003"), None);
+
+        let unused_variable = MessageName::from_code("WLIN0370000").unwrap();
+        assert_eq!(unused_variable.to_string(), "LintWarning::unused_variable");
+        assert_eq!(MessageName::from_code("ELIN0370000"), Some(unused_variable));
+
+        assert_eq!(MessageName::from_code("EPAR0379999"), None);
+        assert_eq!(MessageName::from_code("EPAR037000"), None);
+    }
+}
@@ -29,6 +29,7 @@ pub(crate) const INDENT: &str = "    ";
 ///     undefined value `x`
 ///     --> file.leo: 2:8
 ///      = help: Initialize a variable `x` first.
+///      = note: Variables must be defined before they are used.
 #[derive(Derivative)]
 #[derivative(Clone, Debug, Default, Hash, PartialEq)]
 pub struct Backtraced {
@@ -44,6 +45,8 @@ pub struct Backtraced {
     pub type_: String,
     /// Is this Backtrace a warning or error?
     pub error: bool,
+    /// The notes shown after the message.
+    pub notes: Vec<String>,
     #[derivative(PartialEq = "ignore")]
     #[derivative(Hash = "ignore")]
     /// The backtrace representing where the error occured in Leo.
@@ -71,10 +74,30 @@ impl Backtraced {
             code_identifier,
             type_,
             error,
+            notes: Vec::new(),
             backtrace,
         }
     }
 
+    /// Replaces the help message.
+    pub fn with_help<S: ToString>(mut self, help: S) -> Self {
+        self.help = Some(help.to_string());
+        self
+    }
+
+    /// Reports the warning as an error, noting that its warning code is denied.
+    pub fn deny(mut self) -> Self {
+        let code = self.warning_code();
+        self.error = true;
+        self.with_note(format!("`{code}` is denied"))
+    }
+
+    /// Adds a note shown after the message.
+    pub fn with_note<S: ToString>(mut self, note: S) -> Self {
+        self.notes.push(note.to_string());
+        self
+    }
+
     /// Gets the backtraced error exit code.
     pub fn exit_code(&self) -> i32 {
         let mut code: i32;
@@ -141,6 +164,13 @@ impl fmt::Display for Backtraced {
             )?;
         }
 
+        if self.help.is_none() && !self.notes.is_empty() {
+            write!(f, "\n{indent     } |", indent = INDENT)?;
+        }
+        for note in self.notes.iter() {
+            write!(f, "\n{indent     } = note: {note}", indent = INDENT, note = note)?;
+        }
+
         let leo_backtrace = std::env::var("LEO_BACKTRACE").unwrap_or_default().trim().to_owned();
         match leo_backtrace.as_ref() {
             "1" => {
@@ -16,12 +16,25 @@
 
 use crate::{Backtraced, INDENT};
 
-use leo_span::{source_map::SpanLocation, symbol::with_session_globals, Span};
+use leo_span::{
+    source_map::{SourceMap, SpanLocation},
+    symbol::with_session_globals,
+    Span,
+};
 
 use backtrace::Backtrace;
 use color_backtrace::{BacktracePrinter, Verbosity};
 use colored::Colorize;
-use std::fmt;
+use std::{fmt, rc::Rc};
+
+/// A secondary span of a formatted message, with a label explaining its relevance.
+#[derive(Clone, Debug, Default, Hash, PartialEq)]
+pub struct Label {
+    /// The labelled span, which may be in another file than the primary span.
+    pub span: Span,
+    /// The text shown next to the span.
+    pub message: String,
+}
 
 /// Formatted compiler error type
 ///     undefined value `x`
@@ -30,12 +43,18 @@ use std::fmt;
 ///    2 | let a = x;
 ///      |         ^
 ///      |
+///    1 | let b = 1;
+///      |     - a similar variable is defined here
+///      |
 ///      = help: Initialize a variable `x` first.
+///      = note: Variables must be defined before they are used.
 /// Makes use of the same fields as a BacktracedError.
 #[derive(Clone, Debug, Default, Hash, PartialEq)]
 pub struct Formatted {
     /// The formatted error span information.
     pub span: Span,
+    /// The labelled secondary spans.
+    pub labels: Vec<Label>,
     /// The backtrace to track where the Leo error originated.
     pub backtrace: Backtraced,
 }
@@ -58,6 +77,7 @@ impl Formatted {
     {
         Self {
             span,
+            labels: Vec::new(),
             backtrace: Backtraced::new_from_backtrace(
                 message.to_string(),
                 help,
@@ -70,6 +90,33 @@ impl Formatted {
         }
     }
 
+    /// Adds a secondary span with a label.
+    pub fn with_label<S: ToString>(mut self, span: Span, message: S) -> Self {
+        self.labels.push(Label {
+            span,
+            message: message.to_string(),
+        });
+        self
+    }
+
+    /// Replaces the help message.
+    pub fn with_help<S: ToString>(mut self, help: S) -> Self {
+        self.backtrace = self.backtrace.with_help(help);
+        self
+    }
+
+    /// Reports the warning as an error, noting that its warning code is denied.
+    pub fn deny(mut self) -> Self {
+        self.backtrace = self.backtrace.deny();
+        self
+    }
+
+    /// Adds a note shown after the source snippets.
+    pub fn with_note<S: ToString>(mut self, note: S) -> Self {
+        self.backtrace = self.backtrace.with_note(note);
+        self
+    }
+
     /// Calls the backtraces error exit code.
     pub fn exit_code(&self) -> i32 {
         self.backtrace.exit_code()
@@ -88,38 +135,6 @@ impl Formatted {
 
 impl fmt::Display for Formatted {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let underline = |mut start: usize, mut end: usize| -> String {
-            if start > end {
-                std::mem::swap(&mut start, &mut end)
-            }
-
-            let mut underline = String::new();
-
-            for _ in 0..start {
-                underline.push(' ');
-                end -= 1;
-            }
-
-            for _ in 0..end {
-                underline.push('^');
-            }
-
-            underline
-        };
-
-        let (loc, contents) = with_session_globals(|s| {
-            (
-                s.source_map
-                    .span_to_location(self.span)
-                    .unwrap_or_else(SpanLocation::dummy),
-                s.source_map
-                    .line_contents_of_span(self.span)
-                    .unwrap_or_else(|| "<contents unavailable>".to_owned()),
-            )
-        });
-
-        let underlined = underline(loc.col_start, loc.col_stop);
-
         let (kind, code) = if self.backtrace.error {
             ("Error", self.error_code())
         } else {
@@ -144,32 +159,37 @@ impl fmt::Display for Formatted {
             write!(f, "{}", message)?;
         };
 
+        let (loc, snippet) = with_session_globals(|s| Snippet::new(&s.source_map, self.span, '^', None));
+
         write!(
             f,
             "\n{indent     }--> {path}:{line_start}:{start}\n\
-            {indent     } |\n",
+            {indent     } |\n{snippet}",
             indent = INDENT,
             path = &loc.source_file.name,
             line_start = loc.line_start,
             start = loc.col_start,
         )?;
 
-        for (line_no, line) in contents.lines().enumerate() {
-            writeln!(
-                f,
-                "{line_no:width$} | {text}",
-                width = INDENT.len(),
-                line_no = loc.line_start + line_no,
-                text = line,
-            )?;
-        }
+        // Secondary labels follow the primary span, with a header when they are in another file.
+        for label in self.labels.iter() {
+            let (label_loc, snippet) =
+                with_session_globals(|s| Snippet::new(&s.source_map, label.span, '-', Some(&label.message)));
 
-        write!(
-            f,
-            "{indent     } |{underlined}",
-            indent = INDENT,
-            underlined = underlined,
-        )?;
+            if Rc::ptr_eq(&label_loc.source_file, &loc.source_file) {
+                write!(f, "\n{indent     } |\n{snippet}", indent = INDENT)?;
+            } else {
+                write!(
+                    f,
+                    "\n{indent     }::: {path}:{line_start}:{start}\n\
+                    {indent     } |\n{snippet}",
+                    indent = INDENT,
+                    path = &label_loc.source_file.name,
+                    line_start = label_loc.line_start,
+                    start = label_loc.col_start,
+                )?;
+            }
+        }
 
         if let Some(help) = &self.backtrace.help {
             write!(
@@ -181,6 +201,13 @@ impl fmt::Display for Formatted {
             )?;
         }
 
+        if self.backtrace.help.is_none() && !self.backtrace.notes.is_empty() {
+            write!(f, "\n{indent     } |", indent = INDENT)?;
+        }
+        for note in self.backtrace.notes.iter() {
+            write!(f, "\n{indent     } = note: {note}", indent = INDENT, note = note)?;
+        }
+
         let leo_backtrace = std::env::var("LEO_BACKTRACE").unwrap_or_default().trim().to_owned();
         match leo_backtrace.as_ref() {
             "1" => {
@@ -208,6 +235,84 @@ impl fmt::Display for Formatted {
     }
 }
 
+/// The source lines of a span with the span marked underneath them.
+///    2 | let a = x;
+///      |         ^
+/// Spans over several lines are connected from their first to their last character.
+///    1 |   circuit Foo {
+///      |  _^
+///    2 | |     x: u32,
+///    3 | | }
+///      | |_^
+struct Snippet(String);
+
+impl Snippet {
+    /// Renders the lines of `span` with `marker` underneath, followed by the optional `label`.
+    fn new(source_map: &SourceMap, span: Span, marker: char, label: Option<&str>) -> (SpanLocation, Self) {
+        let loc = source_map.span_to_location(span).unwrap_or_else(SpanLocation::dummy);
+        let contents = source_map
+            .line_contents_of_span(span)
+            .unwrap_or_else(|| "<contents unavailable>".to_owned());
+        let label = label.map(|label| format!(" {}", label)).unwrap_or_default();
+        let lines: Vec<&str> = contents.lines().collect();
+
+        let mut snippet = String::new();
+        let mut line = |line_no: usize, text: &str| {
+            snippet.push_str(&format!(
+                "{line_no:width$} | {text}\n",
+                width = INDENT.len(),
+                line_no = loc.line_start + line_no,
+                text = text,
+            ));
+        };
+
+        if lines.len() <= 1 || loc.line_start == loc.line_stop {
+            let (mut start, mut end) = (loc.col_start, loc.col_stop);
+            if start > end {
+                std::mem::swap(&mut start, &mut end)
+            }
+
+            for (line_no, text) in lines.iter().enumerate() {
+                line(line_no, text);
+            }
+            snippet.push_str(&format!(
+                "{indent     } |{spaces}{markers}{label}",
+                indent = INDENT,
+                spaces = " ".repeat(start),
+                markers = marker.to_string().repeat(end - start),
+            ));
+        } else {
+            line(0, &format!("  {}", lines[0]));
+            let mut underline = format!(
+                "{indent     } |  {underscores}{marker}\n",
+                indent = INDENT,
+                underscores = "_".repeat(loc.col_start),
+            );
+            for (line_no, text) in lines.iter().enumerate().skip(1) {
+                underline.push_str(&format!(
+                    "{line_no:width$} | | {text}\n",
+                    width = INDENT.len(),
+                    line_no = loc.line_start + line_no,
+                ));
+            }
+            snippet.push_str(&underline);
+            snippet.push_str(&format!(
+                "{indent     } | |{underscores}{marker}{label}",
+                indent = INDENT,
+                underscores = "_".repeat(loc.col_stop.saturating_sub(1)),
+            ));
+        }
+
+        (loc, Self(snippet))
+    }
+}
+
+impl fmt::Display for Snippet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
 impl std::error::Error for Formatted {
     fn description(&self) -> &str {
         &self.backtrace.message
@@ -17,6 +17,9 @@
 /// A macro that given an enum, exit code mask, error code string prefix,
 /// and error methods generated through a DSL creates and generates errors
 /// with a unique error code.
+///
+/// After its `help`, a formatted message may list secondary spans with
+/// `labels: [(span, "label")],`, and any message may list `notes: ["note"],`.
 #[macro_export]
 macro_rules! create_messages {
     (@step $code:expr,) => {
@@ -26,21 +29,34 @@ macro_rules! create_messages {
             $code
         }
     };
-    ($(#[$error_type_docs:meta])* $type_:ident, code_mask: $code_mask:expr, code_prefix: $code_prefix:expr, $($(#[$docs:meta])* @$formatted_or_backtraced_list:ident $names:ident { args: ($($arg_names:ident: $arg_types:ty$(,)?)*), msg: $messages:expr, help: $helps:expr, })*) => {
+    ($(#[$error_type_docs:meta])* $type_:ident, code_mask: $code_mask:expr, code_prefix: $code_prefix:expr, $($(#[$docs:meta])* @$formatted_or_backtraced_list:ident $names:ident { args: ($($arg_names:ident: $arg_types:ty$(,)?)*), msg: $messages:expr, help: $helps:expr, $(labels: [$($labels:tt)*],)? $(notes: [$($notes:tt)*],)? })*) => {
         #[allow(unused_imports)] // Allow unused for errors that only use formatted or backtraced errors.
         use $crate::{Backtraced, Formatted, LeoMessageCode};
 
         use backtrace::Backtrace;
 
         // Generates the enum and implements from FormattedError and BacktracedErrors.
+        // The messages are boxed to keep results carrying an error small.
         #[derive(Clone, Debug, Error)]
         $(#[$error_type_docs])*
         pub enum $type_ {
             #[error(transparent)]
-            Formatted(#[from] Formatted),
+            Formatted(Box<Formatted>),
 
 	        #[error(transparent)]
-            Backtraced(#[from] Backtraced),
+            Backtraced(Box<Backtraced>),
+        }
+
+        impl From<Formatted> for $type_ {
+            fn from(formatted: Formatted) -> Self {
+                Self::Formatted(Box::new(formatted))
+            }
+        }
+
+        impl From<Backtraced> for $type_ {
+            fn from(backtraced: Backtraced) -> Self {
+                Self::Backtraced(Box::new(backtraced))
+            }
         }
 
         /// Implements the trait for LeoError Codes.
@@ -86,18 +102,79 @@ macro_rules! create_messages {
         }
 
 
+        impl $type_ {
+            /// The names of the functions creating the messages, in the order of their codes.
+            pub const MESSAGE_NAMES: &'static [&'static str] = &[$(stringify!($names)),*];
+
+            /// Returns the message, help, and codes of the message.
+            pub fn backtraced(&self) -> &Backtraced {
+                match self {
+                    Self::Formatted(formatted) => &formatted.backtrace,
+                    Self::Backtraced(backtraced) => backtraced,
+                }
+            }
+
+            /// Returns the span the message points to, if it has one.
+            pub fn span(&self) -> Option<leo_span::Span> {
+                match self {
+                    Self::Formatted(formatted) => Some(formatted.span),
+                    Self::Backtraced(_) => None,
+                }
+            }
+
+            /// Returns the labelled secondary spans of the message.
+            pub fn labels(&self) -> &[$crate::Label] {
+                match self {
+                    Self::Formatted(formatted) => &formatted.labels,
+                    Self::Backtraced(_) => &[],
+                }
+            }
+
+            /// Adds a secondary span with a label, if the message has a primary span.
+            pub fn with_label<S: ToString>(self, span: leo_span::Span, label: S) -> Self {
+                match self {
+                    Self::Formatted(formatted) => (*formatted).with_label(span, label).into(),
+                    Self::Backtraced(backtraced) => Self::Backtraced(backtraced),
+                }
+            }
+
+            /// Replaces the help message.
+            pub fn with_help<S: ToString>(self, help: S) -> Self {
+                match self {
+                    Self::Formatted(formatted) => (*formatted).with_help(help).into(),
+                    Self::Backtraced(backtraced) => (*backtraced).with_help(help).into(),
+                }
+            }
+
+            /// Reports the message as an error, noting that its warning code is denied.
+            pub fn deny(self) -> Self {
+                match self {
+                    Self::Formatted(formatted) => (*formatted).deny().into(),
+                    Self::Backtraced(backtraced) => (*backtraced).deny().into(),
+                }
+            }
+
+            /// Adds a note shown at the end of the message.
+            pub fn with_note<S: ToString>(self, note: S) -> Self {
+                match self {
+                    Self::Formatted(formatted) => (*formatted).with_note(note).into(),
+                    Self::Backtraced(backtraced) => (*backtraced).with_note(note).into(),
+                }
+            }
+        }
+
         // Steps over the list of functions with an initial code of 0.
         impl $type_ {
-            create_messages!(@step 0i32, $(($(#[$docs])* $formatted_or_backtraced_list, $names($($arg_names: $arg_types,)*), $messages, $helps),)*);
+            create_messages!(@step 0i32, $(($(#[$docs])* $formatted_or_backtraced_list, $names($($arg_names: $arg_types,)*), $messages, $helps, [$($($labels)*)?], [$($($notes)*)?]),)*);
         }
     };
     // Matches the function if it is a formatted message.
-    (@step $code:expr, ($(#[$error_func_docs:meta])* formatted, $name:ident($($arg_names:ident: $arg_types:ty,)*), $message:expr, $help:expr), $(($(#[$docs:meta])* $formatted_or_backtraced_tail:ident, $names:ident($($tail_arg_names:ident: $tail_arg_types:ty,)*), $messages:expr, $helps:expr),)*) => {
+    (@step $code:expr, ($(#[$error_func_docs:meta])* formatted, $name:ident($($arg_names:ident: $arg_types:ty,)*), $message:expr, $help:expr, [$(($label_span:expr, $label:expr)),* $(,)?], [$($note:expr),* $(,)?]), $(($(#[$docs:meta])* $formatted_or_backtraced_tail:ident, $names:ident($($tail_arg_names:ident: $tail_arg_types:ty,)*), $messages:expr, $helps:expr, [$($tail_labels:tt)*], [$($tail_notes:tt)*]),)*) => {
         // Formatted errors always takes a span.
         $(#[$error_func_docs])*
         // Expands additional arguments for the error defining function.
         pub fn $name($($arg_names: $arg_types,)* span: leo_span::Span) -> Self {
-            Self::Formatted(
+            Self::from(
                 Formatted::new_from_span(
                     $message,
                     $help,
@@ -109,18 +186,20 @@ macro_rules! create_messages {
                     // Each function always generates its own backtrace for backtrace clarity to originate from the error function.
                     Backtrace::new(),
                 )
+                $(.with_label($label_span, $label))*
+                $(.with_note($note))*
             )
         }
 
         // Steps the code value by one and calls on the rest of the functions.
-        create_messages!(@step $code + 1i32, $(($(#[$docs])* $formatted_or_backtraced_tail, $names($($tail_arg_names: $tail_arg_types,)*), $messages, $helps),)*);
+        create_messages!(@step $code + 1i32, $(($(#[$docs])* $formatted_or_backtraced_tail, $names($($tail_arg_names: $tail_arg_types,)*), $messages, $helps, [$($tail_labels)*], [$($tail_notes)*]),)*);
     };
     // matches the function if it is a backtraced message.
-    (@step $code:expr, ($(#[$error_func_docs:meta])* backtraced, $name:ident($($arg_names:ident: $arg_types:ty,)*), $message:expr, $help:expr), $(($(#[$docs:meta])* $formatted_or_backtraced_tail:ident, $names:ident($($tail_arg_names:ident: $tail_arg_types:ty,)*), $messages:expr, $helps:expr),)*) => {
+    (@step $code:expr, ($(#[$error_func_docs:meta])* backtraced, $name:ident($($arg_names:ident: $arg_types:ty,)*), $message:expr, $help:expr, [], [$($note:expr),* $(,)?]), $(($(#[$docs:meta])* $formatted_or_backtraced_tail:ident, $names:ident($($tail_arg_names:ident: $tail_arg_types:ty,)*), $messages:expr, $helps:expr, [$($tail_labels:tt)*], [$($tail_notes:tt)*]),)*) => {
         $(#[$error_func_docs])*
         // Expands additional arguments for the error defining function.
         pub fn $name($($arg_names: $arg_types,)*) -> Self {
-            Self::Backtraced(
+            Self::from(
                 Backtraced::new_from_backtrace(
                     $message,
                     $help,
@@ -131,10 +210,11 @@ macro_rules! create_messages {
                     // Each function always generates its own backtrace for backtrace clarity to originate from the error function.
                     Backtrace::new(),
                 )
+                $(.with_note($note))*
             )
         }
 
         // Steps the code value by one and calls on the rest of the functions.
-        create_messages!(@step $code + 1i32, $(($(#[$docs])* $formatted_or_backtraced_tail, $names($($tail_arg_names: $tail_arg_types,)*), $messages, $helps),)*);
+        create_messages!(@step $code + 1i32, $(($(#[$docs])* $formatted_or_backtraced_tail, $names($($tail_arg_names: $tail_arg_types,)*), $messages, $helps, [$($tail_labels)*], [$($tail_notes)*]),)*);
     };
 }
@@ -14,12 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::LeoWarning;
+use crate::{Backtraced, CliError, Label, LeoWarning, MessageName};
 
 use super::LeoError;
 use core::default::Default;
 use core::fmt;
+use leo_span::{symbol::with_session_globals, Span};
+use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 
 /// Types that are sinks for compiler errors.
@@ -35,15 +38,38 @@ pub trait Emitter {
 }
 
 /// A trivial `Emitter` using the standard error.
+#[derive(Default)]
 pub struct StderrEmitter {
     /// Exit code of the last emitted error.
     last_error_code: Option<i32>,
+    /// Returns whether `leo explain` has an explanation for a code.
+    explained: Option<fn(&str) -> bool>,
+}
+
+impl StderrEmitter {
+    /// Returns an emitter that follows each message whose code `explained` accepts with a pointer to `leo explain`.
+    pub fn with_explanations(explained: fn(&str) -> bool) -> Self {
+        Self {
+            last_error_code: None,
+            explained: Some(explained),
+        }
+    }
+
+    /// Points to the explanation of the code `code` of an error or warning, if there is one.
+    fn point_to_explanation(&self, code: &str, kind: &str) {
+        if self.explained.map_or(false, |explained| explained(code)) {
+            eprintln!("For more information about this {kind}, try `leo explain {code}`.");
+        }
+    }
 }
 
 impl Emitter for StderrEmitter {
     fn emit_err(&mut self, err: LeoError) {
         self.last_error_code = Some(err.exit_code());
         eprintln!("{}", err);
+        if let Some(backtraced) = err.backtraced() {
+            self.point_to_explanation(&backtraced.error_code(), "error");
+        }
     }
 
     fn last_emitted_err_code(&self) -> Option<i32> {
@@ -52,6 +78,159 @@ impl Emitter for StderrEmitter {
 
     fn emit_warning(&mut self, warning: LeoWarning) {
         eprintln!("{warning}");
+        self.point_to_explanation(&warning.error_code(), "warning");
+    }
+}
+
+/// An `Emitter` that writes each diagnostic to the standard error as one line of JSON.
+#[derive(Default)]
+pub struct JsonEmitter {
+    /// Exit code of the last emitted error.
+    last_error_code: Option<i32>,
+}
+
+impl Emitter for JsonEmitter {
+    fn emit_err(&mut self, err: LeoError) {
+        if let LeoError::LastErrorCode(_) = err {
+            return;
+        }
+        if err.backtraced().is_some() {
+            self.last_error_code = Some(err.exit_code());
+        }
+        eprintln!("{}", Diagnostic::from_error(&err));
+    }
+
+    fn last_emitted_err_code(&self) -> Option<i32> {
+        self.last_error_code
+    }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        eprintln!("{}", Diagnostic::from_warning(&warning));
+    }
+}
+
+/// The severity of a diagnostic.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The diagnostic is an error.
+    Error,
+    /// The diagnostic is a warning.
+    Warning,
+}
+
+/// The location of a span in a source file, with lines and columns starting at 1.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DiagnosticSpan {
+    /// The name of the source file.
+    pub file: String,
+    /// The line the span starts on.
+    pub line_start: usize,
+    /// The column the span starts at.
+    pub column_start: usize,
+    /// The line the span ends on.
+    pub line_end: usize,
+    /// The column just past the end of the span.
+    pub column_end: usize,
+}
+
+impl DiagnosticSpan {
+    /// Locates `span` in the source map, if it belongs to a loaded file.
+    pub fn new(span: Span) -> Option<Self> {
+        let location = with_session_globals(|s| s.source_map.span_to_location(span))?;
+        Some(Self {
+            file: location.source_file.name.to_string(),
+            line_start: location.line_start,
+            column_start: location.col_start,
+            line_end: location.line_stop,
+            column_end: location.col_stop,
+        })
+    }
+}
+
+/// A machine readable error or warning.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Diagnostic {
+    /// The error code, e.g. `EPAR0370005`, if the error has one.
+    pub code: Option<String>,
+    /// Whether the diagnostic is an error or a warning.
+    pub severity: Severity,
+    /// The message of the diagnostic.
+    pub message: String,
+    /// The help message of the diagnostic, if it has one.
+    pub help: Option<String>,
+    /// The primary span of the diagnostic.
+    pub span: Option<DiagnosticSpan>,
+    /// The labelled secondary spans of the diagnostic.
+    pub labels: Vec<DiagnosticLabel>,
+    /// The notes of the diagnostic.
+    pub notes: Vec<String>,
+}
+
+/// A labelled secondary span of a diagnostic.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DiagnosticLabel {
+    /// The location of the span.
+    pub span: Option<DiagnosticSpan>,
+    /// The text of the label.
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Creates the diagnostic of a message with the given codes, span, and labels.
+    fn new(backtraced: &Backtraced, severity: Severity, span: Option<Span>, labels: &[Label]) -> Self {
+        Self {
+            code: Some(match severity {
+                Severity::Error => backtraced.error_code(),
+                Severity::Warning => backtraced.warning_code(),
+            }),
+            severity,
+            message: backtraced.message.clone(),
+            help: backtraced.help.clone(),
+            span: span.and_then(DiagnosticSpan::new),
+            labels: labels
+                .iter()
+                .map(|label| DiagnosticLabel {
+                    span: DiagnosticSpan::new(label.span),
+                    message: label.message.clone(),
+                })
+                .collect(),
+            notes: backtraced.notes.clone(),
+        }
+    }
+
+    /// Creates the diagnostic of an error.
+    /// Errors without a code, such as those from snarkVM, only carry their message.
+    pub fn from_error(err: &LeoError) -> Self {
+        match err.backtraced() {
+            Some(backtraced) => Self::new(backtraced, Severity::Error, err.span(), err.labels()),
+            None => Self {
+                code: None,
+                severity: Severity::Error,
+                message: err.to_string(),
+                help: None,
+                span: None,
+                labels: Vec::new(),
+                notes: Vec::new(),
+            },
+        }
+    }
+
+    /// Creates the diagnostic of a warning.
+    pub fn from_warning(warning: &LeoWarning) -> Self {
+        Self::new(
+            warning.backtraced(),
+            Severity::Warning,
+            warning.span(),
+            warning.labels(),
+        )
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let json = serde_json::to_string(self).map_err(|_| fmt::Error)?;
+        f.write_str(&json)
     }
 }
 
@@ -136,6 +315,21 @@ impl Emitter for BufferEmitter {
     }
 }
 
+/// How the `Handler` reports a warning.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    /// The warning is not reported.
+    Allow,
+    /// The warning is reported as a warning.
+    Warn,
+    /// The warning is reported as an error.
+    Deny,
+}
+
+/// The lint name that sets the level of every warning without a level of its own.
+pub const ALL_WARNINGS: &str = "warnings";
+
 /// Contains the actual data for `Handler`.
 /// Modelled this way to afford an API using interior mutability.
 struct HandlerInner {
@@ -145,6 +339,8 @@ struct HandlerInner {
     warn_count: usize,
     /// The sink through which errors will be emitted.
     emitter: Box<dyn Emitter>,
+    /// The lint levels of warning codes, without their leading `W`, and of `ALL_WARNINGS`.
+    lint_levels: HashMap<String, LintLevel>,
 }
 
 impl HandlerInner {
@@ -164,6 +360,21 @@ impl HandlerInner {
         self.warn_count = self.warn_count.saturating_add(1);
         self.emitter.emit_warning(warning);
     }
+
+    /// Returns the lint level of `warning`.
+    fn lint_level(&self, warning: &LeoWarning) -> LintLevel {
+        let code = warning.error_code();
+        self.lint_levels
+            .get(lint_key(&code))
+            .or_else(|| self.lint_levels.get(ALL_WARNINGS))
+            .copied()
+            .unwrap_or(LintLevel::Warn)
+    }
+}
+
+/// Returns the warning code `lint` without its leading `W`, so that `WPAR0370000` and `PAR0370000` are the same lint.
+fn lint_key(lint: &str) -> &str {
+    lint.strip_prefix('W').unwrap_or(lint)
 }
 
 /// A handler deals with errors and other compiler output.
@@ -175,7 +386,7 @@ pub struct Handler {
 
 impl Default for Handler {
     fn default() -> Self {
-        Self::new(Box::new(StderrEmitter { last_error_code: None }))
+        Self::new(Box::<StderrEmitter>::default())
     }
 }
 
@@ -186,6 +397,7 @@ impl Handler {
             err_count: 0,
             warn_count: 0,
             emitter,
+            lint_levels: HashMap::new(),
         });
         Self { inner }
     }
@@ -209,9 +421,33 @@ impl Handler {
         self.inner.borrow_mut().emit_err(err.into());
     }
 
-    /// Emit the error `err`.
+    /// Emit the warning `warning` according to its lint level.
+    /// Allowed warnings are dropped, and denied warnings are emitted as errors.
     pub fn emit_warning(&self, warning: LeoWarning) {
-        self.inner.borrow_mut().emit_warning(warning);
+        let level = self.inner.borrow().lint_level(&warning);
+        match level {
+            LintLevel::Allow => {}
+            LintLevel::Warn => self.inner.borrow_mut().emit_warning(warning),
+            LintLevel::Deny => self.emit_err(warning.deny()),
+        }
+    }
+
+    /// Sets the lint level of the warning code `lint`, e.g. `WPAR0370000`, or of `ALL_WARNINGS`.
+    /// The level of a warning code takes precedence over the level of `ALL_WARNINGS`.
+    /// Returns an error if `lint` is neither the code of a warning nor `ALL_WARNINGS`.
+    pub fn set_lint_level(&self, lint: &str, level: LintLevel) -> Result<(), LeoError> {
+        let key = match lint {
+            ALL_WARNINGS => ALL_WARNINGS.to_string(),
+            _ => {
+                let key = lint_key(&lint.to_ascii_uppercase()).to_string();
+                if MessageName::from_code(&format!("W{}", key)).is_none() {
+                    return Err(CliError::unknown_lint(lint).into());
+                }
+                key
+            }
+        };
+        self.inner.borrow_mut().lint_levels.insert(key, level);
+        Ok(())
     }
 
     /// Emits the error `err`.
@@ -247,6 +483,16 @@ impl Handler {
         }
     }
 
+    /// Gets the last emitted error's exit code if more than `count` errors have been emitted.
+    /// This lets a pass fail on its own errors only, when given the error count from before it ran.
+    pub fn last_err_after(&self, count: usize) -> Result<(), LeoError> {
+        if self.err_count() > count {
+            self.last_err()
+        } else {
+            Ok(())
+        }
+    }
+
     /// Extend handler with `error` given `res = Err(error)`.
     #[allow(clippy::result_unit_err)]
     pub fn extend_if_error<T>(&self, res: Result<T, LeoError>) -> Result<T, ()> {
@@ -264,8 +510,8 @@ impl Handler {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ParserError;
-    use leo_span::{symbol::create_session_if_not_set_then, Span};
+    use crate::{AstError, LintWarning, ParserError, ParserWarning};
+    use leo_span::{source_map::FileName, span::BytePos, symbol::create_session_if_not_set_then, Span};
 
     #[test]
     fn fresh_no_errors() {
@@ -302,4 +548,87 @@ mod tests {
             Handler::with(|_| Ok(())).unwrap();
         })
     }
+
+    #[test]
+    fn labels_and_notes() {
+        create_session_if_not_set_then(|s| {
+            let source_file = s
+                .source_map
+                .new_source("let x = 1;\nlet x = 2;\n", FileName::Custom("main.leo".into()));
+            let previous = Span::new(source_file.start_pos + BytePos(4), source_file.start_pos + BytePos(5));
+            let span = Span::new(source_file.start_pos + BytePos(15), source_file.start_pos + BytePos(16));
+
+            let err: LeoError = AstError::shadowed_variable("x", previous, span)
+                .with_note("Leo does not allow shadowing.")
+                .into();
+            let rendered = err.to_string();
+            assert!(rendered.contains("   1 | let x = 1;\n     |     - previous definition of `x` here"));
+            assert!(rendered.ends_with("     = note: Leo does not allow shadowing."));
+
+            let diagnostic = Diagnostic::from_error(&err);
+            assert_eq!(diagnostic.labels.len(), 1);
+            assert_eq!(diagnostic.labels[0].span.as_ref().unwrap().line_start, 1);
+            assert_eq!(diagnostic.notes, vec!["Leo does not allow shadowing.".to_string()]);
+        })
+    }
+
+    #[test]
+    fn json_diagnostic() {
+        create_session_if_not_set_then(|s| {
+            let source_file = s
+                .source_map
+                .new_source("let x = y;\n", FileName::Custom("main.leo".into()));
+            let span = Span::new(source_file.start_pos + BytePos(8), source_file.start_pos + BytePos(9));
+
+            let err = ParserError::unexpected_eof(span).into();
+            let diagnostic = Diagnostic::from_error(&err);
+            assert_eq!(diagnostic.code, Some(err.error_code()));
+            assert_eq!(diagnostic.severity, Severity::Error);
+            assert_eq!(
+                diagnostic.span,
+                Some(DiagnosticSpan {
+                    file: "main.leo".into(),
+                    line_start: 1,
+                    column_start: 9,
+                    line_end: 1,
+                    column_end: 10,
+                })
+            );
+
+            let json = diagnostic.to_string();
+            assert!(!json.contains('\n'));
+            assert!(json.contains(r#""severity":"error""#));
+        })
+    }
+
+    #[test]
+    fn lint_levels() {
+        create_session_if_not_set_then(|_| {
+            let (handler, buf) = Handler::new_with_buf();
+            let s = Span::default();
+
+            handler.set_lint_level(ALL_WARNINGS, LintLevel::Deny).unwrap();
+            handler.set_lint_level("PAR0370000", LintLevel::Allow).unwrap();
+            handler.emit_warning(ParserWarning::const_parameter_or_input(s).into());
+            assert_eq!(handler.warning_count(), 0);
+            assert_eq!(handler.err_count(), 0);
+
+            handler.emit_warning(LintWarning::unused_variable("x", s).into());
+            assert_eq!(handler.err_count(), 1);
+            let errs = buf.extract_errs().to_string();
+            assert!(errs.starts_with("Error [ELIN0370000]: Unused variable `x`."));
+            assert!(errs.contains("= note: `WLIN0370000` is denied"));
+
+            handler.set_lint_level("wlin0370000", LintLevel::Warn).unwrap();
+            handler.emit_warning(LintWarning::unused_variable("x", s).into());
+            assert_eq!(handler.warning_count(), 1);
+            assert_eq!(handler.err_count(), 1);
+
+            // Only warning codes have lint levels.
+            for lint in ["unused_variable", "WLIN0379999", "EPAR0370000", "Warnings"] {
+                let err = handler.set_lint_level(lint, LintLevel::Allow).unwrap_err();
+                assert!(err.to_string().contains(&format!("Unknown lint `{}`.", lint)));
+            }
+        })
+    }
 }
@@ -15,6 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::create_messages;
+use leo_span::Span;
 use std::{
     error::Error as ErrorArg,
     fmt::{Debug, Display},
@@ -93,32 +94,44 @@ create_messages!(
     /// For when a user shadows a function.
     @formatted
     shadowed_function {
-        args: (func: impl Display),
+        args: (func: impl Display, previous: Span),
         msg: format!("function `{func}` shadowed by"),
         help: None,
+        labels: [(previous, format!("previous definition of `{func}` here"))],
     }
 
     /// For when a user shadows a circuit.
     @formatted
     shadowed_circuit {
-        args: (circ: impl Display),
+        args: (circ: impl Display, previous: Span),
         msg: format!("circuit `{circ}` shadowed by"),
         help: None,
+        labels: [(previous, format!("previous definition of `{circ}` here"))],
     }
 
     /// For when a user shadows a record.
     @formatted
     shadowed_record {
-        args: (record: impl Display),
+        args: (record: impl Display, previous: Span),
         msg: format!("record `{record}` shadowed by"),
         help: None,
+        labels: [(previous, format!("previous definition of `{record}` here"))],
     }
 
     /// For when a user shadows a variable.
     @formatted
     shadowed_variable {
-        args: (var: impl Display),
+        args: (var: impl Display, previous: Span),
         msg: format!("variable `{var}` shadowed by"),
         help: None,
+        labels: [(previous, format!("previous definition of `{var}` here"))],
+    }
+
+    /// For when a value is converted to a literal but has no literal form, e.g. a tuple.
+    @backtraced
+    value_is_not_a_literal {
+        args: (value: impl Display),
+        msg: format!("the value `{value}` cannot be written as a literal"),
+        help: None,
     }
 );
@@ -115,4 +115,109 @@ create_messages!(
         msg: format!("Failed to parse the `aleo run` command.\nSnarkVM Error: {}", error),
         help: None,
     }
+
+    @backtraced
+    unknown_template {
+        args: (name: impl Display, templates: impl Display),
+        msg: format!("Unknown package template `{}`.", name),
+        help: Some(format!("Available templates are: {}.", templates)),
+    }
+
+    @backtraced
+    library_not_runnable {
+        args: (package: impl Display),
+        msg: format!("The package `{}` is a library and cannot be run.", package),
+        help: Some("Import the library from a program package, and run that instead.".to_string()),
+    }
+
+    @backtraced
+    unknown_message_code {
+        args: (code: impl Display),
+        msg: format!("`{}` is not the code of a Leo error or warning.", code),
+        help: Some("Codes look like `EPAR0370003`, as shown in the brackets of an error or warning.".to_string()),
+    }
+
+    @backtraced
+    no_explanation {
+        args: (code: impl Display, message: impl Display),
+        msg: format!("There is no explanation for `{}` (`{}`) yet.", code, message),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_source_map {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to write the source map `{}`.\nError: {}", path, error),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_formatted_file {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to write the formatted file `{}`.\nError: {}", path, error),
+        help: None,
+    }
+
+    @backtraced
+    files_not_formatted {
+        args: (count: impl Display),
+        msg: format!("{} file(s) are not formatted.", count),
+        help: Some("Run `leo fmt` to format them.".to_string()),
+    }
+
+    @backtraced
+    failed_to_read_lsp_message {
+        args: (error: impl Display),
+        msg: format!("Failed to read a message from the language client.\nError: {}", error),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_lsp_message {
+        args: (error: impl Display),
+        msg: format!("Failed to write a message to the language client.\nError: {}", error),
+        help: None,
+    }
+
+    @backtraced
+    invalid_lsp_message_header {
+        args: (header: impl Display),
+        msg: format!("The message header `{}` does not give the length of the message.", header),
+        help: Some("Language clients start every message with a `Content-Length` header.".to_string()),
+    }
+
+    @backtraced
+    tests_failed {
+        args: (failed: impl Display),
+        msg: format!("{} test(s) failed.", failed),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_test_program {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to write the test program `{}`.\nError: {}", path, error),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_abi {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to write the program interface `{}`.\nError: {}", path, error),
+        help: None,
+    }
+
+    @formatted
+    test_returned_false {
+        args: (),
+        msg: "The test returned `false`.",
+        help: None,
+    }
+
+    @backtraced
+    unknown_lint {
+        args: (lint: impl Display),
+        msg: format!("Unknown lint `{}`.", lint),
+        help: Some("Lints are warning codes, e.g. `WLIN0370000`, or `warnings` for all warnings. See `leo explain <code>`.".to_string()),
+    }
 );
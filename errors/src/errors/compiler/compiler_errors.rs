@@ -56,4 +56,46 @@ create_messages!(
         msg: format!("Failed to open current working directory. Error: {err}"),
         help: None,
     }
+
+    /// For when a chain of imports leads back to the importing file.
+    @formatted
+    cyclic_import {
+        args: (cycle: impl Display),
+        msg: format!("Cyclic import detected: `{cycle}`."),
+        help: Some("Remove one of the imports to break the cycle.".to_string()),
+    }
+
+    /// For when snarkVM rejects an Aleo instruction generated for a Leo function or circuit.
+    @formatted
+    invalid_aleo_instruction {
+        args: (instruction: impl Display, error: impl Display),
+        msg: format!("The Aleo instruction `{instruction}` generated for this Leo code is invalid.\nSnarkVM Error: {error}"),
+        help: None,
+        notes: ["this is an internal compiler error, please report it at https://github.com/AleoHQ/leo/issues/new?labels=bug"],
+    }
+
+    /// For when snarkVM rejects the generated Aleo instructions, and the Leo definition they were generated for is unknown.
+    @backtraced
+    invalid_aleo_program {
+        args: (error: impl Display),
+        msg: format!("The generated Aleo instructions are invalid.\nSnarkVM Error: {error}"),
+        help: None,
+        notes: ["this is an internal compiler error, please report it at https://github.com/AleoHQ/leo/issues/new?labels=bug"],
+    }
+
+    /// For when an imported program was never filled in after parsing.
+    @formatted
+    unresolved_import {
+        args: (import: impl Display),
+        msg: format!("The import `{import}.leo` was not resolved to a program."),
+        help: Some("Resolve the imports of the program with `Compiler::resolve_imports` before compiling it.".to_string()),
+    }
+
+    /// For when a program defines a circuit, record or function with the same name as one of a library it imports.
+    @formatted
+    library_name_conflict {
+        args: (name: impl Display, library: impl Display),
+        msg: format!("`{name}` is already defined by the imported library `{library}.leo`."),
+        help: Some("Rename the definition, since the definitions of a library are included in the importing program.".to_string()),
+    }
 );
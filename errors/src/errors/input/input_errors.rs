@@ -58,4 +58,12 @@ create_messages!(
         ),
         help: None,
     }
+
+    /// For when an integer in an input does not fit its type.
+    @backtraced
+    integer_out_of_range {
+        args: (value: impl Display, type_: impl Display),
+        msg: format!("the value '{}' does not fit the type '{}'", value, type_),
+        help: None,
+    }
 );
@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+use std::fmt::{Debug, Display};
+
+create_messages!(
+    /// InterpreterError enum that represents the failures of the interpreter when it evaluates a program.
+    InterpreterError,
+    code_mask: 8000i32,
+    code_prefix: "INT",
+
+    /// For when a `console.assert` fails.
+    @formatted
+    assertion_failed {
+        args: (assertion: impl Display),
+        msg: format!("Assertion `{assertion}` failed."),
+        help: None,
+    }
+
+    /// For when a `console.assert` of a comparison fails.
+    @formatted
+    comparison_assertion_failed {
+        args: (assertion: impl Display, left: impl Display, right: impl Display),
+        msg: format!("Assertion `{assertion}` failed.\nThe left side is `{left}` and the right side is `{right}`."),
+        help: None,
+    }
+
+    /// For when a checked binary operation overflows.
+    @formatted
+    binary_operation_overflow {
+        args: (left: impl Display, op: impl Display, right: impl Display),
+        msg: format!("The operation `{left} {op} {right}` overflows."),
+        help: Some("Use the wrapping version of the operation, e.g. `add_wrapped`, if the overflow is intended.".to_string()),
+    }
+
+    /// For when a checked unary operation overflows.
+    @formatted
+    unary_operation_overflow {
+        args: (operand: impl Display, op: impl Display),
+        msg: format!("The operation `{op}` overflows on `{operand}`."),
+        help: None,
+    }
+
+    /// For when a value is divided by zero.
+    @formatted
+    division_by_zero {
+        args: (left: impl Display),
+        msg: format!("Attempted to divide `{left}` by zero."),
+        help: None,
+    }
+
+    /// For when zero is inverted.
+    @formatted
+    inverse_of_zero {
+        args: (),
+        msg: "Attempted to invert zero.".to_string(),
+        help: None,
+    }
+
+    /// For when the square root of a value does not exist.
+    @formatted
+    no_square_root {
+        args: (operand: impl Display),
+        msg: format!("`{operand}` has no square root."),
+        help: None,
+    }
+
+    /// For when snarkVM cannot represent a value, e.g. a group literal that is not on the curve.
+    @formatted
+    invalid_value {
+        args: (value: impl Display, error: impl Display),
+        msg: format!("`{value}` is not a valid value.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    /// For when a core function fails in snarkVM.
+    @formatted
+    core_function_failed {
+        args: (function: impl Display, error: impl Display),
+        msg: format!("The core function `{function}` failed.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    /// For when a function to call does not exist.
+    @backtraced
+    unknown_function {
+        args: (function: impl Display),
+        msg: format!("The program has no function `{function}`."),
+        help: None,
+    }
+
+    /// For when a function is called with the wrong number of arguments.
+    @backtraced
+    wrong_number_of_arguments {
+        args: (function: impl Display, expected: impl Display, found: impl Display),
+        msg: format!("The function `{function}` takes {expected} arguments, but {found} were given."),
+        help: None,
+    }
+
+    /// For when a function ends without returning a value.
+    @formatted
+    missing_return {
+        args: (function: impl Display),
+        msg: format!("The function `{function}` ended without returning a value."),
+        help: None,
+    }
+
+    /// For when a program uses a construct the interpreter does not evaluate.
+    @formatted
+    unsupported {
+        args: (construct: impl Display),
+        msg: format!("The interpreter does not support {construct}."),
+        help: None,
+    }
+);
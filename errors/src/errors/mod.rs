@@ -15,7 +15,9 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 /// Contains the ASG error definitions.
-use crate::LeoMessageCode;
+use crate::{Backtraced, Label, LeoMessageCode, LeoWarning};
+
+use leo_span::Span;
 
 /// Contains the AST error definitions.
 pub mod ast;
@@ -37,6 +39,10 @@ pub use self::flattener::*;
 pub mod input;
 pub use self::input::*;
 
+/// Contains the Interpreter error definitions.
+pub mod interpreter;
+pub use self::interpreter::*;
+
 /// Contains the Package error definitions.
 pub mod package;
 pub use self::package::*;
@@ -45,10 +51,19 @@ pub use self::package::*;
 pub mod parser;
 pub use self::parser::*;
 
+/// Contains the SnarkVM error definitions.
+pub mod snarkvm;
+pub use self::snarkvm::*;
+
 /// Contains the Type Checker error definitions.
 pub mod type_checker;
 pub use self::type_checker::*;
 
+/// The exit code of a failure caused by warnings whose lint level is deny.
+/// It differs from the exit codes of all other errors and from the usage errors of clap,
+/// also as the 8 bits of an exit status, so that CI can tell denied warnings apart.
+pub const DENIED_WARNING_EXIT_CODE: i32 = 79;
+
 /// The LeoError type that contains all sub error types.
 /// This allows a unified error type throughout the Leo crates.
 #[derive(Debug, Error)]
@@ -77,13 +92,26 @@ pub enum LeoError {
     /// Represents a Flatten Error in a Leo Error.
     #[error(transparent)]
     FlattenError(#[from] FlattenError),
+    /// Represents a SnarkVM Error in a Leo Error.
+    #[error(transparent)]
+    SnarkVMError(#[from] SnarkVMError),
+    /// Represents an Interpreter Error in a Leo Error.
+    #[error(transparent)]
+    InterpreterError(#[from] InterpreterError),
+    /// Represents a warning whose lint level is deny.
+    #[error("{0}")]
+    DeniedWarning(Box<LeoWarning>),
     /// Purely for just exiting with the correct status code and
     /// not re-displaying an error.
     #[error("")]
     LastErrorCode(i32),
-    /// Anyhow errors.
-    #[error(transparent)]
-    Anyhow(#[from] anyhow::Error),
+}
+
+impl From<anyhow::Error> for LeoError {
+    /// SnarkVM reports its errors through `anyhow`, without codes of their own.
+    fn from(error: anyhow::Error) -> Self {
+        SnarkVMError::unclassified(format!("{:#}", error)).into()
+    }
 }
 
 impl LeoError {
@@ -100,8 +128,10 @@ impl LeoError {
             PackageError(error) => error.error_code(),
             TypeCheckerError(error) => error.error_code(),
             FlattenError(error) => error.error_code(),
+            SnarkVMError(error) => error.error_code(),
+            InterpreterError(error) => error.error_code(),
+            DeniedWarning(warning) => warning.backtraced().error_code(),
             LastErrorCode(_) => unreachable!(),
-            Anyhow(_) => unimplemented!(), // todo: implement error codes for snarkvm errors.
         }
     }
 
@@ -118,11 +148,106 @@ impl LeoError {
             PackageError(error) => error.exit_code(),
             TypeCheckerError(error) => error.exit_code(),
             FlattenError(error) => error.exit_code(),
+            SnarkVMError(error) => error.exit_code(),
+            InterpreterError(error) => error.exit_code(),
+            DeniedWarning(_) => DENIED_WARNING_EXIT_CODE,
             LastErrorCode(code) => *code,
-            Anyhow(_) => unimplemented!(), // todo: implement exit codes for snarkvm errors.
+        }
+    }
+
+    /// Returns the message, help, and codes of the error, if it has them.
+    pub fn backtraced(&self) -> Option<&Backtraced> {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => Some(error.backtraced()),
+            CompilerError(error) => Some(error.backtraced()),
+            CliError(error) => Some(error.backtraced()),
+            InputError(error) => Some(error.backtraced()),
+            ParserError(error) => Some(error.backtraced()),
+            PackageError(error) => Some(error.backtraced()),
+            TypeCheckerError(error) => Some(error.backtraced()),
+            FlattenError(error) => Some(error.backtraced()),
+            SnarkVMError(error) => Some(error.backtraced()),
+            InterpreterError(error) => Some(error.backtraced()),
+            DeniedWarning(warning) => Some(warning.backtraced()),
+            LastErrorCode(_) => None,
+        }
+    }
+
+    /// Returns the labelled secondary spans of the error.
+    pub fn labels(&self) -> &[Label] {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => error.labels(),
+            CompilerError(error) => error.labels(),
+            CliError(error) => error.labels(),
+            InputError(error) => error.labels(),
+            ParserError(error) => error.labels(),
+            PackageError(error) => error.labels(),
+            TypeCheckerError(error) => error.labels(),
+            FlattenError(error) => error.labels(),
+            SnarkVMError(error) => error.labels(),
+            InterpreterError(error) => error.labels(),
+            DeniedWarning(warning) => warning.labels(),
+            LastErrorCode(_) => &[],
+        }
+    }
+
+    /// Returns the span the error points to, if it has one.
+    pub fn span(&self) -> Option<Span> {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => error.span(),
+            CompilerError(error) => error.span(),
+            CliError(error) => error.span(),
+            InputError(error) => error.span(),
+            ParserError(error) => error.span(),
+            PackageError(error) => error.span(),
+            TypeCheckerError(error) => error.span(),
+            FlattenError(error) => error.span(),
+            SnarkVMError(error) => error.span(),
+            InterpreterError(error) => error.span(),
+            DeniedWarning(warning) => warning.span(),
+            LastErrorCode(_) => None,
         }
     }
 }
 
 /// A global result type for all Leo crates, that defaults the errors to be a LeoError.
 pub type Result<T, E = LeoError> = core::result::Result<T, E>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the exit codes of the errors of type `T`.
+    fn exit_codes<T: LeoMessageCode>(names: &[&str]) -> impl Iterator<Item = i32> {
+        let first = 10_000 * i32::from(T::code_identifier()) + T::code_mask();
+        (0..names.len() as i32).map(move |index| first + index)
+    }
+
+    #[test]
+    fn denied_warning_exit_code() {
+        // clap exits with 2 on usage errors, and Rust with 101 on panics.
+        let codes = [1, 2, 101]
+            .into_iter()
+            .chain(exit_codes::<AstError>(AstError::MESSAGE_NAMES))
+            .chain(exit_codes::<CliError>(CliError::MESSAGE_NAMES))
+            .chain(exit_codes::<CompilerError>(CompilerError::MESSAGE_NAMES))
+            .chain(exit_codes::<FlattenError>(FlattenError::MESSAGE_NAMES))
+            .chain(exit_codes::<InputError>(InputError::MESSAGE_NAMES))
+            .chain(exit_codes::<InterpreterError>(InterpreterError::MESSAGE_NAMES))
+            .chain(exit_codes::<PackageError>(PackageError::MESSAGE_NAMES))
+            .chain(exit_codes::<ParserError>(ParserError::MESSAGE_NAMES))
+            .chain(exit_codes::<SnarkVMError>(SnarkVMError::MESSAGE_NAMES))
+            .chain(exit_codes::<TypeCheckerError>(TypeCheckerError::MESSAGE_NAMES));
+
+        // Unix keeps the low 8 bits of an exit code as the exit status.
+        for code in codes {
+            assert_ne!(code & 0xff, DENIED_WARNING_EXIT_CODE, "exit code {} is taken", code);
+        }
+    }
+}
@@ -291,4 +291,43 @@ create_messages!(
         msg: format!("Failed to remove aleo file: {}.", error),
         help: None,
     }
+
+    @backtraced
+    failed_to_create_manifest {
+        args: (error: impl Display),
+        msg: format!("Failed to create manifest file: {}", error),
+        help: None,
+    }
+
+    /// For when initializing a package would overwrite existing files.
+    @backtraced
+    package_already_exists {
+        args: (path: impl Debug),
+        msg: format!("Cannot initialize a package at {:?}, it would overwrite existing files.", path),
+        help: Some("Remove the existing files, or create a package in a new directory by running `leo new`.".to_string()),
+    }
+
+    /// For when a package in `imports/` is not a library.
+    @backtraced
+    imported_package_not_library {
+        args: (package: impl Display),
+        msg: format!("The imported package `{}` is not a library.", package),
+        help: Some("Only packages declaring `\"kind\": \"library\"` in their `program.json` can be imported.".to_string()),
+    }
+
+    /// For when a documentation file cannot be written.
+    @backtraced
+    failed_to_write_doc_file {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to write documentation file `{}`: {}", path, error),
+        help: None,
+    }
+
+    /// For when a package name is not a valid Aleo program name.
+    @backtraced
+    invalid_program_name {
+        args: (package: impl Display, error: impl Display),
+        msg: format!("The package name `{}` is not a valid Aleo program name: {}", package, error),
+        help: Some("Program names start with a letter, and contain only letters and numbers.".to_string()),
+    }
 );
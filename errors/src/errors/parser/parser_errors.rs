@@ -253,4 +253,11 @@ create_messages!(
         msg: format!("Invalid import call to non-leo file `{name}`."),
         help: Some("Only imports of Leo `.leo` files are currently supported.".to_string()),
     }
+
+    @formatted
+    unknown_annotation {
+        args: (annotation: impl Display),
+        msg: format!("Unknown annotation `{annotation}`."),
+        help: Some("Functions may only be annotated with `@test`, which marks them as tests for `leo test`.".to_string()),
+    }
 );
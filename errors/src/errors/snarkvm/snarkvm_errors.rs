@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+use std::fmt::{Debug, Display};
+
+create_messages!(
+    /// SnarkVMError enum that represents the failures of snarkVM when it builds or runs a compiled program.
+    SnarkVMError,
+    code_mask: 9000i32,
+    code_prefix: "SVM",
+
+    /// For when snarkVM rejects the Aleo instructions of a program.
+    @backtraced
+    malformed_program {
+        args: (error: impl Display),
+        msg: format!("snarkVM rejected the generated Aleo instructions.\nSnarkVM Error: {}", error),
+        help: Some("The instructions are left in the `build` directory. This is a bug in the Leo compiler, please report it.".to_string()),
+    }
+
+    /// For when snarkVM fails to synthesize the circuit of a function, e.g. to generate its keys.
+    @backtraced
+    synthesis_failed {
+        args: (error: impl Display),
+        msg: format!("snarkVM failed to synthesize the circuit of a function.\nSnarkVM Error: {}", error),
+        help: None,
+    }
+
+    /// For when the evaluation of a function fails on its inputs, e.g. at a failing `console.assert`.
+    @formatted
+    unsatisfied_constraint {
+        args: (function: impl Display, error: impl Display),
+        msg: format!("The function `{}` does not satisfy its constraints on the given inputs.\nSnarkVM Error: {}", function, error),
+        help: Some("Check the assertions and arithmetic of the function against the values in the input file.".to_string()),
+    }
+
+    /// For when snarkVM rejects the inputs given to a function.
+    @formatted
+    invalid_inputs {
+        args: (function: impl Display, error: impl Display),
+        msg: format!("Invalid inputs to the function `{}`.\nSnarkVM Error: {}", function, error),
+        help: Some("The input file must provide one value of the right type for each input of the function.".to_string()),
+    }
+
+    /// For when `aleo build` fails for another reason.
+    @backtraced
+    build_failed {
+        args: (error: impl Display),
+        msg: format!("Failed to build the Aleo program.\nSnarkVM Error: {}", error),
+        help: None,
+    }
+
+    /// For when `aleo run` fails for another reason.
+    @backtraced
+    execution_failed {
+        args: (function: impl Display, error: impl Display),
+        msg: format!("Failed to run the function `{}`.\nSnarkVM Error: {}", function, error),
+        help: None,
+    }
+
+    /// For when a snarkVM error reaches Leo without being classified.
+    @backtraced
+    unclassified {
+        args: (error: impl Display),
+        msg: format!("SnarkVM Error: {}", error),
+        help: None,
+    }
+);
@@ -259,4 +259,11 @@ create_messages!(
         msg: format!("Tuples are only allowed as function return types."),
         help: None,
     }
+
+    @formatted
+    test_function_with_inputs {
+        args: (function: impl Display),
+        msg: format!("The test function `{function}` has inputs."),
+        help: Some("Test functions are run without inputs, declare the values to test with in the function body.".to_string()),
+    }
 );
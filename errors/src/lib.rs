@@ -25,6 +25,10 @@ extern crate thiserror;
 pub mod common;
 pub use self::common::*;
 
+/// Contains the lookup of messages by their codes.
+pub mod codes;
+pub use self::codes::*;
+
 /// Contains traits and types for channels through which errors go.
 pub mod emitter;
 /// Contains the errors for the Leo lang.
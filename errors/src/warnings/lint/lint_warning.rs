@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+
+use leo_span::Span;
+use std::fmt::Display;
+
+create_messages!(
+    /// LintWarning enum that represents all the warnings for the lints in the `leo-passes` crate.
+    LintWarning,
+    code_mask: 0000i32,
+    code_prefix: "LIN",
+
+    /// For when a variable is defined but never read.
+    @formatted
+    unused_variable {
+        args: (name: impl Display),
+        msg: format!("Unused variable `{name}`."),
+        help: None,
+    }
+
+    /// For when a function input is never read.
+    @formatted
+    unused_input {
+        args: (name: impl Display),
+        msg: format!("Unused function input `{name}`."),
+        help: None,
+    }
+
+    /// For when a function cannot be reached from any entry point.
+    /// Not emitted, since every function of a program is a function of its Aleo program, which may be called directly.
+    @formatted
+    unused_function {
+        args: (name: impl Display),
+        msg: format!("Function `{name}` is never used."),
+        help: Some("A function is used if it is called from `main`, or from a function named by a section of the input file.".to_string()),
+    }
+
+    /// For when a circuit is never referred to.
+    @formatted
+    unused_circuit {
+        args: (name: impl Display),
+        msg: format!("Circuit `{name}` is never used."),
+        help: None,
+    }
+
+    /// For when a record is never referred to.
+    @formatted
+    unused_record {
+        args: (name: impl Display),
+        msg: format!("Record `{name}` is never used."),
+        help: None,
+    }
+
+    /// For when a statement follows a return statement.
+    @formatted
+    unreachable_statement {
+        args: (return_span: Span),
+        msg: "Unreachable statement.",
+        help: None,
+        labels: [(return_span, "any code following this return statement is unreachable")],
+    }
+);
@@ -16,7 +16,13 @@
 
 /// The LeoError type that contains all sub error types.
 /// This allows a unified error type throughout the Leo crates.
-use crate::LeoMessageCode;
+use crate::{Backtraced, Label, LeoError, LeoMessageCode};
+
+use leo_span::Span;
+
+/// Contains the Lint warning definitions.
+pub mod lint;
+pub use self::lint::*;
 
 /// Contains the Parser warning definitions.
 pub mod parser;
@@ -29,6 +35,9 @@ pub enum LeoWarning {
     /// Represents an Parser Error in a Leo Error.
     #[error(transparent)]
     ParserWarning(#[from] ParserWarning),
+    /// Represents a Lint Warning in a Leo Warning.
+    #[error(transparent)]
+    LintWarning(#[from] LintWarning),
 }
 
 impl LeoWarning {
@@ -38,6 +47,48 @@ impl LeoWarning {
 
         match self {
             ParserWarning(warning) => warning.warning_code(),
+            LintWarning(warning) => warning.warning_code(),
         }
     }
+
+    /// Returns the message, help, and codes of the warning.
+    pub fn backtraced(&self) -> &Backtraced {
+        use LeoWarning::*;
+
+        match self {
+            ParserWarning(warning) => warning.backtraced(),
+            LintWarning(warning) => warning.backtraced(),
+        }
+    }
+
+    /// Returns the labelled secondary spans of the warning.
+    pub fn labels(&self) -> &[Label] {
+        use LeoWarning::*;
+
+        match self {
+            ParserWarning(warning) => warning.labels(),
+            LintWarning(warning) => warning.labels(),
+        }
+    }
+
+    /// Returns the span the warning points to, if it has one.
+    pub fn span(&self) -> Option<Span> {
+        use LeoWarning::*;
+
+        match self {
+            ParserWarning(warning) => warning.span(),
+            LintWarning(warning) => warning.span(),
+        }
+    }
+
+    /// Turns the warning into an error, for when its lint level is deny.
+    pub fn deny(self) -> LeoError {
+        use LeoWarning::*;
+
+        let warning = match self {
+            ParserWarning(warning) => ParserWarning(warning.deny()),
+            LintWarning(warning) => LintWarning(warning.deny()),
+        };
+        LeoError::DeniedWarning(Box::new(warning))
+    }
 }
@@ -14,14 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::commands::ALEO_CLI_COMMAND;
+use crate::commands::plan::{BuildPlan, Unit};
+use crate::commands::{Network, ALEO_CLI_COMMAND};
 use crate::{commands::Command, context::Context};
 
 use leo_ast::Circuit;
-use leo_compiler::{Compiler, InputAst, OutputOptions};
-use leo_errors::{CliError, CompilerError, PackageError, Result};
-use leo_package::source::{SourceDirectory, MAIN_FILENAME};
-use leo_package::{inputs::InputFile, outputs::OutputsDirectory};
+use leo_compiler::{source_map, Abi, InputAst, InstructionSpan, OutputOptions};
+use leo_errors::{CliError, CompilerError, PackageError, Result, SnarkVMError};
+use leo_package::{inputs::InputFile, outputs::OutputsDirectory, package::PackageKind};
 use leo_span::symbol::with_session_globals;
 
 use aleo::commands::Build as AleoBuild;
@@ -30,9 +30,8 @@ use clap::StructOpt;
 use colored::Colorize;
 use indexmap::IndexMap;
 use std::io::Write;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
-use leo_errors::emitter::Handler;
 use leo_package::build::BuildDirectory;
 use leo_package::imports::ImportsDirectory;
 use leo_span::Symbol;
@@ -58,6 +57,14 @@ pub struct BuildOptions {
         help = "Runs the code generation stage of the compiler and prints the resulting bytecode."
     )]
     pub enable_code_generation: bool,
+    #[structopt(
+        long,
+        help = "Interleaves the lines of Leo code as comments into the Aleo instructions."
+    )]
+    pub enable_source_comments: bool,
+    /// Whether to generate the `@test` functions, which `leo test --prove` runs with snarkVM.
+    #[structopt(skip)]
+    pub test_functions: bool,
 }
 
 impl From<BuildOptions> for OutputOptions {
@@ -67,6 +74,8 @@ impl From<BuildOptions> for OutputOptions {
             initial_input_ast: options.enable_initial_input_ast_snapshot,
             initial_ast: options.enable_initial_ast_snapshot,
             unrolled_ast: options.enable_unrolled_ast_snapshot,
+            source_comments: options.enable_source_comments,
+            test_functions: options.test_functions,
         };
         if options.enable_all_ast_snapshots {
             out_options.initial_input_ast = true;
@@ -87,7 +96,13 @@ pub struct Build {
 
 impl Command for Build {
     type Input = ();
-    type Output = (Option<InputAst>, IndexMap<Symbol, Circuit>);
+    /// The input file, the circuits of all files, and the name spans of the functions of the source files.
+    type Output = (
+        Option<InputAst>,
+        IndexMap<Symbol, Circuit>,
+        IndexMap<Symbol, leo_span::Span>,
+        Vec<InstructionSpan>,
+    );
 
     fn log_span(&self) -> Span {
         tracing::span!(tracing::Level::INFO, "Build")
@@ -111,53 +126,25 @@ impl Command for Build {
         let build_directory = BuildDirectory::open(&package_path)?;
 
         // Initialize error handler
-        let handler = leo_errors::emitter::Handler::default();
-
-        // Fetch paths to all .leo files in the source directory.
-        let source_files = SourceDirectory::files(&package_path)?;
+        let handler = context.handler()?;
 
-        // Store all circuits declarations made in the source files.
-        let mut circuits = IndexMap::new();
+        // Parse every .leo file once and order the files by their imports.
+        let plan = BuildPlan::new(
+            &package_path,
+            &package_name,
+            &handler,
+            &outputs_directory,
+            self.compiler_options.clone(),
+        )?;
 
-        // Compile all .leo files into .aleo files.
-        for file_path in source_files.into_iter() {
-            circuits.extend(compile_leo_file(
-                file_path,
-                &package_path,
-                &package_name,
-                &outputs_directory,
-                &build_directory,
-                &handler,
-                self.compiler_options.clone(),
-            )?);
-        }
-
-        if !ImportsDirectory::is_empty(&package_path)? {
-            // Create Aleo build/imports/ directory.
-            let build_imports_directory = ImportsDirectory::create(&build_directory)?;
-
-            // Fetch paths to all .leo files in the imports directory.
-            let import_files = ImportsDirectory::files(&package_path)?;
-
-            // Compile all .leo files into .aleo files.
-            for file_path in import_files.into_iter() {
-                circuits.extend(compile_leo_file(
-                    file_path,
-                    &package_path,
-                    &package_name,
-                    &outputs_directory,
-                    &build_imports_directory,
-                    &handler,
-                    self.compiler_options.clone(),
-                )?);
-            }
-        }
+        // Library packages are type checked, but not compiled into a program.
+        let library = PackageKind::read_from(&package_path)? == PackageKind::Library;
 
         // Load the input file at `package_name.in`
         let input_file_path = InputFile::new(&package_name).setup_file_path(&package_path);
 
         // Parse the input file.
-        let input_ast = if input_file_path.exists() {
+        let input_ast = if input_file_path.exists() && !library {
             // Load the input file into the source map.
             let input_sf = with_session_globals(|s| s.source_map.load_file(&input_file_path))
                 .map_err(|e| CompilerError::file_read_error(&input_file_path, e))?;
@@ -170,58 +157,67 @@ impl Command for Build {
             None
         };
 
+        // Store all circuits declarations made in the source files.
+        let mut circuits = IndexMap::new();
+
+        // Compile all imported .leo files into .aleo files, imported programs first.
+        if !plan.imports.is_empty() {
+            // Create Aleo build/imports/ directory.
+            let build_imports_directory = ImportsDirectory::create(&build_directory)?;
+
+            for unit in plan.imports.into_values() {
+                circuits.extend(compile_leo_file(unit, &build_imports_directory)?.0);
+            }
+        }
+
+        // Compile all source .leo files into .aleo files.
+        // Failures of `leo run` point to the names of the functions of the source files, or to the Leo code
+        // of their instructions in the source map.
+        let mut functions = IndexMap::new();
+        let mut instruction_spans = Vec::new();
+        for mut unit in plan.sources.into_iter() {
+            unit.library = library;
+            unit.compiler.input_ast = input_ast.clone();
+            let program = unit.compiler.ast.as_repr();
+            functions.extend(program.functions.keys().map(|name| (name.name, name.span)));
+            let (unit_circuits, unit_instruction_spans) = compile_leo_file(unit, &build_directory)?;
+            circuits.extend(unit_circuits);
+            instruction_spans.extend(unit_instruction_spans);
+        }
+
+        // There is no Aleo program to build for a library.
+        if library {
+            return Ok((None, circuits, functions, instruction_spans));
+        }
+
         // Change the cwd to the build directory to compile aleo files.
         std::env::set_current_dir(&build_directory)
             .map_err(|err| PackageError::failed_to_set_cwd(build_directory.display(), err))?;
 
         // Call the `aleo build` command from the Aleo SDK.
         let command = AleoBuild::try_parse_from(&[ALEO_CLI_COMMAND]).map_err(CliError::failed_to_execute_aleo_build)?;
-        let result = command.parse().map_err(CliError::failed_to_execute_aleo_build)?;
+        let result = command
+            .parse()
+            .map_err(|error| SnarkVMError::build_failed(format!("{:#}", error)))?;
 
         // Log the result of the build
         tracing::info!("{}", result);
 
-        Ok((input_ast, circuits))
+        Ok((input_ast, circuits, functions, instruction_spans))
     }
 }
 
-fn compile_leo_file(
-    file_path: PathBuf,
-    _package_path: &Path,
-    package_name: &String,
-    outputs: &Path,
+/// Compiles a parsed Leo file into Aleo instructions and writes them to the `build` directory.
+/// Returns the circuits of the file and the spans of its instructions. Library files are only type checked.
+pub(crate) fn compile_leo_file(
+    mut unit: Unit,
     build: &Path,
-    handler: &Handler,
-    options: BuildOptions,
-) -> Result<IndexMap<Symbol, Circuit>> {
-    // Construct the Leo file name with extension `foo.leo`.
-    let file_name = file_path
-        .file_name()
-        .and_then(|name| name.to_str())
-        .ok_or_else(PackageError::failed_to_get_file_name)?;
-
-    // Construct program name from file_path name `foo`.
-    let program_name = file_name
-        .strip_suffix(".leo")
-        .ok_or_else(PackageError::failed_to_get_file_name)?;
-
-    // Construct program id header for aleo file.
-    // Do not create a program with main.aleo as the ID.
-    let program_id_name = if file_name.eq(MAIN_FILENAME) {
-        package_name
-    } else {
-        program_name
-    };
-
-    // Create a new instance of the Leo compiler.
-    let mut program = Compiler::new(
-        program_id_name.to_string(),
-        String::from("aleo"), // todo: fetch this from Network::Testnet3
-        handler,
-        file_path.clone(),
-        outputs.to_path_buf(),
-        Some(options.into()),
-    );
+) -> Result<(IndexMap<Symbol, Circuit>, Vec<InstructionSpan>)> {
+    if unit.library {
+        let symbol_table = unit.compiler.compiler_stages()?;
+        tracing::info!("✅ Checked library '{}'", unit.file_name);
+        return Ok((symbol_table.circuits, Vec::new()));
+    }
 
     // TODO: Temporarily removing checksum files. Need to redesign this scheme.
     // // Check if we need to compile the Leo program.
@@ -254,11 +250,15 @@ fn compile_leo_file(
 
     // if checksum_differs {
     // Compile the Leo program into Aleo instructions.
-    let (symbol_table, instructions) = program.compile_and_generate_instructions()?;
+    let (symbol_table, instructions, instruction_spans) = unit.compiler.generate_instructions()?;
+
+    // Parse the instructions with snarkVM before writing them, to report invalid ones against the Leo program.
+    unit.compiler
+        .check_instructions::<Network>(&instructions, &instruction_spans)?;
 
     // Create the path to the Aleo file.
     let mut aleo_file_path = build.to_path_buf();
-    aleo_file_path.push(format!("{}.aleo", program_name));
+    aleo_file_path.push(format!("{}.aleo", unit.name));
 
     // Write the instructions.
     std::fs::File::create(&aleo_file_path)
@@ -266,16 +266,43 @@ fn compile_leo_file(
         .write_all(instructions.as_bytes())
         .map_err(CliError::failed_to_load_instructions)?;
 
+    // Write the source map of the instructions next to them.
+    write_source_map(&aleo_file_path, &instruction_spans)?;
+
+    // Write the interface of the program next to them, for the applications calling it.
+    write_abi(build, &unit.name, &unit.compiler.abi(&symbol_table))?;
+
     // Prepare the path string.
     let path_string = format!("(in \"{}\")", aleo_file_path.display());
 
     // Log the build as successful.
     tracing::info!(
         "✅ Compiled '{}' into Aleo instructions {}",
-        file_name,
+        unit.file_name,
         path_string.dimmed()
     );
     // }
 
-    Ok(symbol_table.circuits)
+    Ok((symbol_table.circuits, instruction_spans))
+}
+
+/// Writes the source map of the Aleo file at `aleo_file_path` to `<aleo_file_path>.map`, as JSON.
+fn write_source_map(aleo_file_path: &Path, instruction_spans: &[InstructionSpan]) -> Result<()> {
+    let mut map_file_path = aleo_file_path.as_os_str().to_owned();
+    map_file_path.push(".map");
+    let map_file_path = Path::new(&map_file_path);
+
+    let json = serde_json::to_string_pretty(&source_map(instruction_spans))
+        .map_err(|error| CliError::failed_to_write_source_map(map_file_path.display(), error))?;
+    std::fs::write(map_file_path, json)
+        .map_err(|error| CliError::failed_to_write_source_map(map_file_path.display(), error).into())
+}
+
+/// Writes the interface of the program `name` to `<build>/<name>.abi.json`.
+fn write_abi(build: &Path, name: &str, abi: &Abi) -> Result<()> {
+    let abi_file_path = build.join(format!("{}.abi.json", name));
+    let json = serde_json::to_string_pretty(abi)
+        .map_err(|error| CliError::failed_to_write_abi(abi_file_path.display(), error))?;
+    std::fs::write(&abi_file_path, json)
+        .map_err(|error| CliError::failed_to_write_abi(abi_file_path.display(), error).into())
 }
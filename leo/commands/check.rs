@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::plan::{package_files, parse_leo_file, parse_library_file, BuildPlan, Unit};
+use crate::{commands::Command, context::Context};
+
+use leo_errors::{emitter::Handler, CompilerError, LeoError, Result};
+use leo_package::{inputs::InputFile, outputs::OUTPUTS_DIRECTORY_NAME};
+use leo_span::symbol::with_session_globals;
+use leo_span::Symbol;
+
+use clap::StructOpt;
+use indexmap::IndexSet;
+use std::path::PathBuf;
+use tracing::span::Span;
+
+/// Check the package for errors without building it
+#[derive(StructOpt, Debug)]
+pub struct Check {}
+
+impl Command for Check {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Checking")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Get the package path.
+        let package_path = context.dir()?;
+
+        // Get the program name, without mirroring the manifest into `build/`.
+        let package_name = context.read_manifest()?.program_id().name().to_string();
+
+        // Nothing is written here, the compiler only needs a path for its optional snapshots.
+        let outputs_directory = package_path.join(OUTPUTS_DIRECTORY_NAME);
+
+        // Give every file its own handler, so that errors in one file do not stop the others from being checked.
+        let files = package_files(&package_path)?;
+        let handlers = (0..=files.len())
+            .map(|_| context.handler())
+            .collect::<Result<Vec<Handler>>>()?;
+        let (input_handler, file_handlers) = handlers.split_last().unwrap();
+        let mut file_handlers = file_handlers.iter();
+
+        // The exit code of the last error reported.
+        let mut last_error = None;
+
+        // Parse every file, reporting the syntax errors of all of them.
+        // Returns the parsed files, and the names of the files that failed to parse.
+        let mut parse_files = |files: Vec<(Option<String>, PathBuf)>, last_error: &mut Option<i32>| {
+            let (mut units, mut unparsed) = (Vec::new(), IndexSet::new());
+            for (library, file_path) in files {
+                let handler = file_handlers.next().unwrap();
+                let name = library.clone().or_else(|| {
+                    let stem = file_path.file_stem()?;
+                    Some(stem.to_string_lossy().to_string())
+                });
+                let unit = match library {
+                    Some(name) => parse_library_file(name, file_path, handler, &outputs_directory, Default::default()),
+                    None => parse_leo_file(
+                        file_path,
+                        &package_name,
+                        handler,
+                        &outputs_directory,
+                        Default::default(),
+                    ),
+                };
+                match unit {
+                    Ok(unit) => units.push(unit),
+                    Err(err) => {
+                        *last_error = Some(report(handler, err));
+                        unparsed.extend(name.map(|name| Symbol::intern(&name)));
+                    }
+                }
+            }
+            (units, unparsed)
+        };
+        let (mut imports, mut unparsed) = parse_files(
+            (files.imports.into_iter().map(|file_path| (None, file_path)))
+                .chain(
+                    files
+                        .libraries
+                        .into_iter()
+                        .map(|(name, file_path)| (Some(name), file_path)),
+                )
+                .collect(),
+            &mut last_error,
+        );
+        let (mut sources, _) = parse_files(
+            files.sources.into_iter().map(|file_path| (None, file_path)).collect(),
+            &mut last_error,
+        );
+
+        // Imports cannot be resolved against files that failed to parse,
+        // so the files that import them, directly or not, are left out of the checks below.
+        loop {
+            let (left_out, parsed): (Vec<Unit>, Vec<Unit>) =
+                imports.into_iter().partition(|unit| imports_any(unit, &unparsed));
+            imports = parsed;
+            if left_out.is_empty() {
+                break;
+            }
+            unparsed.extend(left_out.iter().map(|unit| Symbol::intern(&unit.name)));
+        }
+        sources.retain(|unit| !imports_any(unit, &unparsed));
+
+        // Check the input file.
+        let input_file_path = InputFile::new(&package_name).setup_file_path(&package_path);
+        let mut input_ast = None;
+        if input_file_path.exists() {
+            let input = with_session_globals(|s| s.source_map.load_file(&input_file_path))
+                .map_err(|e| CompilerError::file_read_error(&input_file_path, e).into())
+                .and_then(|input_sf| leo_parser::parse_input(input_handler, &input_sf.src, input_sf.start_pos));
+            match input {
+                Ok(input) => input_ast = Some(input),
+                Err(err) => last_error = Some(report(input_handler, err)),
+            }
+        }
+
+        // Run the compiler passes on every file, imported programs first, with the input file of the package.
+        let mut plan = BuildPlan::order(&package_path, imports, sources)?;
+        for unit in plan.sources.iter_mut() {
+            unit.compiler.input_ast = input_ast.clone();
+        }
+        for mut unit in plan.imports.into_values().chain(plan.sources) {
+            if let Err(err) = unit.compiler.compiler_stages() {
+                last_error = Some(report(unit.handler, err));
+            }
+        }
+
+        match last_error {
+            Some(code) => Err(LeoError::LastErrorCode(code)),
+            None => {
+                tracing::info!("✅ No errors found");
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Returns `true` if the file of `unit` imports any of the files `names`.
+fn imports_any(unit: &Unit, names: &IndexSet<Symbol>) -> bool {
+    let program = unit.compiler.ast.as_repr();
+    program.imports.keys().any(|import| names.contains(&import.name))
+}
+
+/// Emits `err` unless it has been emitted already, and returns its exit code.
+pub(crate) fn report(handler: &Handler, err: LeoError) -> i32 {
+    match err {
+        LeoError::LastErrorCode(code) => code,
+        err => {
+            let code = err.exit_code();
+            handler.emit_err(err);
+            code
+        }
+    }
+}
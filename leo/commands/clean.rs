@@ -17,6 +17,7 @@
 use crate::{commands::Command, context::Context};
 use leo_errors::Result;
 use leo_package::build::BuildDirectory;
+use leo_package::doc::DocDirectory;
 use leo_package::outputs::OutputsDirectory;
 
 use clap::StructOpt;
@@ -50,6 +51,10 @@ impl Command for Clean {
         let build_path = BuildDirectory::remove(&path)?;
         tracing::info!("✅ Cleaned the build directory {}", build_path.dimmed());
 
+        // Removes the doc/ directory.
+        let doc_path = DocDirectory::remove(&path)?;
+        tracing::info!("✅ Cleaned the doc directory {}", doc_path.dimmed());
+
         // Remove the checksum from the output directory
         // ChecksumFile::new(&package_name).remove(&path)?;
 
@@ -0,0 +1,521 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::plan::{package_files, parse_leo_file, parse_library_file, Unit};
+use crate::{commands::Command, context::Context};
+
+use leo_ast::{Circuit, CircuitMember, Function, Program, Type};
+use leo_errors::{PackageError, Result};
+use leo_package::{doc::DocDirectory, imports::IMPORTS_DIRECTORY_NAME, outputs::OUTPUTS_DIRECTORY_NAME};
+
+use clap::{ArgEnum, StructOpt};
+use colored::Colorize;
+use std::fmt::Write;
+use std::fs;
+use tracing::span::Span;
+
+/// Generate the documentation of the package from its doc comments
+#[derive(StructOpt, Debug)]
+pub struct Doc {
+    #[structopt(long, arg_enum, default_value = "html", help = "The format of the documentation")]
+    pub(crate) format: DocFormat,
+}
+
+/// The format of the documentation written by `leo doc`.
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DocFormat {
+    /// A static HTML site.
+    Html,
+    /// Markdown files, e.g., for a repository wiki.
+    Markdown,
+}
+
+impl DocFormat {
+    /// Returns the extension of the documentation files.
+    fn extension(self) -> &'static str {
+        match self {
+            DocFormat::Html => "html",
+            DocFormat::Markdown => "md",
+        }
+    }
+}
+
+impl Command for Doc {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Documenting")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Get the package path and the program name.
+        let package_path = context.dir()?;
+        let package_name = context.read_manifest()?.program_id().name().to_string();
+
+        // Nothing is written here, the compiler only needs a path for its optional snapshots.
+        let outputs_directory = package_path.join(OUTPUTS_DIRECTORY_NAME);
+
+        // Every file is documented on its own, so the imports are not resolved.
+        let handler = context.handler()?;
+        let files = package_files(&package_path)?;
+        let mut pages = Vec::new();
+        for file_path in files.sources {
+            let unit = parse_leo_file(
+                file_path,
+                &package_name,
+                &handler,
+                &outputs_directory,
+                Default::default(),
+            )?;
+            pages.push(Page::new("", unit));
+        }
+        for file_path in files.imports {
+            let unit = parse_leo_file(
+                file_path,
+                &package_name,
+                &handler,
+                &outputs_directory,
+                Default::default(),
+            )?;
+            pages.push(Page::new(IMPORTS_DIRECTORY_NAME, unit));
+        }
+        for (name, file_path) in files.libraries {
+            let unit = parse_library_file(name, file_path, &handler, &outputs_directory, Default::default())?;
+            pages.push(Page::new(IMPORTS_DIRECTORY_NAME, unit));
+        }
+
+        // The parser recovers from syntax errors, so fail before writing documentation of a partial program.
+        handler.last_err()?;
+
+        // Write a page for every file, and an index linking them.
+        let site = Site {
+            format: self.format,
+            pages,
+        };
+        let doc_directory = DocDirectory::create(&package_path)?;
+        let mut files = vec![(format!("index.{}", self.format.extension()), site.index(&package_name))];
+        files.extend(site.pages.iter().map(|page| (site.path(page), site.page(page))));
+        for (path, content) in files {
+            let path = doc_directory.join(path);
+            if let Some(directory) = path.parent() {
+                fs::create_dir_all(directory).map_err(|e| PackageError::failed_to_write_doc_file(path.display(), e))?;
+            }
+            fs::write(&path, content).map_err(|e| PackageError::failed_to_write_doc_file(path.display(), e))?;
+        }
+
+        let index = doc_directory.join(format!("index.{}", self.format.extension()));
+        tracing::info!(
+            "✅ Documented the package {}",
+            format!("(in \"{}\")", index.display()).dimmed()
+        );
+
+        Ok(())
+    }
+}
+
+/// The documentation of one `.leo` file.
+struct Page {
+    /// The directory of the page within `doc/`, e.g. `imports/`.
+    directory: &'static str,
+    /// The name of the file without its extension, e.g. `foo`.
+    name: String,
+    /// The name of the file, e.g. `foo.leo`.
+    file_name: String,
+    /// The parsed program of the file.
+    program: Program,
+}
+
+impl Page {
+    fn new(directory: &'static str, unit: Unit) -> Self {
+        Self {
+            directory,
+            name: unit.name,
+            file_name: unit.file_name,
+            program: unit.compiler.ast.into_repr(),
+        }
+    }
+
+    /// Returns the circuit or record named `name` declared in this file, if any.
+    fn circuit(&self, name: &Type) -> Option<&Circuit> {
+        match name {
+            Type::Identifier(identifier) => self.program.circuits.values().find(|c| c.name() == identifier.name),
+            _ => None,
+        }
+    }
+}
+
+/// The documentation of a package, in one format.
+struct Site {
+    format: DocFormat,
+    pages: Vec<Page>,
+}
+
+impl Site {
+    /// Returns the path of `page` within `doc/`.
+    fn path(&self, page: &Page) -> String {
+        format!("{}{}.{}", page.directory, page.name, self.format.extension())
+    }
+
+    /// Returns the link from `from` to the `anchor` in `to`.
+    fn href(&self, from: &Page, to: &Page, anchor: &str) -> String {
+        if std::ptr::eq(from, to) {
+            format!("#{}", anchor)
+        } else {
+            format!(
+                "{}{}#{}",
+                "../".repeat(from.directory.matches('/').count()),
+                self.path(to),
+                anchor
+            )
+        }
+    }
+
+    /// Renders the index of the package, linking the page of every file.
+    fn index(&self, package_name: &str) -> String {
+        let mut writer = Writer::new(self.format);
+        writer.heading(1, None, package_name);
+        for (title, directory) in [("Programs", ""), ("Imports", IMPORTS_DIRECTORY_NAME)] {
+            let items: Vec<String> = self
+                .pages
+                .iter()
+                .filter(|page| page.directory == directory)
+                .map(|page| {
+                    let items = page.program.circuits.len() + page.program.functions.len();
+                    format!("{} ({} items)", writer.link(&page.file_name, &self.path(page)), items)
+                })
+                .collect();
+            if !items.is_empty() {
+                writer.heading(2, None, title);
+                writer.list(&items);
+            }
+        }
+        writer.finish(package_name)
+    }
+
+    /// Renders the documentation of the records, circuits and functions of `page`.
+    fn page(&self, page: &Page) -> String {
+        let mut writer = Writer::new(self.format);
+        writer.heading(1, None, &page.file_name);
+
+        let (records, circuits): (Vec<&Circuit>, Vec<&Circuit>) =
+            page.program.circuits.values().partition(|circuit| circuit.is_record);
+        for (title, circuits) in [("Records", records), ("Circuits", circuits)] {
+            if !circuits.is_empty() {
+                writer.heading(2, None, title);
+            }
+            for circuit in circuits {
+                self.circuit(&mut writer, page, circuit);
+            }
+        }
+
+        if !page.program.functions.is_empty() {
+            writer.heading(2, None, "Functions");
+        }
+        for function in page.program.functions.values() {
+            self.function(&mut writer, page, function);
+        }
+
+        writer.finish(&page.file_name)
+    }
+
+    /// Renders a circuit or record with the layout of its members.
+    fn circuit(&self, writer: &mut Writer, page: &Page, circuit: &Circuit) {
+        let kind = if circuit.is_record { "record" } else { "circuit" };
+        writer.heading(
+            3,
+            Some(&anchor(kind, &circuit.name().to_string())),
+            &format!("{} {}", kind, circuit.name()),
+        );
+        writer.docs(&circuit.docs);
+
+        let mut declaration = format!("{} {} {{\n", kind, circuit.name());
+        for CircuitMember::CircuitVariable(name, type_, _) in circuit.members.iter() {
+            writeln!(declaration, "    {}: {},", name, source_type(type_)).unwrap();
+        }
+        declaration.push('}');
+        writer.code_block(&declaration);
+
+        let rows = circuit
+            .members
+            .iter()
+            .map(|CircuitMember::CircuitVariable(name, type_, docs)| {
+                vec![
+                    writer.code(&name.to_string()),
+                    self.type_(writer, page, type_),
+                    writer.text(&docs.join(" ")),
+                ]
+            })
+            .collect::<Vec<_>>();
+        writer.table(&["Field", "Type", "Description"], &rows);
+    }
+
+    /// Renders the signature of a function, with the modes and types of its parameters.
+    fn function(&self, writer: &mut Writer, page: &Page, function: &Function) {
+        writer.heading(
+            3,
+            Some(&anchor("function", &function.name().to_string())),
+            &format!("function {}", function.name()),
+        );
+        writer.docs(&function.docs);
+
+        let mut signature = String::new();
+        for annotation in function.annotations.iter() {
+            writeln!(signature, "{}", annotation).unwrap();
+        }
+        let inputs = function
+            .input
+            .iter()
+            .map(|input| {
+                let input = input.get_variable();
+                format!("{} {}: {}", input.mode(), input.identifier, source_type(&input.type_))
+            })
+            .collect::<Vec<_>>();
+        let output = source_type(&function.output);
+        write!(
+            signature,
+            "function {}({}) -> {}",
+            function.name(),
+            inputs.join(", "),
+            output
+        )
+        .unwrap();
+        writer.code_block(&signature);
+
+        if !function.input.is_empty() {
+            let rows = function
+                .input
+                .iter()
+                .map(|input| {
+                    let input = input.get_variable();
+                    vec![
+                        writer.code(&input.identifier.to_string()),
+                        writer.text(&input.mode().to_string()),
+                        self.type_(writer, page, &input.type_),
+                    ]
+                })
+                .collect::<Vec<_>>();
+            writer.table(&["Parameter", "Mode", "Type"], &rows);
+        }
+        let output = format!("Returns {}.", self.type_(writer, page, &function.output));
+        writer.paragraph(&output);
+    }
+
+    /// Renders `type_`, linking the circuits and records it names to their documentation.
+    /// Circuits declared in the same file are preferred over those of other files.
+    fn type_(&self, writer: &Writer, page: &Page, type_: &Type) -> String {
+        match type_ {
+            Type::Tuple(tuple) => {
+                let types = tuple
+                    .0
+                    .iter()
+                    .map(|type_| self.type_(writer, page, type_))
+                    .collect::<Vec<_>>();
+                format!("({})", types.join(", "))
+            }
+            _ => {
+                let pages = std::iter::once(page).chain(self.pages.iter());
+                match pages.filter_map(|to| Some((to, to.circuit(type_)?))).next() {
+                    Some((to, circuit)) => {
+                        let kind = if circuit.is_record { "record" } else { "circuit" };
+                        let href = self.href(page, to, &anchor(kind, &circuit.name().to_string()));
+                        writer.link(&source_type(type_), &href)
+                    }
+                    None => writer.code(&source_type(type_)),
+                }
+            }
+        }
+    }
+}
+
+/// Returns `type_` as it is written in Leo source, e.g. `Token` rather than `circuit Token`.
+fn source_type(type_: &Type) -> String {
+    match type_ {
+        Type::Identifier(identifier) => identifier.to_string(),
+        Type::Tuple(tuple) => {
+            let types = tuple.0.iter().map(source_type).collect::<Vec<_>>();
+            format!("({})", types.join(", "))
+        }
+        _ => type_.to_string(),
+    }
+}
+
+/// Returns the anchor of the item `name` of the given `kind`, e.g. `circuit-foo`.
+/// It matches the anchors Markdown renderers derive from the item's heading.
+fn anchor(kind: &str, name: &str) -> String {
+    format!("{}-{}", kind, name).to_lowercase()
+}
+
+/// Writes a document in one format.
+struct Writer {
+    format: DocFormat,
+    out: String,
+}
+
+impl Writer {
+    fn new(format: DocFormat) -> Self {
+        Self {
+            format,
+            out: String::new(),
+        }
+    }
+
+    /// Returns the finished document with the given title.
+    fn finish(self, title: &str) -> String {
+        match self.format {
+            DocFormat::Html => format!(
+                "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+                escape_html(title),
+                STYLE,
+                self.out
+            ),
+            DocFormat::Markdown => self.out,
+        }
+    }
+
+    fn heading(&mut self, level: usize, anchor: Option<&str>, text: &str) {
+        match self.format {
+            DocFormat::Html => {
+                let id = anchor.map(|anchor| format!(" id=\"{}\"", anchor)).unwrap_or_default();
+                writeln!(self.out, "<h{}{}>{}</h{}>", level, id, escape_html(text), level).unwrap();
+            }
+            // Markdown renderers derive the anchor from the heading.
+            DocFormat::Markdown => writeln!(self.out, "{} {}\n", "#".repeat(level), text).unwrap(),
+        }
+    }
+
+    /// Writes doc comments, whose blank lines separate paragraphs.
+    fn docs(&mut self, docs: &[String]) {
+        if docs.is_empty() {
+            return;
+        }
+        match self.format {
+            DocFormat::Html => {
+                for paragraph in docs
+                    .split(|line| line.trim().is_empty())
+                    .filter(|lines| !lines.is_empty())
+                {
+                    writeln!(self.out, "<p>{}</p>", escape_html(&paragraph.join("\n"))).unwrap();
+                }
+            }
+            DocFormat::Markdown => writeln!(self.out, "{}\n", docs.join("\n")).unwrap(),
+        }
+    }
+
+    /// Writes a paragraph of already formatted text.
+    fn paragraph(&mut self, text: &str) {
+        match self.format {
+            DocFormat::Html => writeln!(self.out, "<p>{}</p>", text).unwrap(),
+            DocFormat::Markdown => writeln!(self.out, "{}\n", text).unwrap(),
+        }
+    }
+
+    fn code_block(&mut self, code: &str) {
+        match self.format {
+            DocFormat::Html => writeln!(self.out, "<pre><code>{}</code></pre>", escape_html(code)).unwrap(),
+            DocFormat::Markdown => writeln!(self.out, "```leo\n{}\n```\n", code).unwrap(),
+        }
+    }
+
+    /// Writes a table of already formatted cells.
+    fn table(&mut self, headers: &[&str], rows: &[Vec<String>]) {
+        match self.format {
+            DocFormat::Html => {
+                self.out.push_str("<table>\n<tr>");
+                for header in headers {
+                    write!(self.out, "<th>{}</th>", header).unwrap();
+                }
+                self.out.push_str("</tr>\n");
+                for row in rows {
+                    self.out.push_str("<tr>");
+                    for cell in row {
+                        write!(self.out, "<td>{}</td>", cell).unwrap();
+                    }
+                    self.out.push_str("</tr>\n");
+                }
+                self.out.push_str("</table>\n");
+            }
+            DocFormat::Markdown => {
+                writeln!(self.out, "| {} |", headers.join(" | ")).unwrap();
+                writeln!(self.out, "|{}", " --- |".repeat(headers.len())).unwrap();
+                for row in rows {
+                    writeln!(self.out, "| {} |", row.join(" | ")).unwrap();
+                }
+                self.out.push('\n');
+            }
+        }
+    }
+
+    /// Writes a list of already formatted items.
+    fn list(&mut self, items: &[String]) {
+        match self.format {
+            DocFormat::Html => {
+                self.out.push_str("<ul>\n");
+                for item in items {
+                    writeln!(self.out, "<li>{}</li>", item).unwrap();
+                }
+                self.out.push_str("</ul>\n");
+            }
+            DocFormat::Markdown => {
+                for item in items {
+                    writeln!(self.out, "- {}", item).unwrap();
+                }
+                self.out.push('\n');
+            }
+        }
+    }
+
+    /// Formats a link to `href` showing `text` as code.
+    fn link(&self, text: &str, href: &str) -> String {
+        match self.format {
+            DocFormat::Html => format!("<a href=\"{}\"><code>{}</code></a>", href, escape_html(text)),
+            DocFormat::Markdown => format!("[`{}`]({})", text, href),
+        }
+    }
+
+    /// Formats `text` as code.
+    fn code(&self, text: &str) -> String {
+        match self.format {
+            DocFormat::Html => format!("<code>{}</code>", escape_html(text)),
+            DocFormat::Markdown => format!("`{}`", text),
+        }
+    }
+
+    /// Formats `text` to show as it is, e.g., within a table cell.
+    fn text(&self, text: &str) -> String {
+        match self.format {
+            DocFormat::Html => escape_html(text),
+            DocFormat::Markdown => text.replace('|', "\\|"),
+        }
+    }
+}
+
+/// The style sheet of the HTML documentation.
+const STYLE: &str = "body { font-family: sans-serif; max-width: 60em; margin: auto; padding: 1em; } \
+    pre, code { font-family: monospace; background: #f4f4f4; } pre { padding: 0.5em; } \
+    table { border-collapse: collapse; } th, td { border: 1px solid #ccc; padding: 0.25em 0.5em; text-align: left; }";
+
+/// Escapes the characters of `text` that have a meaning in HTML.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
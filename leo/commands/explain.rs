@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::{explanations::Explanation, Command};
+use crate::context::Context;
+use leo_errors::{CliError, MessageName, Result};
+
+use clap::StructOpt;
+use tracing::span::Span;
+
+/// Explain an error or warning code
+#[derive(StructOpt, Debug)]
+pub struct Explain {
+    #[structopt(
+        name = "CODE",
+        help = "The code of the error or warning, e.g. EPAR0370003, or the name of a guide, e.g. context_annotation"
+    )]
+    code: String,
+}
+
+impl Command for Explain {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Explain")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, _: Context, _: Self::Input) -> Result<Self::Output> {
+        let guide = match (
+            MessageName::from_code(&self.code),
+            Explanation::find_by_name(&self.code),
+        ) {
+            (Some(message), _) => Explanation::find(&self.code).map_or_else(
+                || Explanation::stub(&self.code, message),
+                |explanation| explanation.guide.to_string(),
+            ),
+            (None, Some(explanation)) => explanation.guide.to_string(),
+            (None, None) => return Err(CliError::unknown_message_code(&self.code).into()),
+        };
+
+        // The guide is the output of the command, so it is printed even when logging is quiet.
+        println!("{}", guide.trim_end());
+
+        Ok(())
+    }
+}
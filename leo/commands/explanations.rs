@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The explanations of `leo explain`, embedded from the guides in `docs/error-guides/`.
+//! Messages without a guide are explained by a stub pointing to their message and to the issue tracker.
+
+use leo_errors::MessageName;
+
+/// The guide explaining one error or warning.
+pub struct Explanation {
+    /// The type defining the message, e.g. `ParserError`.
+    pub kind: &'static str,
+    /// The function creating the message, e.g. `unexpected_eof`.
+    pub name: &'static str,
+    /// The guide, in markdown.
+    pub guide: &'static str,
+}
+
+macro_rules! explanations {
+    ($directory:literal, $kind:literal, [$($name:literal),* $(,)?]) => {
+        [$(
+            Explanation {
+                kind: $kind,
+                name: $name,
+                guide: include_str!(concat!("../../docs/error-guides/", $directory, "/", $name, ".md")),
+            },
+        )*]
+    };
+}
+
+/// The explanations available to `leo explain`, one for each guide in `docs/error-guides/`.
+/// A guide is named after the function creating its message, in the directory of the message's crate.
+/// The guides of messages the compiler no longer reports are found by their name only, e.g. `context_annotation`.
+pub const EXPLANATIONS: &[&[Explanation]] = &[
+    &explanations!(
+        "parser",
+        "ParserError",
+        [
+            "unexpected_token",
+            "invalid_address_lit",
+            "invalid_import_list",
+            "unexpected_eof",
+            "unexpected_whitespace",
+            "unexpected",
+            "mixed_commas_and_semicolons",
+            "unexpected_ident",
+            "unexpected_statement",
+            "unexpected_str",
+            "spread_in_array_init",
+            "test_function",
+            "array_tuple_dimensions_empty",
+            "context_annotation",
+            "let_mut_statement",
+            "member_const_after_fun",
+            "member_const_after_var",
+            "member_var_after_fun",
+            "mut_function_input",
+            "mut_self_parameter",
+            "unable_to_parse_array_dimensions",
+        ]
+    ),
+    // Assignments were checked by the parser, before the type checker took over.
+    &explanations!("parser", "TypeCheckerError", ["invalid_assignment_target"]),
+    &explanations!("type_checker", "TypeCheckerError", ["unknown_sym"]),
+    &explanations!("lint", "LintWarning", ["unused_variable", "unreachable_statement"]),
+];
+
+impl Explanation {
+    /// Returns the explanation of the error or warning with the code `code`, if there is one.
+    pub fn find(code: &str) -> Option<&'static Explanation> {
+        let message = MessageName::from_code(code)?;
+        EXPLANATIONS
+            .iter()
+            .flat_map(|explanations| explanations.iter())
+            .find(|explanation| explanation.kind == message.kind && explanation.name == message.name)
+    }
+
+    /// Returns the explanation of the message created by the function `name`, e.g. `context_annotation`.
+    pub fn find_by_name(name: &str) -> Option<&'static Explanation> {
+        EXPLANATIONS
+            .iter()
+            .flat_map(|explanations| explanations.iter())
+            .find(|explanation| explanation.name == name)
+    }
+
+    /// Returns whether the error or warning with the code `code` has an explanation.
+    pub fn exists(code: &str) -> bool {
+        Self::find(code).is_some()
+    }
+
+    /// Returns the stub guide of the error or warning `message` with the code `code`, which has no guide of its own.
+    pub fn stub(code: &str, message: MessageName) -> String {
+        format!(
+            "# `{code}`: `{message}`\n\n\
+             There is no guide for this message yet. The message and its help, as reported by the compiler, \
+             describe what went wrong and how it may be fixed.\n\n\
+             If they do not, please open an issue at https://github.com/AleoHQ/leo/issues/new, \
+             or add a guide named `{name}.md` to `docs/error-guides/`.",
+            code = code.trim().to_ascii_uppercase(),
+            message = message,
+            name = message.name,
+        )
+    }
+}
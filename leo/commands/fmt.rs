@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::check::report;
+use crate::commands::plan::package_files;
+use crate::{commands::Command, context::Context};
+
+use leo_errors::{emitter::Handler, CliError, CompilerError, LeoError, Result};
+use leo_span::{source_map::FileName, symbol::with_session_globals};
+
+use clap::StructOpt;
+use colored::Colorize;
+use std::{fs, path::Path};
+use tracing::span::Span;
+
+/// Format the Leo files of the package
+#[derive(StructOpt, Debug)]
+pub struct Fmt {
+    #[structopt(long, help = "Checks that the files are formatted, without rewriting them.")]
+    pub(crate) check: bool,
+}
+
+impl Command for Fmt {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Formatting")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let package_path = context.dir()?;
+        let handler = context.handler()?;
+
+        // Format the files in `imports/` and `src/`, imported libraries are packages of their own.
+        let files = package_files(&package_path)?;
+
+        // The exit code of the last error reported, files with errors are left as they are.
+        let mut last_error = None;
+        let mut unformatted = 0;
+        for file_path in files.imports.into_iter().chain(files.sources) {
+            let (source, formatted) = match format_file(&file_path, &handler) {
+                Ok(result) => result,
+                Err(err) => {
+                    last_error = Some(report(&handler, err));
+                    continue;
+                }
+            };
+            if source == formatted {
+                continue;
+            }
+
+            if self.check {
+                tracing::info!("{} is not formatted", file_path.display().to_string().dimmed());
+                unformatted += 1;
+            } else {
+                fs::write(&file_path, formatted)
+                    .map_err(|err| CliError::failed_to_write_formatted_file(file_path.display(), err))?;
+                tracing::info!("✅ Formatted {}", file_path.display().to_string().dimmed());
+            }
+        }
+
+        if let Some(code) = last_error {
+            return Err(LeoError::LastErrorCode(code));
+        }
+        if unformatted > 0 {
+            return Err(CliError::files_not_formatted(unformatted).into());
+        }
+        Ok(())
+    }
+}
+
+/// Returns the source code text of the file at `file_path`, and its formatted version.
+fn format_file(file_path: &Path, handler: &Handler) -> Result<(String, String)> {
+    let source = fs::read_to_string(file_path).map_err(|e| CompilerError::file_read_error(file_path, e))?;
+    let sf = with_session_globals(|s| {
+        s.source_map
+            .new_source(&source, FileName::Real(file_path.to_path_buf()))
+    });
+    let formatted = leo_parser::format(handler, &sf.src, sf.start_pos)?;
+    Ok((source, formatted))
+}
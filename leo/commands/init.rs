@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::Network;
+use crate::{commands::Command, context::Context};
+use leo_errors::{PackageError, Result};
+use leo_package::package::Package;
+use snarkvm::{file::Manifest, prelude::ProgramID};
+
+use clap::StructOpt;
+use colored::Colorize;
+use std::str::FromStr;
+use tracing::span::Span;
+
+/// Init Leo project command within current directory
+#[derive(StructOpt, Debug)]
+pub struct Init {}
+
+impl Command for Init {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Initializing")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Derive the package directory path.
+        let path = context.dir()?;
+
+        // The package is named after its directory.
+        let package_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if !Package::is_package_name_valid(&package_name) {
+            return Err(PackageError::invalid_package_name(package_name).into());
+        }
+
+        // The package name is also the name of its Aleo program, which is stricter.
+        let program_id = ProgramID::<Network>::from_str(&format!("{}.aleo", package_name))
+            .map_err(|err| PackageError::invalid_program_name(&package_name, err))?;
+
+        // Refuse to overwrite the files of an existing package.
+        if !Package::can_initialize(&package_name, &path) {
+            return Err(PackageError::package_already_exists(path).into());
+        }
+
+        // Create the .gitignore, source, input and build files of the package.
+        Package::initialize(&package_name, &path)?;
+
+        // Create the manifest file `program.json` last, so that the directory only becomes a package once it is complete.
+        Manifest::create(&path, &program_id).map_err(PackageError::failed_to_create_manifest)?;
+
+        tracing::info!(
+            "✅ Initialized package '{}' {}",
+            package_name,
+            format!("(in \"{}\")", path.display()).dimmed()
+        );
+
+        Ok(())
+    }
+}
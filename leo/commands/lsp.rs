@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context, lsp};
+
+use leo_errors::Result;
+
+use clap::StructOpt;
+use tracing::span::Span;
+
+/// Run the Leo language server over the standard input and output
+#[derive(StructOpt, Debug)]
+pub struct Lsp {}
+
+impl Command for Lsp {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Language server")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, _: Context, _: Self::Input) -> Result<Self::Output> {
+        let stdin = std::io::stdin();
+        let stdout = std::io::stdout();
+        lsp::run(stdin.lock(), stdout.lock())
+    }
+}
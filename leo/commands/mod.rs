@@ -18,17 +18,42 @@
 pub mod build;
 pub use build::Build;
 
+pub mod check;
+pub use check::Check;
+
 pub mod clean;
 pub use clean::Clean;
 
+pub mod doc;
+pub use doc::Doc;
+
+pub mod explain;
+pub use explain::Explain;
+
+pub mod fmt;
+pub use fmt::Fmt;
+
+pub mod init;
+pub use init::Init;
+
+pub mod lsp;
+pub use lsp::Lsp;
+
 pub mod new;
 pub use new::New;
 
 pub mod run;
 pub use run::Run;
 
-// pub mod init;
-// pub use init::Init;
+pub mod test;
+pub use test::Test;
+
+// command helpers
+pub mod explanations;
+
+pub mod plan;
+
+pub mod templates;
 
 use crate::context::*;
 use leo_errors::Result;
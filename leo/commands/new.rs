@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::commands::templates::{Template, TEMPLATES};
 use crate::commands::Network;
 use crate::{
     commands::{Command, ALEO_CLI_COMMAND},
@@ -27,13 +28,20 @@ use snarkvm::file::AleoFile;
 use aleo::commands::New as AleoNew;
 
 use clap::StructOpt;
+use colored::Colorize;
 use tracing::span::Span;
 
 /// Create new Leo project
 #[derive(StructOpt, Debug)]
 pub struct New {
-    #[structopt(name = "NAME", help = "Set package name")]
-    name: String,
+    #[structopt(name = "NAME", help = "Set package name", required_unless_present = "list-templates")]
+    name: Option<String>,
+
+    #[structopt(long, help = "Create the package from a built-in template, see `--list-templates`")]
+    template: Option<String>,
+
+    #[structopt(long = "list-templates", help = "List the built-in package templates")]
+    list_templates: bool,
 }
 
 impl Command for New {
@@ -49,9 +57,28 @@ impl Command for New {
     }
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        if self.list_templates {
+            for template in TEMPLATES {
+                tracing::info!("{:<10} {}", template.name.bold(), template.description);
+            }
+            return Ok(());
+        }
+
+        // Clap ensures the name is set unless templates are listed.
+        let name = self.name.unwrap_or_default();
+
+        // Look up the template before creating anything.
+        let template = match &self.template {
+            Some(template_name) => Some(Template::find(template_name).ok_or_else(|| {
+                let templates = TEMPLATES.iter().map(|t| t.name).collect::<Vec<_>>().join(", ");
+                CliError::unknown_template(template_name, templates)
+            })?),
+            None => None,
+        };
+
         // Call the `aleo new` command from the Aleo SDK.
         let command =
-            AleoNew::try_parse_from(&[ALEO_CLI_COMMAND, &self.name]).map_err(CliError::failed_to_parse_aleo_new)?;
+            AleoNew::try_parse_from(&[ALEO_CLI_COMMAND, &name]).map_err(CliError::failed_to_parse_aleo_new)?;
         let result = command.parse().map_err(CliError::failed_to_execute_aleo_new)?;
 
         // todo: modify the readme file to recommend building with `leo build`.
@@ -61,10 +88,15 @@ impl Command for New {
 
         // Derive the program directory path.
         let mut package_path = context.dir()?;
-        package_path.push(&self.name);
+        package_path.push(&name);
 
         // Initialize the Leo package in the directory created by `aleo new`.
-        Package::initialize(&self.name, &package_path)?;
+        Package::initialize(&name, &package_path)?;
+
+        // Replace the default program with the template's.
+        if let Some(template) = template {
+            template.write_to(&name, &package_path)?;
+        }
 
         // Change the cwd to the Leo package directory. to compile aleo files.
         std::env::set_current_dir(&package_path)
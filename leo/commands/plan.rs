@@ -0,0 +1,362 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The build plan of a package.
+//!
+//! Every `.leo` file in `src/` and `imports/` is parsed exactly once. The import
+//! declarations of the parsed programs form a graph over the files in `imports/`,
+//! which is ordered so that each program comes after the programs it imports.
+//!
+//! The files are read from disk in parallel. They are parsed and compiled one after
+//! another, because the symbol interner and source map are thread-local session globals
+//! that the ASTs of all files share.
+
+use crate::commands::build::BuildOptions;
+
+use leo_ast::{Identifier, Program};
+use leo_compiler::Compiler;
+use leo_errors::{emitter::Handler, CompilerError, PackageError, Result};
+use leo_package::{
+    imports::{ImportsDirectory, IMPORTS_DIRECTORY_NAME},
+    package::PackageKind,
+    source::{SourceDirectory, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+use leo_span::source_map::FileName;
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A parsed Leo file of the package.
+pub struct Unit<'a> {
+    /// The name of the `.leo` file, e.g. `foo.leo`.
+    pub file_name: String,
+    /// The name of the file without its extension, e.g. `foo`.
+    pub name: String,
+    /// The compiler holding the parsed program, with its imports resolved.
+    pub compiler: Compiler<'a>,
+    /// The handler the compiler emits errors to.
+    pub handler: &'a Handler,
+    /// Whether the file is the main file of a library, which is included by its importers
+    /// rather than compiled into a program of its own.
+    pub library: bool,
+}
+
+/// The `.leo` files of a package.
+pub struct PackageFiles {
+    /// The files in `imports/`.
+    pub imports: Vec<PathBuf>,
+    /// The names and main files of the library packages in `imports/`.
+    pub libraries: Vec<(String, PathBuf)>,
+    /// The files in `src/`.
+    pub sources: Vec<PathBuf>,
+}
+
+impl PackageFiles {
+    /// Returns the number of files.
+    pub fn len(&self) -> usize {
+        self.imports.len() + self.libraries.len() + self.sources.len()
+    }
+
+    /// Returns the paths to all files.
+    pub fn paths(&self) -> Vec<PathBuf> {
+        let libraries = self.libraries.iter().map(|(_, file_path)| file_path);
+        self.imports
+            .iter()
+            .chain(libraries)
+            .chain(&self.sources)
+            .cloned()
+            .collect()
+    }
+
+    /// Returns `true` if the package has no files.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// The parsed files of a package in compilation order.
+pub struct BuildPlan<'a> {
+    /// The files in `imports/`, keyed by import name, each after the files it imports.
+    pub imports: IndexMap<Symbol, Unit<'a>>,
+    /// The files in `src/`.
+    pub sources: Vec<Unit<'a>>,
+}
+
+impl<'a> BuildPlan<'a> {
+    /// Parses all files of the package at `package_path` and orders them by their imports.
+    pub fn new(
+        package_path: &Path,
+        package_name: &str,
+        handler: &'a Handler,
+        outputs: &Path,
+        options: BuildOptions,
+    ) -> Result<Self> {
+        // Read every file in `imports/` and `src/` in parallel, then parse each once.
+        let files = package_files(package_path)?;
+        let mut contents = read_files(files.paths())?;
+        let mut parse = |file_path: PathBuf, program_name: &str| {
+            let source = contents.remove(&file_path).unwrap_or_default();
+            parse_leo_source(file_path, &source, program_name, handler, outputs, options.clone())
+        };
+
+        let mut imports = files
+            .imports
+            .into_iter()
+            .map(|file_path| parse(file_path, package_name))
+            .collect::<Result<Vec<_>>>()?;
+        for (name, file_path) in files.libraries {
+            let mut unit = parse(file_path, &name)?;
+            unit.name = name;
+            unit.library = true;
+            imports.push(unit);
+        }
+        let sources = files
+            .sources
+            .into_iter()
+            .map(|file_path| parse(file_path, package_name))
+            .collect::<Result<Vec<_>>>()?;
+
+        Self::order(package_path, imports, sources)
+    }
+
+    /// Orders the parsed files of the package at `package_path` by their imports and resolves them.
+    pub fn order(package_path: &Path, imports: Vec<Unit<'a>>, mut sources: Vec<Unit<'a>>) -> Result<Self> {
+        let mut planner = Planner {
+            imports_directory: package_path.join(IMPORTS_DIRECTORY_NAME),
+            unordered: imports
+                .into_iter()
+                .map(|unit| (Symbol::intern(&unit.name), unit))
+                .collect(),
+            ordered: IndexMap::new(),
+            programs: IndexMap::new(),
+            libraries: IndexMap::new(),
+            stack: Vec::new(),
+        };
+
+        // Order the imports reachable from the source files first, then any unused ones.
+        for unit in sources.iter_mut() {
+            planner.resolve(unit)?;
+        }
+        while let Some(name) = planner.unordered.keys().next().copied() {
+            planner.schedule(name)?;
+        }
+
+        Ok(Self {
+            imports: planner.ordered,
+            sources,
+        })
+    }
+}
+
+/// The state of the topological sort over the import graph.
+struct Planner<'a> {
+    /// The path to the `imports/` directory of the package.
+    imports_directory: PathBuf,
+    /// The import files that have not been visited yet.
+    unordered: IndexMap<Symbol, Unit<'a>>,
+    /// The import files whose imports have all been ordered and resolved.
+    ordered: IndexMap<Symbol, Unit<'a>>,
+    /// The resolved programs of the ordered import files.
+    programs: IndexMap<Symbol, Program>,
+    /// The resolved programs of the ordered libraries.
+    libraries: IndexMap<Symbol, Program>,
+    /// The import files currently being visited, used to report cycles.
+    stack: Vec<Symbol>,
+}
+
+impl<'a> Planner<'a> {
+    /// Orders the import file `name` after everything it imports.
+    fn schedule(&mut self, name: Symbol) -> Result<()> {
+        let mut unit = self.unordered.shift_remove(&name).unwrap();
+
+        self.stack.push(name);
+        self.resolve(&mut unit)?;
+        self.stack.pop();
+
+        let program = unit.compiler.ast.as_repr().clone();
+        if unit.library {
+            self.libraries.insert(name, program);
+        } else {
+            self.programs.insert(name, program);
+        }
+        self.ordered.insert(name, unit);
+        Ok(())
+    }
+
+    /// Orders the imports of `unit` and fills them into its program.
+    fn resolve(&mut self, unit: &mut Unit<'a>) -> Result<()> {
+        let imports: Vec<Identifier> = unit.compiler.ast.as_repr().imports.keys().cloned().collect();
+
+        for import in imports.iter() {
+            if self.ordered.contains_key(&import.name) {
+                continue;
+            }
+
+            if let Some(position) = self.stack.iter().position(|name| *name == import.name) {
+                let cycle = self.stack[position..]
+                    .iter()
+                    .chain(std::iter::once(&import.name))
+                    .map(|name| format!("{}.leo", name))
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+                return Err(CompilerError::cyclic_import(cycle, import.span).into());
+            }
+
+            if !self.unordered.contains_key(&import.name) {
+                let import_file_path = self.imports_directory.join(format!("{}.leo", import.name));
+                return Err(CompilerError::import_not_found(import_file_path.display(), import.span).into());
+            }
+
+            self.schedule(import.name)?;
+        }
+
+        unit.compiler.resolve_imports(&self.programs);
+        for import in imports {
+            if let Some(library) = self.libraries.get(&import.name) {
+                unit.compiler.include_library(import.name, library)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the paths to the `.leo` files in `imports/` and in `src/` of the package.
+pub fn package_files(package_path: &Path) -> Result<PackageFiles> {
+    let (imports, libraries) = if ImportsDirectory::is_empty(package_path)? {
+        (Vec::new(), Vec::new())
+    } else {
+        // Packages in `imports/` must be libraries, their main file is included by the importing files.
+        let mut libraries = Vec::new();
+        for library_path in ImportsDirectory::packages(package_path)? {
+            let name = library_path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            if PackageKind::read_from(&library_path)? != PackageKind::Library {
+                return Err(PackageError::imported_package_not_library(name).into());
+            }
+            libraries.push((name, library_path.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME)));
+        }
+
+        (ImportsDirectory::files(package_path)?, libraries)
+    };
+
+    Ok(PackageFiles {
+        imports,
+        libraries,
+        sources: SourceDirectory::files(package_path)?,
+    })
+}
+
+/// Parses the main file of the library package `name`.
+pub fn parse_library_file<'a>(
+    name: String,
+    file_path: PathBuf,
+    handler: &'a Handler,
+    outputs: &Path,
+    options: BuildOptions,
+) -> Result<Unit<'a>> {
+    let mut unit = parse_leo_file(file_path, &name, handler, outputs, options)?;
+    unit.name = name;
+    unit.library = true;
+    Ok(unit)
+}
+
+/// Returns the contents of the files at `paths`, which are read on separate threads.
+fn read_files(paths: Vec<PathBuf>) -> Result<IndexMap<PathBuf, String>> {
+    let readers = paths
+        .into_iter()
+        .map(|file_path| {
+            std::thread::spawn(move || {
+                let source = fs::read_to_string(&file_path);
+                (file_path, source)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    readers
+        .into_iter()
+        .map(|reader| {
+            let (file_path, source) = reader.join().expect("reading a file does not panic");
+            let source = source.map_err(|e| CompilerError::file_read_error(&file_path, e))?;
+            Ok((file_path, source))
+        })
+        .collect()
+}
+
+/// Parses the Leo file at `file_path` without resolving its imports.
+pub fn parse_leo_file<'a>(
+    file_path: PathBuf,
+    package_name: &str,
+    handler: &'a Handler,
+    outputs: &Path,
+    options: BuildOptions,
+) -> Result<Unit<'a>> {
+    let source = fs::read_to_string(&file_path).map_err(|e| CompilerError::file_read_error(&file_path, e))?;
+    parse_leo_source(file_path, &source, package_name, handler, outputs, options)
+}
+
+/// Parses the `source` of the Leo file at `file_path` without resolving its imports.
+fn parse_leo_source<'a>(
+    file_path: PathBuf,
+    source: &str,
+    package_name: &str,
+    handler: &'a Handler,
+    outputs: &Path,
+    options: BuildOptions,
+) -> Result<Unit<'a>> {
+    // Construct the Leo file name with extension `foo.leo`.
+    let file_name = file_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(PackageError::failed_to_get_file_name)?
+        .to_string();
+
+    // Construct program name from file_path name `foo`.
+    let name = file_name
+        .strip_suffix(".leo")
+        .ok_or_else(PackageError::failed_to_get_file_name)?
+        .to_string();
+
+    // Construct program id header for aleo file.
+    // Do not create a program with main.aleo as the ID.
+    let program_id_name = if file_name.eq(MAIN_FILENAME) {
+        package_name
+    } else {
+        &name
+    };
+
+    // Create a new instance of the Leo compiler.
+    let mut compiler = Compiler::new(
+        program_id_name.to_string(),
+        String::from("aleo"), // todo: fetch this from Network::Testnet3
+        handler,
+        file_path.clone(),
+        outputs.to_path_buf(),
+        Some(options.into()),
+    );
+    compiler.parse_program_from_string(source, FileName::Real(file_path))?;
+
+    Ok(Unit {
+        file_name,
+        name,
+        compiler,
+        handler,
+        library: false,
+    })
+}
@@ -15,17 +15,24 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::build::BuildOptions;
-use crate::commands::ALEO_CLI_COMMAND;
+use crate::commands::{Network, ALEO_CLI_COMMAND};
 use crate::{
     commands::{Build, Command},
     context::Context,
 };
-use leo_errors::{CliError, PackageError, Result};
-use leo_package::build::BuildDirectory;
+use leo_compiler::{source_map::failed_instruction, InstructionSpan};
+use leo_errors::{CliError, PackageError, Result, SnarkVMError};
+use leo_package::{build::BuildDirectory, imports::IMPORTS_DIRECTORY_NAME, package::PackageKind};
+use leo_span::Symbol;
 
 use aleo::commands::Run as AleoRun;
+use snarkvm::circuit::AleoV0;
+use snarkvm::file::AleoFile;
+use snarkvm::prelude::{Identifier, PrivateKey, Process, Program, ProgramID, Value};
 
 use clap::StructOpt;
+use std::path::Path;
+use std::str::FromStr;
 use tracing::span::Span;
 
 /// Build, Prove and Run Leo program with inputs
@@ -50,6 +57,12 @@ impl Command for Run {
     }
 
     fn prelude(&self, context: Context) -> Result<Self::Input> {
+        // Libraries have no functions to run.
+        if PackageKind::read_from(&context.dir()?)? == PackageKind::Library {
+            let package_name = context.read_manifest()?.program_id().name().to_string();
+            return Err(CliError::library_not_runnable(package_name).into());
+        }
+
         (Build {
             compiler_options: self.compiler_options.clone(),
         })
@@ -57,15 +70,17 @@ impl Command for Run {
     }
 
     fn apply(self, context: Context, input: Self::Input) -> Result<Self::Output> {
+        let (input_ast, circuits, functions, instruction_spans) = input;
+
         // Get the input values.
-        let mut inputs = match input {
-            (Some(input_ast), circuits) => input_ast.program_inputs(&self.name, circuits),
-            _ => Vec::new(),
+        let mut inputs = match input_ast {
+            Some(input_ast) => input_ast.program_inputs(&self.name, circuits),
+            None => Vec::new(),
         };
 
-        // Compose the `aleo run` command.
-        let mut arguments = vec![ALEO_CLI_COMMAND.to_string(), self.name];
-        arguments.append(&mut inputs);
+        // Failures of the function point to its definition in the Leo source.
+        let span = functions.get(&Symbol::intern(&self.name)).copied().unwrap_or_default();
+        let program_id = context.open_manifest()?.program_id().to_string();
 
         // Open the Leo build/ directory
         let path = context.dir()?;
@@ -75,9 +90,18 @@ impl Command for Run {
         std::env::set_current_dir(&build_directory)
             .map_err(|err| PackageError::failed_to_set_cwd(build_directory.display(), err))?;
 
+        // Evaluate the function first, so that its failures point to the Leo code.
+        evaluate(&program_id, &self.name, span, &inputs, &instruction_spans)?;
+
+        // Compose the `aleo run` command.
+        let mut arguments = vec![ALEO_CLI_COMMAND.to_string(), self.name.clone()];
+        arguments.append(&mut inputs);
+
         // Call the `aleo run` command from the Aleo SDK.
         let command = AleoRun::try_parse_from(&arguments).map_err(CliError::failed_to_parse_aleo_run)?;
-        let res = command.parse().map_err(CliError::failed_to_execute_aleo_run)?;
+        let res = command
+            .parse()
+            .map_err(|error| SnarkVMError::execution_failed(&self.name, format!("{:#}", error)))?;
 
         // Log the output of the `aleo run` command.
         tracing::info!("{}", res);
@@ -85,3 +109,66 @@ impl Command for Run {
         Ok(())
     }
 }
+
+/// Evaluates the function `function` of the program in the current directory with snarkVM, without proving it.
+/// Inputs that snarkVM rejects point to the Leo function at `span`. A failing instruction points to the Leo code it was
+/// generated for, as found in `instruction_spans`, or else to the function.
+pub(crate) fn evaluate(
+    program_id: &str,
+    function: &str,
+    span: leo_span::Span,
+    inputs: &[String],
+    instruction_spans: &[InstructionSpan],
+) -> Result<()> {
+    let process = load_process().map_err(|error| SnarkVMError::malformed_program(format!("{:#}", error)))?;
+
+    // snarkVM checks the inputs against the function when it authorizes the call.
+    let rng = &mut rand::thread_rng();
+    let authorization = inputs
+        .iter()
+        .map(|input| Value::<Network>::from_str(input))
+        .collect::<anyhow::Result<Vec<_>>>()
+        .and_then(|inputs| {
+            let private_key = PrivateKey::new(rng)?;
+            let program_id = ProgramID::from_str(program_id)?;
+            process.authorize::<AleoV0, _>(&private_key, &program_id, Identifier::from_str(function)?, &inputs, rng)
+        })
+        .map_err(|error| SnarkVMError::invalid_inputs(function, format!("{:#}", error), span))?;
+
+    process.evaluate::<AleoV0>(authorization).map_err(|error| {
+        let error = format!("{:#}", error);
+        let span = failed_instruction(instruction_spans, Symbol::intern(function), &error)
+            .map_or(span, |instruction| instruction.span);
+        SnarkVMError::unsatisfied_constraint(function, error, span)
+    })?;
+
+    Ok(())
+}
+
+/// Loads the Aleo program in the current directory and the programs in its `imports/` directory into a snarkVM process.
+fn load_process() -> anyhow::Result<Process<Network>> {
+    let read = |path: &Path| Program::<Network>::from_str(&std::fs::read_to_string(path)?);
+
+    let mut imports = Vec::new();
+    if Path::new(IMPORTS_DIRECTORY_NAME).is_dir() {
+        for entry in std::fs::read_dir(IMPORTS_DIRECTORY_NAME)? {
+            let path = entry?.path();
+            if path.extension().map_or(false, |extension| extension == "aleo") {
+                imports.push(read(&path)?);
+            }
+        }
+    }
+
+    // A program can only be added after the programs it imports.
+    let mut process = Process::load()?;
+    while !imports.is_empty() {
+        let count = imports.len();
+        imports.retain(|program| process.add_program(program).is_err());
+        if imports.len() == count {
+            process.add_program(&imports[0])?;
+        }
+    }
+    process.add_program(&read(Path::new(&AleoFile::<Network>::main_file_name()))?)?;
+
+    Ok(process)
+}
@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The package templates of `leo new`, embedded from `leo/templates/`.
+//!
+//! Each template is a copy of one of the programs in `examples/`, with its program name
+//! replaced by the `{{name}}` placeholder.
+
+use leo_errors::{PackageError, Result};
+use leo_package::{
+    inputs::InputFile,
+    source::{MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+
+use std::path::Path;
+
+/// The placeholder for the package name in the template files.
+const NAME_PLACEHOLDER: &str = "{{name}}";
+
+/// A package template built from one of the example programs.
+pub struct Template {
+    /// The name of the template, which is also the program name of its example.
+    pub name: &'static str,
+    /// A short description of the template.
+    pub description: &'static str,
+    /// The contents of `src/main.leo`.
+    main: &'static str,
+    /// The contents of `inputs/{{name}}.in`.
+    input: &'static str,
+}
+
+macro_rules! template {
+    ($name:literal, $description:literal) => {
+        Template {
+            name: $name,
+            description: $description,
+            main: include_str!(concat!("../templates/", $name, "/src/main.leo")),
+            input: include_str!(concat!("../templates/", $name, "/inputs/input.in")),
+        }
+    };
+}
+
+/// The templates available to `leo new --template`.
+pub const TEMPLATES: &[Template] = &[
+    template!("token", "A record-based token with `mint` and `transfer` functions"),
+    template!("message", "A circuit type passed in as program input"),
+    template!("groups", "Arithmetic on elliptic curve group elements"),
+    template!("core", "Calls to the core BHP256 and Poseidon2 circuits"),
+];
+
+impl Template {
+    /// Returns the template with the given name, if it exists.
+    pub fn find(name: &str) -> Option<&'static Template> {
+        TEMPLATES.iter().find(|template| template.name == name)
+    }
+
+    /// Writes the template's source and input files into the package `package_name` at `path`,
+    /// replacing every `{{name}}` placeholder with the package name.
+    pub fn write_to(&self, package_name: &str, path: &Path) -> Result<()> {
+        let main_file_path = path.join(SOURCE_DIRECTORY_NAME).join(MAIN_FILENAME);
+        std::fs::write(main_file_path, Self::substitute(self.main, package_name))
+            .map_err(PackageError::io_error_main_file)?;
+
+        let input_file_path = InputFile::new(package_name).setup_file_path(path);
+        std::fs::write(input_file_path, Self::substitute(self.input, package_name))
+            .map_err(PackageError::io_error_input_file)?;
+
+        Ok(())
+    }
+
+    /// Replaces the `{{name}}` placeholders in `contents` with `package_name`.
+    fn substitute(contents: &str, package_name: &str) -> String {
+        contents.replace(NAME_PLACEHOLDER, package_name)
+    }
+}
@@ -0,0 +1,281 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::build::{compile_leo_file, BuildOptions};
+use super::run::evaluate;
+use crate::commands::plan::{package_files, parse_leo_file, parse_library_file, BuildPlan, Unit};
+use crate::commands::{Network, ALEO_CLI_COMMAND};
+use crate::{commands::Command, context::Context};
+
+use leo_ast::Value;
+use leo_errors::{emitter::Handler, CliError, PackageError, Result, SnarkVMError};
+use leo_interpreter::Interpreter;
+use leo_package::{
+    build::{BuildDirectory, BUILD_DIRECTORY_NAME},
+    imports::ImportsDirectory,
+    outputs::OUTPUTS_DIRECTORY_NAME,
+    source::MAIN_FILENAME,
+    tests::{TestsDirectory, TESTS_DIRECTORY_NAME},
+};
+use leo_span::{Span, Symbol};
+
+use aleo::commands::Run as AleoRun;
+use snarkvm::file::Manifest;
+use snarkvm::prelude::ProgramID;
+
+use clap::StructOpt;
+use colored::Colorize;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Run the `@test` functions of the package
+#[derive(StructOpt, Debug)]
+pub struct Test {
+    #[structopt(name = "FILTER", help = "Only runs the tests whose names contain this string.")]
+    pub(crate) filter: Option<String>,
+
+    #[structopt(long, help = "Executes the tests with snarkVM, instead of evaluating them directly.")]
+    pub(crate) prove: bool,
+
+    #[structopt(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
+
+/// A test function, named by its file and function, e.g. `main::test_add`.
+struct TestCase {
+    path: String,
+    function: Symbol,
+    span: Span,
+}
+
+/// The number of tests that passed, failed, and were left out by the filter.
+#[derive(Default)]
+struct Summary {
+    passed: usize,
+    failed: usize,
+    filtered_out: usize,
+}
+
+impl Command for Test {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> tracing::span::Span {
+        tracing::span!(tracing::Level::INFO, "Testing")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        // Get the package path.
+        let package_path = context.dir()?;
+
+        // Get the program name, without mirroring the manifest into `build/`.
+        let package_name = context.read_manifest()?.program_id().name().to_string();
+
+        // Nothing is written here, the compiler only needs a path for its optional snapshots.
+        let outputs_directory = package_path.join(OUTPUTS_DIRECTORY_NAME);
+
+        // Compile errors are reported as usual, failed tests are reported to a handler of their own,
+        // so that they do not stop the files after them from being compiled.
+        let handler = context.handler()?;
+        let failures = context.handler()?;
+
+        // Only the instructions executed by snarkVM need the test functions.
+        let mut options = self.compiler_options.clone();
+        options.test_functions = self.prove;
+
+        // Parse every file once, the files in `tests/` are compiled like the files in `src/`.
+        let files = package_files(&package_path)?;
+        let parse = |file_path| parse_leo_file(file_path, &package_name, &handler, &outputs_directory, options.clone());
+        let mut imports = files.imports.into_iter().map(&parse).collect::<Result<Vec<_>>>()?;
+        for (name, file_path) in files.libraries {
+            imports.push(parse_library_file(
+                name,
+                file_path,
+                &handler,
+                &outputs_directory,
+                options.clone(),
+            )?);
+        }
+        let sources = files
+            .sources
+            .into_iter()
+            .chain(TestsDirectory::files(&package_path)?)
+            .map(parse)
+            .collect::<Result<Vec<_>>>()?;
+        let plan = BuildPlan::order(&package_path, imports, sources)?;
+
+        // The programs to execute with snarkVM are built one after another as the package in `build/tests/`.
+        let tests_directory = package_path.join(BUILD_DIRECTORY_NAME).join(TESTS_DIRECTORY_NAME);
+        if self.prove {
+            std::fs::create_dir_all(&tests_directory)
+                .map_err(|err| PackageError::failed_to_create_directory(TESTS_DIRECTORY_NAME, err))?;
+        }
+        let tests_directory = tests_directory.canonicalize().unwrap_or(tests_directory);
+
+        // Check the imported files, which the tests may call into.
+        for mut unit in plan.imports.into_values() {
+            if self.prove {
+                compile_leo_file(unit, &ImportsDirectory::create(&tests_directory)?)?;
+            } else {
+                unit.compiler.compiler_stages()?;
+            }
+        }
+
+        let mut summary = Summary::default();
+        for unit in plan.sources {
+            let tests = self.tests(&unit, &mut summary);
+            if tests.is_empty() {
+                continue;
+            }
+
+            if self.prove {
+                execute_tests(unit, &tests, &tests_directory, &failures, &mut summary)?;
+            } else {
+                evaluate_tests(unit, &tests, &failures, &mut summary)?;
+            }
+        }
+
+        let result = match summary.failed {
+            0 => "ok".green(),
+            _ => "FAILED".red(),
+        };
+        tracing::info!(
+            "test result: {}. {} passed; {} failed; {} filtered out",
+            result,
+            summary.passed,
+            summary.failed,
+            summary.filtered_out
+        );
+
+        match summary.failed {
+            0 => Ok(()),
+            failed => Err(CliError::tests_failed(failed).into()),
+        }
+    }
+}
+
+impl Test {
+    /// Returns the `@test` functions of the file that match the filter.
+    fn tests(&self, unit: &Unit, summary: &mut Summary) -> Vec<TestCase> {
+        let mut tests = Vec::new();
+        for function in unit.compiler.ast.as_repr().functions.values() {
+            if !function.is_test() {
+                continue;
+            }
+
+            let path = format!("{}::{}", unit.name, function.name());
+            match &self.filter {
+                Some(filter) if !path.contains(filter.as_str()) => summary.filtered_out += 1,
+                _ => tests.push(TestCase {
+                    path,
+                    function: function.name(),
+                    span: function.identifier.span,
+                }),
+            }
+        }
+        tests
+    }
+}
+
+/// Evaluates the tests of a file with the Leo interpreter.
+fn evaluate_tests(mut unit: Unit, tests: &[TestCase], failures: &Handler, summary: &mut Summary) -> Result<()> {
+    unit.compiler.compiler_stages()?;
+    let program = unit.compiler.ast.as_repr();
+
+    for test in tests {
+        let mut interpreter = Interpreter::new(program);
+        let result = interpreter
+            .call(test.function, Vec::new())
+            .and_then(|value| match value {
+                // A test fails when it returns `false`.
+                Value::Boolean(false, _) => Err(CliError::test_returned_false(test.span).into()),
+                _ => Ok(()),
+            });
+
+        // The output of a test is only of interest when it fails.
+        if result.is_err() {
+            for line in interpreter.logs() {
+                tracing::info!("{}", line);
+            }
+        }
+        report(test, result, failures, summary);
+    }
+    Ok(())
+}
+
+/// Compiles a file with its tests into Aleo instructions, and executes the tests with snarkVM.
+fn execute_tests(
+    mut unit: Unit,
+    tests: &[TestCase],
+    tests_directory: &Path,
+    failures: &Handler,
+    summary: &mut Summary,
+) -> Result<()> {
+    // The file becomes the main program of the test package, whose keys are built anew.
+    BuildDirectory::remove(tests_directory)?;
+    let manifest_path = tests_directory.join(Manifest::<Network>::file_name());
+    if manifest_path.exists() {
+        std::fs::remove_file(&manifest_path)
+            .map_err(|err| CliError::failed_to_write_test_program(manifest_path.display(), err))?;
+    }
+
+    let program_id = format!("{}.aleo", unit.compiler.program_name);
+    unit.name = MAIN_FILENAME.trim_end_matches(".leo").to_string();
+    let (_, instruction_spans) = compile_leo_file(unit, tests_directory)?;
+    ProgramID::<Network>::from_str(&program_id)
+        .and_then(|program_id| Manifest::create(tests_directory, &program_id))
+        .map_err(|err| CliError::failed_to_write_test_program(tests_directory.display(), err))?;
+
+    // Change the cwd to the test package, where the Aleo SDK looks for the program, and back after the tests.
+    let current_directory = std::env::current_dir().map_err(CliError::cli_io_error)?;
+    std::env::set_current_dir(tests_directory)
+        .map_err(|err| PackageError::failed_to_set_cwd(tests_directory.display(), err))?;
+
+    for test in tests {
+        let function = test.function.to_string();
+        let result = evaluate(&program_id, &function, test.span, &[], &instruction_spans).and_then(|()| {
+            let command =
+                AleoRun::try_parse_from([ALEO_CLI_COMMAND, &function]).map_err(CliError::failed_to_parse_aleo_run)?;
+            command
+                .parse()
+                .map(|_| ())
+                .map_err(|error| SnarkVMError::execution_failed(&function, format!("{:#}", error)).into())
+        });
+        report(test, result, failures, summary);
+    }
+
+    std::env::set_current_dir(&current_directory)
+        .map_err(|err| PackageError::failed_to_set_cwd(current_directory.display(), err).into())
+}
+
+/// Logs the outcome of a test, and reports why it failed.
+fn report(test: &TestCase, result: Result<()>, failures: &Handler, summary: &mut Summary) {
+    match result {
+        Ok(()) => {
+            tracing::info!("test {} ... {}", test.path, "ok".green());
+            summary.passed += 1;
+        }
+        Err(err) => {
+            tracing::info!("test {} ... {}", test.path, "FAILED".red());
+            failures.emit_err(err);
+            summary.failed += 1;
+        }
+    }
+}
@@ -14,11 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::commands::Network;
-use leo_errors::{CliError, PackageError, Result};
+use crate::commands::{explanations::Explanation, Network};
+use leo_errors::{
+    emitter::{Emitter, Handler, JsonEmitter, LintLevel, StderrEmitter},
+    CliError, PackageError, Result,
+};
 use snarkvm::file::Manifest;
 
-use leo_package::build::{BuildDirectory, BUILD_DIRECTORY_NAME};
+use clap::ArgEnum;
+use leo_package::{
+    build::{BuildDirectory, BUILD_DIRECTORY_NAME},
+    package::Lints,
+    root::MANIFEST_FILE_NAME,
+};
 use std::fs::File;
 use std::io::Write;
 use std::{
@@ -26,17 +34,66 @@ use std::{
     path::{Path, PathBuf},
 };
 
+/// The format in which errors and warnings are reported.
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageFormat {
+    /// Colored text for people to read.
+    Human,
+    /// One JSON object per line, for editors and other tools.
+    Json,
+}
+
+impl MessageFormat {
+    /// Returns an emitter that reports messages in this format.
+    pub fn emitter(self) -> Box<dyn Emitter> {
+        match self {
+            MessageFormat::Human => Box::new(StderrEmitter::with_explanations(Explanation::exists)),
+            MessageFormat::Json => Box::<JsonEmitter>::default(),
+        }
+    }
+}
+
 /// Project context, manifest, current directory etc
 /// All the info that is relevant in most of the commands
 #[derive(Clone)]
 pub struct Context {
     /// Path at which the command is called, None when default
     pub path: Option<PathBuf>,
+    /// The format in which errors and warnings are reported
+    pub message_format: MessageFormat,
+    /// The lint levels given on the command line, which take precedence over those of the manifest
+    pub lint_levels: Vec<(String, LintLevel)>,
 }
 
 impl Context {
-    pub fn new(path: Option<PathBuf>) -> Result<Context> {
-        Ok(Context { path })
+    pub fn new(
+        path: Option<PathBuf>,
+        message_format: MessageFormat,
+        lint_levels: Vec<(String, LintLevel)>,
+    ) -> Result<Context> {
+        Ok(Context {
+            path,
+            message_format,
+            lint_levels,
+        })
+    }
+
+    /// Returns a new error handler reporting in the message format of the context.
+    /// The handler applies the lint levels of the package manifest, if there is one, and then those of the command line.
+    pub fn handler(&self) -> Result<Handler> {
+        let handler = Handler::new(self.message_format.emitter());
+
+        let path = self.dir()?;
+        if path.join(MANIFEST_FILE_NAME).exists() {
+            for (lint, level) in Lints::read_from(&path)?.0 {
+                handler.set_lint_level(&lint, level)?;
+            }
+        }
+        for (lint, level) in self.lint_levels.iter() {
+            handler.set_lint_level(lint, *level)?;
+        }
+
+        Ok(handler)
     }
 
     /// Returns the path to the Leo package.
@@ -47,12 +104,18 @@ impl Context {
         }
     }
 
+    /// Opens the manifest file `program.json` without touching the build directory.
+    pub fn read_manifest(&self) -> Result<Manifest<Network>> {
+        let path = self.dir()?;
+        Manifest::<Network>::open(&path).map_err(|e| PackageError::failed_to_open_manifest(e).into())
+    }
+
     /// Returns the package name as a String.
     /// Opens the manifest file `program.json` and creates the build directory if it doesn't exist.
     pub fn open_manifest(&self) -> Result<Manifest<Network>> {
         // Open the manifest file.
         let path = self.dir()?;
-        let manifest = Manifest::<Network>::open(&path).map_err(PackageError::failed_to_open_manifest)?;
+        let manifest = self.read_manifest()?;
 
         // Lookup the program id.
         // let program_id = manifest.program_id();
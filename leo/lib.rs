@@ -19,6 +19,7 @@
 pub mod commands;
 pub mod context;
 pub mod logger;
+pub mod lsp;
 pub mod updater;
 
 #[cfg(test)]
@@ -0,0 +1,468 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{DiagnosticsEmitter, LineIndex, Position, Range};
+
+use leo_ast::{
+    AccessExpression, AssignStatement, Ast, CallExpression, Circuit, CircuitExpression, CircuitMember,
+    DefinitionStatement, Expression, ExpressionVisitor, Function, Identifier, IterationStatement, Program,
+    ProgramVisitor, StatementVisitor, Type,
+};
+use leo_core::CoreInstruction;
+use leo_errors::{emitter::Handler, LeoError};
+use leo_passes::{CreateSymbolTable, FunctionSymbol, Pass, SymbolTable, TypeChecker, VariableSymbol};
+use leo_span::{
+    source_map::FileName,
+    symbol::{create_session_if_not_set_then, with_session_globals, SessionGlobals, SESSION_GLOBALS},
+    Span, Symbol,
+};
+
+use indexmap::IndexMap;
+use serde_json::{json, Value};
+use std::rc::Rc;
+
+/// The LSP symbol kind of functions.
+const FUNCTION_SYMBOL: u8 = 12;
+/// The LSP symbol kind of circuits and records.
+const STRUCT_SYMBOL: u8 = 23;
+/// The LSP symbol kind of circuit and record members.
+const FIELD_SYMBOL: u8 = 8;
+
+/// What the server knows about a document from its last analysis.
+#[derive(Debug, Default)]
+pub struct Analysis {
+    /// The errors and warnings of the document, as LSP diagnostics.
+    pub diagnostics: Vec<Value>,
+    /// Whether the document parsed, so that the rest of the analysis describes it.
+    pub parsed: bool,
+    /// The functions, circuits and records of the document, as LSP document symbols.
+    pub symbols: Vec<Value>,
+    /// The identifiers of the document that refer to a definition.
+    pub references: Vec<Reference>,
+    /// The circuits and records of the document, with the names and types of their members.
+    pub circuits: IndexMap<String, Vec<(String, String)>>,
+    /// The functions of the document, with their signatures.
+    pub functions: IndexMap<String, String>,
+}
+
+/// An identifier of a document, with what it refers to.
+#[derive(Clone, Debug)]
+pub struct Reference {
+    /// The name of the identifier.
+    pub name: String,
+    /// The range of the identifier.
+    pub range: Range,
+    /// What the identifier refers to, in Markdown.
+    pub hover: String,
+    /// The range of the definition the identifier refers to.
+    pub definition: Range,
+    /// The circuit or record that the value of the identifier is an instance of, if any.
+    pub circuit: Option<String>,
+}
+
+impl Analysis {
+    /// Parses and type checks `text`, the content of the document at `uri`.
+    /// The analysis runs in a session of its own, so that the source map does not grow with every edit.
+    pub fn new(uri: &str, text: &str) -> Self {
+        SESSION_GLOBALS.set(&SessionGlobals::default(), || Self::analyze(uri, text))
+    }
+
+    /// Keeps what `previous` knows about the definitions of the document, if this analysis could not parse it.
+    /// The document does not parse while it is edited, e.g. right after a `.` is typed, which is when completion is needed.
+    pub fn fall_back_to(&mut self, previous: Analysis) {
+        if !self.parsed {
+            self.symbols = previous.symbols;
+            self.references = previous.references;
+            self.circuits = previous.circuits;
+            self.functions = previous.functions;
+        }
+    }
+
+    /// Returns the identifier at `position`, if it refers to a definition.
+    pub fn reference_at(&self, position: Position) -> Option<&Reference> {
+        self.references
+            .iter()
+            .find(|reference| reference.range.contains(position))
+    }
+
+    /// Returns the circuit or record that the value of the identifier `name`, ending at `end`, is an instance of.
+    /// If the identifier is not where it was when the document last parsed, an identifier with the same name is used.
+    pub fn circuit_of(&self, name: &str, end: Position) -> Option<&str> {
+        let mut references = self
+            .references
+            .iter()
+            .filter(|reference| reference.name == name && reference.circuit.is_some());
+        let reference = references
+            .clone()
+            .find(|reference| reference.range.end == end)
+            .or_else(|| references.find(|reference| reference.range.start < end))?;
+        reference.circuit.as_deref()
+    }
+
+    /// Analyzes the document in the current session.
+    fn analyze(uri: &str, text: &str) -> Self {
+        let sf = with_session_globals(|s| s.source_map.new_source(text, FileName::Custom(uri.to_string())));
+        let line_index = Rc::new(LineIndex::new(&sf.src, sf.start_pos));
+        let emitter = DiagnosticsEmitter::new(uri, line_index.clone());
+        let handler = Handler::new(Box::new(emitter.clone()));
+
+        let mut analysis = Self::default();
+        match leo_parser::parse_ast(&handler, &sf.src, sf.start_pos) {
+            Ok(ast) => {
+                analysis.parsed = true;
+                analysis.collect(&ast, &handler, &line_index);
+            }
+            Err(LeoError::LastErrorCode(_)) => {}
+            Err(err) => handler.emit_err(err),
+        }
+        analysis.diagnostics = emitter.take();
+        analysis
+    }
+
+    /// Collects the definitions of the parsed document, and the identifiers that refer to them.
+    fn collect(&mut self, ast: &Ast, handler: &Handler, line_index: &LineIndex) {
+        let program = ast.as_repr();
+        self.symbols = document_symbols(program, line_index);
+
+        // Definitions that conflict with each other leave nothing to refer to.
+        let symbol_table = match CreateSymbolTable::do_pass((ast, handler)) {
+            Ok(symbol_table) => symbol_table,
+            Err(LeoError::LastErrorCode(_)) => return,
+            Err(err) => return handler.emit_err(err),
+        };
+
+        // The variables of each scope are only known once the scope has been type checked.
+        // Type errors are reported, but do not keep the variables from being referred to.
+        let mut checker = TypeChecker::new(symbol_table, handler);
+        checker.visit_program(program);
+        let symbol_table = checker.into_symbol_table();
+
+        for (name, circuit) in symbol_table.circuits.iter() {
+            let members = circuit
+                .members
+                .iter()
+                .map(|CircuitMember::CircuitVariable(member, type_, _)| (member.to_string(), type_.to_string()))
+                .collect();
+            self.circuits.insert(name.to_string(), members);
+        }
+        for (name, function) in symbol_table.functions.iter() {
+            self.functions.insert(name.to_string(), signature(*name, function));
+        }
+
+        let mut resolver = Resolver {
+            symbol_table: &symbol_table,
+            line_index,
+            variables: Vec::new(),
+            references: Vec::new(),
+        };
+        resolver.visit_program(program);
+        self.references = resolver.references;
+    }
+}
+
+/// Returns the core circuits, such as `BHP256`, with the names and return types of their functions.
+pub fn core_circuits() -> IndexMap<String, Vec<(String, String)>> {
+    create_session_if_not_set_then(|_| {
+        let mut circuits = IndexMap::<String, Vec<(String, String)>>::new();
+        for (circuit, function, instruction) in CoreInstruction::ALL {
+            circuits
+                .entry(circuit.to_string())
+                .or_default()
+                .push((function.to_string(), instruction.return_type().to_string()));
+        }
+        circuits
+    })
+}
+
+/// Returns the signature of the function `name`, e.g. `function add(a: u32, b: u32) -> u32`.
+fn signature(name: Symbol, function: &FunctionSymbol) -> String {
+    let input: Vec<String> = function.input.iter().map(|input| input.to_string()).collect();
+    format!("function {}({}) -> {}", name, input.join(", "), function.output)
+}
+
+/// Returns `code` as a block of Leo code in Markdown, followed by `text`.
+fn hover(code: &str, text: &str) -> String {
+    format!("```leo\n{}\n```\n{}", code, text).trim_end().to_string()
+}
+
+/// Returns the LSP document symbols of the functions, circuits and records of `program`, in source order.
+fn document_symbols(program: &Program, line_index: &LineIndex) -> Vec<Value> {
+    let symbol = |name: &Identifier, detail: String, kind: u8, span: Span, children: Vec<Value>| {
+        let range = line_index.range(span)?;
+        Some(json!({
+            "name": name.to_string(),
+            "detail": detail,
+            "kind": kind,
+            "range": range,
+            "selectionRange": line_index.range(name.span).unwrap_or(range),
+            "children": children,
+        }))
+    };
+
+    let mut symbols: Vec<(Span, Value)> = Vec::new();
+    for function in program.functions.values() {
+        let input: Vec<String> = function.input.iter().map(|input| input.to_string()).collect();
+        let detail = format!("({}) -> {}", input.join(", "), function.output);
+        symbols.extend(
+            symbol(&function.identifier, detail, FUNCTION_SYMBOL, function.span, Vec::new())
+                .map(|symbol| (function.span, symbol)),
+        );
+    }
+    for circuit in program.circuits.values() {
+        let members = circuit
+            .members
+            .iter()
+            .filter_map(|CircuitMember::CircuitVariable(member, type_, _)| {
+                symbol(member, type_.to_string(), FIELD_SYMBOL, member.span, Vec::new())
+            })
+            .collect();
+        let detail = if circuit.is_record { "record" } else { "circuit" };
+        symbols.extend(
+            symbol(
+                &circuit.identifier,
+                detail.to_string(),
+                STRUCT_SYMBOL,
+                circuit.span,
+                members,
+            )
+            .map(|symbol| (circuit.span, symbol)),
+        );
+    }
+    symbols.sort_by_key(|(span, _)| span.lo);
+    symbols.into_iter().map(|(_, symbol)| symbol).collect()
+}
+
+/// Resolves the identifiers of a program to the definitions of the symbol table.
+struct Resolver<'a> {
+    /// The symbol table of the program, with the variables of every scope.
+    symbol_table: &'a SymbolTable,
+    /// Locates the spans of the program in the document.
+    line_index: &'a LineIndex,
+    /// The variables of the function being visited, in all of its scopes.
+    variables: Vec<(Symbol, VariableSymbol)>,
+    /// The identifiers resolved thus far.
+    references: Vec<Reference>,
+}
+
+impl<'a> Resolver<'a> {
+    /// Returns the variable `name` refers to at `span`.
+    /// Since Leo does not allow shadowing, this is the last variable of that name that is defined before `span`.
+    fn variable(&self, name: Symbol, span: Span) -> Option<&VariableSymbol> {
+        self.variables
+            .iter()
+            .filter(|(variable, symbol)| *variable == name && symbol.span.lo <= span.lo)
+            .max_by_key(|(_, symbol)| symbol.span.lo)
+            .map(|(_, symbol)| symbol)
+    }
+
+    /// Returns the circuit or record that `type_` is an instance of, if it is one.
+    fn circuit_of_type(&self, type_: &Type) -> Option<&'a Circuit> {
+        match type_ {
+            Type::Identifier(identifier) => self.symbol_table.lookup_circuit(identifier.name),
+            _ => None,
+        }
+    }
+
+    /// Returns the circuit or record that the value of `expression` is an instance of, if it is one.
+    fn circuit_of(&self, expression: &Expression) -> Option<&'a Circuit> {
+        match expression {
+            Expression::Identifier(identifier) => self
+                .variable(identifier.name, identifier.span)
+                .and_then(|variable| self.circuit_of_type(&variable.type_)),
+            Expression::Access(AccessExpression::Member(access)) => {
+                let (_, type_) = member(self.circuit_of(&access.inner)?, access.name.name)?;
+                self.circuit_of_type(type_)
+            }
+            Expression::Call(call) => match &*call.function {
+                Expression::Identifier(identifier) => self
+                    .symbol_table
+                    .lookup_fn_symbol(identifier.name)
+                    .and_then(|function| self.circuit_of_type(&function.output)),
+                _ => None,
+            },
+            Expression::Circuit(circuit) => self.symbol_table.lookup_circuit(circuit.name.name),
+            Expression::Ternary(ternary) => self.circuit_of(&ternary.if_true),
+            _ => None,
+        }
+    }
+
+    /// Records that `identifier` refers to the definition at `definition`.
+    fn refer(&mut self, identifier: &Identifier, hover: String, definition: Span, circuit: Option<&Circuit>) {
+        if let (Some(range), Some(definition)) = (
+            self.line_index.range(identifier.span),
+            self.line_index.range(definition),
+        ) {
+            self.references.push(Reference {
+                name: identifier.to_string(),
+                range,
+                hover,
+                definition,
+                circuit: circuit.map(|circuit| circuit.name().to_string()),
+            });
+        }
+    }
+
+    /// Resolves `identifier` to a variable, a function, or a circuit or record, in that order.
+    fn resolve(&mut self, identifier: &Identifier) {
+        if let Some(variable) = self.variable(identifier.name, identifier.span).cloned() {
+            let code = format!("{}: {}", identifier, variable.type_);
+            let circuit = self.circuit_of_type(&variable.type_);
+            self.refer(
+                identifier,
+                hover(&code, &variable.declaration.to_string()),
+                variable.span,
+                circuit,
+            );
+        } else if let Some(function) = self.symbol_table.lookup_fn_symbol(identifier.name) {
+            let code = signature(identifier.name, function);
+            self.refer(identifier, hover(&code, ""), function.span, None);
+        } else if let Some(circuit) = self.symbol_table.lookup_circuit(identifier.name) {
+            self.refer(identifier, hover(&circuit.to_string(), ""), circuit.span, None);
+        }
+    }
+
+    /// Resolves `identifier` to the member of the same name of `circuit`.
+    fn resolve_member(&mut self, identifier: &Identifier, circuit: &Circuit) {
+        if let Some((member, type_)) = member(circuit, identifier.name) {
+            let code = format!("{}: {}", identifier, type_);
+            let kind = if circuit.is_record { "record" } else { "circuit" };
+            let text = format!("member of {} `{}`", kind, circuit.name());
+            let instance = self.circuit_of_type(type_);
+            self.refer(identifier, hover(&code, &text), member.span, instance);
+        }
+    }
+
+    /// Resolves the circuits and records named in `type_`.
+    fn visit_type(&mut self, type_: &Type) {
+        match type_ {
+            Type::Identifier(identifier) => self.resolve(identifier),
+            Type::Tuple(tuple) => tuple.0.iter().for_each(|type_| self.visit_type(type_)),
+            _ => {}
+        }
+    }
+}
+
+/// Returns the member `name` of `circuit`, with its type.
+fn member(circuit: &Circuit, name: Symbol) -> Option<(&Identifier, &Type)> {
+    circuit
+        .members
+        .iter()
+        .map(|CircuitMember::CircuitVariable(member, type_, _)| (member, type_))
+        .find(|(member, _)| member.name == name)
+}
+
+impl<'a> ExpressionVisitor<'a> for Resolver<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::AssociatedFunction(function) => {
+                function
+                    .args
+                    .iter()
+                    .for_each(|arg| self.visit_expression(arg, additional));
+            }
+            AccessExpression::Member(access) => {
+                self.visit_expression(&access.inner, additional);
+                if let Some(circuit) = self.circuit_of(&access.inner) {
+                    self.resolve_member(&access.name, circuit);
+                }
+            }
+            AccessExpression::Tuple(access) => self.visit_expression(&access.tuple, additional),
+            AccessExpression::AssociatedConstant(_) => {}
+        }
+    }
+
+    fn visit_circuit_init(&mut self, input: &'a CircuitExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.resolve(&input.name);
+        let circuit = self.symbol_table.lookup_circuit(input.name.name);
+        for initializer in input.members.iter() {
+            match (&initializer.expression, circuit) {
+                (Some(expression), Some(circuit)) => {
+                    self.resolve_member(&initializer.identifier, circuit);
+                    self.visit_expression(expression, additional);
+                }
+                (Some(expression), None) => self.visit_expression(expression, additional),
+                // `Point { x }` initializes the member `x` with the variable `x`.
+                (None, _) => self.resolve(&initializer.identifier),
+            }
+        }
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.function, additional);
+        input
+            .arguments
+            .iter()
+            .for_each(|arg| self.visit_expression(arg, additional));
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.resolve(input);
+    }
+}
+
+impl<'a> StatementVisitor<'a> for Resolver<'a> {
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.resolve(&input.variable_name);
+        self.visit_type(&input.type_);
+        self.visit_expression(&input.value, &());
+    }
+
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.place, &());
+        self.visit_expression(&input.value, &());
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.resolve(&input.variable);
+        self.visit_type(&input.type_);
+        self.visit_expression(&input.start, &());
+        self.visit_expression(&input.stop, &());
+        self.visit_block(&input.block);
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for Resolver<'a> {
+    fn visit_function(&mut self, input: &'a Function) {
+        self.variables = self
+            .symbol_table
+            .lookup_fn_scope(input.name())
+            .map(|scope| scope.borrow().nested_variables())
+            .unwrap_or_default();
+
+        self.resolve(&input.identifier);
+        for input in input.input.iter() {
+            let variable = input.get_variable();
+            self.resolve(&variable.identifier);
+            self.visit_type(&variable.type_);
+        }
+        self.visit_type(&input.output);
+        self.visit_block(&input.block);
+        self.variables.clear();
+    }
+
+    fn visit_circuit(&mut self, input: &'a Circuit) {
+        self.resolve(&input.identifier);
+        for CircuitMember::CircuitVariable(_, type_, _) in input.members.iter() {
+            self.visit_type(type_);
+        }
+    }
+
+    // Imported programs are not part of the document.
+    fn visit_import(&mut self, _input: &'a Program) {}
+}
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::LineIndex;
+
+use leo_errors::{emitter::Emitter, Backtraced, Label, LeoError, LeoWarning};
+use leo_span::Span;
+
+use serde_json::{json, Value};
+use std::{cell::RefCell, rc::Rc};
+
+/// The LSP severity of errors.
+const ERROR: u8 = 1;
+/// The LSP severity of warnings.
+const WARNING: u8 = 2;
+
+/// An `Emitter` that collects the errors and warnings of a document as LSP diagnostics.
+/// Clones share their diagnostics, so that they can be taken from the emitter given to a `Handler`.
+#[derive(Clone)]
+pub struct DiagnosticsEmitter {
+    /// The URI of the document.
+    uri: String,
+    /// Locates the spans of the diagnostics in the document.
+    line_index: Rc<LineIndex>,
+    /// The diagnostics emitted thus far.
+    diagnostics: Rc<RefCell<Vec<Value>>>,
+    /// Exit code of the last emitted error.
+    last_error_code: Option<i32>,
+}
+
+impl DiagnosticsEmitter {
+    /// Returns an emitter for the diagnostics of the document at `uri`.
+    pub fn new(uri: &str, line_index: Rc<LineIndex>) -> Self {
+        Self {
+            uri: uri.to_string(),
+            line_index,
+            diagnostics: Default::default(),
+            last_error_code: None,
+        }
+    }
+
+    /// Extracts the diagnostics collected thus far.
+    pub fn take(&self) -> Vec<Value> {
+        self.diagnostics.take()
+    }
+
+    /// Collects the diagnostic of a message.
+    /// Spans outside of the document, such as those of imported programs, are shown at its start.
+    fn push(&self, backtraced: &Backtraced, code: String, severity: u8, span: Option<Span>, labels: &[Label]) {
+        let mut message = backtraced.message.clone();
+        if let Some(help) = &backtraced.help {
+            message = format!("{message}\nhelp: {help}");
+        }
+        let related: Vec<Value> = labels
+            .iter()
+            .filter_map(|label| {
+                let range = self.line_index.range(label.span)?;
+                Some(json!({
+                    "location": { "uri": self.uri, "range": range },
+                    "message": label.message,
+                }))
+            })
+            .collect();
+
+        self.diagnostics.borrow_mut().push(json!({
+            "range": span.and_then(|span| self.line_index.range(span)).unwrap_or_default(),
+            "severity": severity,
+            "code": code,
+            "source": "leo",
+            "message": message,
+            "relatedInformation": related,
+        }));
+    }
+}
+
+impl Emitter for DiagnosticsEmitter {
+    fn emit_err(&mut self, err: LeoError) {
+        // `LastErrorCode` only points at an error that has been emitted already.
+        if let Some(backtraced) = err.backtraced() {
+            self.last_error_code = Some(err.exit_code());
+            self.push(backtraced, backtraced.error_code(), ERROR, err.span(), err.labels());
+        }
+    }
+
+    fn last_emitted_err_code(&self) -> Option<i32> {
+        self.last_error_code
+    }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        let backtraced = warning.backtraced();
+        self.push(
+            backtraced,
+            backtraced.warning_code(),
+            WARNING,
+            warning.span(),
+            warning.labels(),
+        );
+    }
+}
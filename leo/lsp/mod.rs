@@ -0,0 +1,293 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A language server for Leo, speaking the Language Server Protocol over the standard input and output.
+//!
+//! Documents are analyzed one at a time, as they are opened and changed. The parser and the type checker
+//! report their errors and warnings as diagnostics, and the symbol table gives the types and definitions
+//! of the identifiers for hover, go-to-definition, and completion.
+
+pub mod analysis;
+pub use analysis::*;
+
+pub mod diagnostics;
+pub use diagnostics::*;
+
+pub mod protocol;
+pub use protocol::*;
+
+use leo_errors::Result;
+
+use indexmap::IndexMap;
+use serde_json::{json, Value};
+use std::{
+    collections::HashMap,
+    io::{BufRead, Write},
+};
+
+/// The LSP completion item kind of functions.
+const FUNCTION_COMPLETION: u8 = 3;
+/// The LSP completion item kind of circuit and record members.
+const FIELD_COMPLETION: u8 = 5;
+/// The LSP completion item kind of core circuits.
+const MODULE_COMPLETION: u8 = 9;
+/// The LSP completion item kind of circuits and records.
+const STRUCT_COMPLETION: u8 = 22;
+
+/// Serves the messages read from `reader`, writing the responses to `writer`, until the client exits.
+pub fn run(mut reader: impl BufRead, writer: impl Write) -> Result<()> {
+    let mut server = Server::new(writer);
+    while let Some(message) = read_message(&mut reader)? {
+        if !server.handle(&message)? {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// An open document.
+struct Document {
+    /// The content of the document.
+    text: String,
+    /// What is known about the document.
+    analysis: Analysis,
+}
+
+/// The state of the language server.
+pub struct Server<W: Write> {
+    /// Where the responses and notifications of the server are written.
+    writer: W,
+    /// The open documents, by URI.
+    documents: HashMap<String, Document>,
+    /// The core circuits, with the names and return types of their functions.
+    core_circuits: IndexMap<String, Vec<(String, String)>>,
+    /// Whether the client has asked the server to shut down.
+    shutting_down: bool,
+}
+
+impl<W: Write> Server<W> {
+    /// Returns a server writing to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            documents: HashMap::new(),
+            core_circuits: core_circuits(),
+            shutting_down: false,
+        }
+    }
+
+    /// Handles the message `message`, and returns whether the server should go on.
+    pub fn handle(&mut self, message: &str) -> Result<bool> {
+        let message: Value = match serde_json::from_str(message) {
+            Ok(message) => message,
+            Err(err) => {
+                self.respond_error(Value::Null, PARSE_ERROR, err.to_string())?;
+                return Ok(true);
+            }
+        };
+
+        let params = &message["params"];
+        match (message["method"].as_str(), message.get("id")) {
+            (Some("exit"), _) => return Ok(false),
+            (Some(_), Some(id)) if self.shutting_down => {
+                self.respond_error(id.clone(), INVALID_REQUEST, "The server is shutting down.".to_string())?
+            }
+            (Some(method), Some(id)) => self.request(id.clone(), method, params)?,
+            (Some(method), None) => self.notification(method, params)?,
+            // Responses to requests of the server, which sends none.
+            (None, _) => {}
+        }
+        Ok(true)
+    }
+
+    /// Handles the request `method`, and responds to it.
+    fn request(&mut self, id: Value, method: &str, params: &Value) -> Result<()> {
+        let result = match method {
+            "initialize" => Some(json!({
+                "capabilities": {
+                    // The whole content of a document is sent on every change.
+                    "textDocumentSync": 1,
+                    "hoverProvider": true,
+                    "definitionProvider": true,
+                    "documentSymbolProvider": true,
+                    "completionProvider": { "triggerCharacters": [".", ":"] },
+                },
+                "serverInfo": { "name": "leo", "version": env!("CARGO_PKG_VERSION") },
+            })),
+            "shutdown" => {
+                self.shutting_down = true;
+                Some(Value::Null)
+            }
+            "textDocument/hover" => self.hover(params),
+            "textDocument/definition" => self.definition(params),
+            "textDocument/documentSymbol" => self.document_symbols(params),
+            "textDocument/completion" => self.completion(params),
+            _ => return self.respond_error(id, METHOD_NOT_FOUND, format!("Unknown method `{}`.", method)),
+        };
+        match result {
+            Some(result) => self.send(json!({ "jsonrpc": "2.0", "id": id, "result": result })),
+            None => self.respond_error(id, INVALID_PARAMS, format!("Invalid parameters for `{}`.", method)),
+        }
+    }
+
+    /// Handles the notification `method`.
+    fn notification(&mut self, method: &str, params: &Value) -> Result<()> {
+        let document = &params["textDocument"];
+        match method {
+            "textDocument/didOpen" => {
+                if let (Some(uri), Some(text)) = (document["uri"].as_str(), document["text"].as_str()) {
+                    self.update(uri, text.to_string())?;
+                }
+            }
+            "textDocument/didChange" => {
+                // The last change holds the whole content of the document.
+                let text = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str());
+                if let (Some(uri), Some(text)) = (document["uri"].as_str(), text) {
+                    self.update(uri, text.to_string())?;
+                }
+            }
+            "textDocument/didClose" => {
+                if let Some(uri) = document["uri"].as_str() {
+                    self.documents.remove(uri);
+                    self.publish_diagnostics(uri, &[])?;
+                }
+            }
+            // Other notifications, such as `initialized`, need nothing from the server.
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Analyzes the new content `text` of the document at `uri`, and publishes its diagnostics.
+    fn update(&mut self, uri: &str, text: String) -> Result<()> {
+        let mut analysis = Analysis::new(uri, &text);
+        if let Some(previous) = self.documents.remove(uri) {
+            analysis.fall_back_to(previous.analysis);
+        }
+        self.publish_diagnostics(uri, &analysis.diagnostics)?;
+        self.documents.insert(uri.to_string(), Document { text, analysis });
+        Ok(())
+    }
+
+    /// Returns the open document and the position given by the parameters of a request.
+    fn document_position<'a>(&'a self, params: &'a Value) -> Option<(&'a str, &'a Document, Position)> {
+        let uri = params["textDocument"]["uri"].as_str()?;
+        let position = serde_json::from_value(params["position"].clone()).ok()?;
+        Some((uri, self.documents.get(uri)?, position))
+    }
+
+    /// Returns what the identifier at the given position refers to.
+    fn hover(&self, params: &Value) -> Option<Value> {
+        let (_, document, position) = self.document_position(params)?;
+        Some(match document.analysis.reference_at(position) {
+            Some(reference) => json!({
+                "contents": { "kind": "markdown", "value": reference.hover },
+                "range": reference.range,
+            }),
+            None => Value::Null,
+        })
+    }
+
+    /// Returns the location of the definition that the identifier at the given position refers to.
+    fn definition(&self, params: &Value) -> Option<Value> {
+        let (uri, document, position) = self.document_position(params)?;
+        Some(match document.analysis.reference_at(position) {
+            Some(reference) => json!({ "uri": uri, "range": reference.definition }),
+            None => Value::Null,
+        })
+    }
+
+    /// Returns the functions, circuits and records of a document.
+    fn document_symbols(&self, params: &Value) -> Option<Value> {
+        let document = self.documents.get(params["textDocument"]["uri"].as_str()?)?;
+        Some(json!(document.analysis.symbols))
+    }
+
+    /// Returns the completions at the given position.
+    /// After `.` these are the members of a circuit or record, after `::` the functions of a core circuit,
+    /// and otherwise the core circuits, and the circuits, records and functions of the document.
+    fn completion(&self, params: &Value) -> Option<Value> {
+        let (_, document, position) = self.document_position(params)?;
+        let analysis = &document.analysis;
+
+        // The part of the identifier being completed is left for the client to match.
+        let prefix = line_prefix(&document.text, position);
+        let prefix = prefix.trim_end_matches(|c: char| c.is_ascii_alphanumeric() || c == '_');
+        let item = |label: &str, kind: u8, detail: &str| json!({ "label": label, "kind": kind, "detail": detail });
+
+        let items: Vec<Value> = if let Some(receiver) = prefix.strip_suffix("::") {
+            let circuit = receiver.rsplit(|c: char| !c.is_ascii_alphanumeric()).next()?;
+            self.core_circuits
+                .get(circuit)
+                .into_iter()
+                .flatten()
+                .map(|(function, return_type)| item(function, FUNCTION_COMPLETION, &format!("-> {}", return_type)))
+                .collect()
+        } else if let Some(receiver) = prefix.strip_suffix('.') {
+            let name = receiver
+                .rsplit(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .next()?;
+            let end = Position {
+                line: position.line,
+                character: receiver.encode_utf16().count() as u32,
+            };
+            analysis
+                .circuit_of(name, end)
+                .and_then(|circuit| analysis.circuits.get(circuit))
+                .into_iter()
+                .flatten()
+                .map(|(member, type_)| item(member, FIELD_COMPLETION, type_))
+                .collect()
+        } else {
+            let core = self
+                .core_circuits
+                .keys()
+                .map(|circuit| item(circuit, MODULE_COMPLETION, "core circuit"));
+            let circuits = analysis
+                .circuits
+                .keys()
+                .map(|circuit| item(circuit, STRUCT_COMPLETION, ""));
+            let functions = analysis
+                .functions
+                .iter()
+                .map(|(function, signature)| item(function, FUNCTION_COMPLETION, signature));
+            core.chain(circuits).chain(functions).collect()
+        };
+        Some(json!(items))
+    }
+
+    /// Publishes the diagnostics of the document at `uri`, replacing those published before.
+    fn publish_diagnostics(&mut self, uri: &str, diagnostics: &[Value]) -> Result<()> {
+        self.send(json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": { "uri": uri, "diagnostics": diagnostics },
+        }))
+    }
+
+    /// Responds to the request `id` with an error.
+    fn respond_error(&mut self, id: Value, code: i64, message: String) -> Result<()> {
+        self.send(json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } }))
+    }
+
+    /// Sends `message` to the client.
+    fn send(&mut self, message: Value) -> Result<()> {
+        write_message(&mut self.writer, &message)
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_errors::{CliError, Result};
+use leo_span::{span::BytePos, Span};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::io::{BufRead, Write};
+
+/// The JSON-RPC error code of a message that is not valid JSON.
+pub const PARSE_ERROR: i64 = -32700;
+/// The JSON-RPC error code of a message that is not a valid request.
+pub const INVALID_REQUEST: i64 = -32600;
+/// The JSON-RPC error code of a request for a method the server does not provide.
+pub const METHOD_NOT_FOUND: i64 = -32601;
+/// The JSON-RPC error code of a request with invalid parameters.
+pub const INVALID_PARAMS: i64 = -32602;
+
+/// Reads the content of the next message from `reader`, or `None` at the end of the input.
+pub fn read_message(reader: &mut impl BufRead) -> Result<Option<String>> {
+    let mut length = None;
+    loop {
+        let mut header = String::new();
+        if reader
+            .read_line(&mut header)
+            .map_err(CliError::failed_to_read_lsp_message)?
+            == 0
+        {
+            return Ok(None);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        // Other headers, such as `Content-Type`, are ignored.
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("Content-Length") {
+                length = Some(
+                    value
+                        .trim()
+                        .parse::<usize>()
+                        .map_err(|_| CliError::invalid_lsp_message_header(header))?,
+                );
+            }
+        }
+    }
+
+    let length = length.ok_or_else(|| CliError::invalid_lsp_message_header(""))?;
+    let mut content = vec![0; length];
+    reader
+        .read_exact(&mut content)
+        .map_err(CliError::failed_to_read_lsp_message)?;
+    String::from_utf8(content)
+        .map(Some)
+        .map_err(|err| CliError::failed_to_read_lsp_message(err).into())
+}
+
+/// Writes the message `message` to `writer`.
+pub fn write_message(writer: &mut impl Write, message: &Value) -> Result<()> {
+    let content = message.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{}", content.len(), content)
+        .and_then(|_| writer.flush())
+        .map_err(|err| CliError::failed_to_write_lsp_message(err).into())
+}
+
+/// A position in a document, with lines and characters starting at 0.
+/// Characters are counted in UTF-16 code units, as the protocol requires.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Position {
+    /// The line of the position.
+    pub line: u32,
+    /// The character of the position in its line.
+    pub character: u32,
+}
+
+/// A range in a document, from `start` to just before `end`.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Range {
+    /// The start of the range.
+    pub start: Position,
+    /// The position just past the end of the range.
+    pub end: Position,
+}
+
+impl Range {
+    /// Returns whether `position` is in the range, or just past its end.
+    pub fn contains(&self, position: Position) -> bool {
+        self.start <= position && position <= self.end
+    }
+}
+
+/// Converts the spans of a source file into ranges in the document it was read from.
+pub struct LineIndex {
+    /// The source code text of the file.
+    text: String,
+    /// The position of the file in the source map.
+    start_pos: BytePos,
+    /// The offsets at which the lines of the file start.
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    /// Returns the line index of the source file with the text `text`, starting at `start_pos` in the source map.
+    pub fn new(text: &str, start_pos: BytePos) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(offset, _)| offset + 1))
+            .collect();
+        Self {
+            text: text.to_string(),
+            start_pos,
+            line_starts,
+        }
+    }
+
+    /// Returns the position of the byte offset `offset` in the file.
+    pub fn position(&self, offset: usize) -> Position {
+        let offset = offset.min(self.text.len());
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(line) => line - 1,
+        };
+        let start = self.line_starts[line];
+        Position {
+            line: line as u32,
+            character: self.text[start..offset].encode_utf16().count() as u32,
+        }
+    }
+
+    /// Returns the range of `span`, if it is a span of the file.
+    pub fn range(&self, span: Span) -> Option<Range> {
+        let end = self.start_pos.0 as usize + self.text.len();
+        if span.is_dummy() || span.lo < self.start_pos || span.hi.0 as usize > end {
+            return None;
+        }
+        Some(Range {
+            start: self.position((span.lo.0 - self.start_pos.0) as usize),
+            end: self.position((span.hi.0 - self.start_pos.0) as usize),
+        })
+    }
+}
+
+/// Returns the text of the line of `position` in `text`, up to `position`.
+pub fn line_prefix(text: &str, position: Position) -> String {
+    let line = text.split('\n').nth(position.line as usize).unwrap_or_default();
+    let mut units = 0;
+    line.chars()
+        .take_while(|c| {
+            units += c.len_utf16() as u32;
+            units <= position.character
+        })
+        .collect()
+}
@@ -17,14 +17,16 @@
 pub mod commands;
 pub mod context;
 pub mod logger;
+pub mod lsp;
 pub mod updater;
 
 use crate::commands::*;
 use crate::context::*;
-use leo_errors::Result;
+use leo_errors::{emitter::LintLevel, Result};
 use leo_span::symbol::create_session_if_not_set_then;
 
 use clap::StructOpt;
+use std::ffi::OsString;
 use std::path::PathBuf;
 use std::process::exit;
 
@@ -51,17 +53,109 @@ pub struct CLI {
         parse(from_os_str)
     )]
     path: Option<PathBuf>,
+
+    #[structopt(
+        long,
+        global = true,
+        arg_enum,
+        default_value = "human",
+        help = "The format in which errors and warnings are reported"
+    )]
+    message_format: MessageFormat,
+
+    #[structopt(
+        short = 'A',
+        long,
+        global = true,
+        help = "Silence a warning code, or `warnings` for all warnings"
+    )]
+    allow: Vec<String>,
+
+    #[structopt(
+        short = 'W',
+        long,
+        global = true,
+        help = "Report a warning code, or `warnings` for all warnings, as a warning"
+    )]
+    warn: Vec<String>,
+
+    #[structopt(
+        short = 'D',
+        long,
+        global = true,
+        help = "Report a warning code, or `warnings` for all warnings, as an error"
+    )]
+    deny: Vec<String>,
+
+    /// The lint levels of `--allow`, `--warn` and `--deny`, in the order they were given, see `lint_levels`.
+    #[structopt(skip)]
+    lint_levels: Vec<(String, LintLevel)>,
+}
+
+impl CLI {
+    /// Parses the command line `args`, including the name of the program.
+    pub fn try_parse_args<I, T>(args: I) -> Result<Self, clap::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        let args: Vec<OsString> = args.into_iter().map(Into::into).collect();
+        let mut cli = Self::try_parse_from(&args)?;
+        cli.lint_levels = lint_levels(&args);
+        Ok(cli)
+    }
+}
+
+/// Returns the lint levels of the `-A`, `-W` and `-D` flags in the command line `args`, in the order they were given,
+/// so that a later flag overrides an earlier one, whichever of them it is.
+/// clap keeps the values of a global flag at one level of subcommands only, so the flags are read from `args` again.
+fn lint_levels(args: &[OsString]) -> Vec<(String, LintLevel)> {
+    let mut levels = Vec::new();
+    let mut args = args.iter().skip(1).map(|arg| arg.to_string_lossy());
+    while let Some(arg) = args.next() {
+        let (level, value) = if arg == "--" {
+            break;
+        } else if let Some(long) = arg.strip_prefix("--") {
+            let (name, value) = match long.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (long, None),
+            };
+            match name {
+                "allow" => (LintLevel::Allow, value),
+                "warn" => (LintLevel::Warn, value),
+                "deny" => (LintLevel::Deny, value),
+                _ => continue,
+            }
+        } else if let Some(short) = arg.strip_prefix('-') {
+            // Short flags may follow `-d` and `-q`, e.g. `-qD warnings`, and may be followed by their value.
+            let short = short.trim_start_matches(|c| c == 'd' || c == 'q');
+            let value = short.get(1..).map(|value| value.trim_start_matches('='));
+            let value = value.filter(|value| !value.is_empty()).map(str::to_string);
+            match short.chars().next() {
+                Some('A') => (LintLevel::Allow, value),
+                Some('W') => (LintLevel::Warn, value),
+                Some('D') => (LintLevel::Deny, value),
+                _ => continue,
+            }
+        } else {
+            continue;
+        };
+
+        if let Some(lint) = value.or_else(|| args.next().map(|arg| arg.to_string())) {
+            levels.push((lint, level));
+        }
+    }
+    levels
 }
 
 ///Leo compiler and package manager
 #[derive(StructOpt, Debug)]
 enum Commands {
-    // #[structopt(about = "Create a new Leo package in an existing directory")]
-    // Init {
-    //     #[structopt(flatten)]
-    //     command: Init,
-    // },
-    //
+    #[structopt(about = "Create a new Leo package in an existing directory")]
+    Init {
+        #[structopt(flatten)]
+        command: Init,
+    },
     #[structopt(about = "Create a new Leo package in a new directory")]
     New {
         #[structopt(flatten)]
@@ -72,6 +166,11 @@ enum Commands {
         #[structopt(flatten)]
         command: Build,
     },
+    #[structopt(about = "Check the current package for errors without building it")]
+    Check {
+        #[structopt(flatten)]
+        command: Check,
+    },
     #[structopt(about = "Clean the output directory")]
     Clean {
         #[structopt(flatten)]
@@ -82,6 +181,31 @@ enum Commands {
         #[structopt(flatten)]
         command: Run,
     },
+    #[structopt(about = "Run the tests of the current package")]
+    Test {
+        #[structopt(flatten)]
+        command: Test,
+    },
+    #[structopt(about = "Explain an error or warning code")]
+    Explain {
+        #[structopt(flatten)]
+        command: Explain,
+    },
+    #[structopt(about = "Generate the documentation of the current package from its doc comments")]
+    Doc {
+        #[structopt(flatten)]
+        command: Doc,
+    },
+    #[structopt(about = "Format the Leo files of the current package")]
+    Fmt {
+        #[structopt(flatten)]
+        command: Fmt,
+    },
+    #[structopt(about = "Run the Leo language server over the standard input and output")]
+    Lsp {
+        #[structopt(flatten)]
+        command: Lsp,
+    },
 }
 
 fn set_panic_hook() {
@@ -113,19 +237,21 @@ fn set_panic_hook() {
     });
 }
 
-pub fn handle_error<T>(res: Result<T>) -> T {
+pub fn handle_error<T>(res: Result<T>, message_format: MessageFormat) -> T {
     match res {
         Ok(t) => t,
         Err(err) => {
-            eprintln!("{}", err);
-            exit(err.exit_code());
+            let code = err.exit_code();
+            message_format.emitter().emit_err(err);
+            exit(code);
         }
     }
 }
 
 /// Run command with custom build arguments.
 pub fn run_with_args(cli: CLI) -> Result<()> {
-    if !cli.quiet {
+    // The language server speaks its protocol on the standard output, where the logger writes.
+    if !cli.quiet && !matches!(cli.command, Commands::Lsp { .. }) {
         // Init logger with optional debug flag.
         logger::init_logger(
             "leo",
@@ -138,18 +264,62 @@ pub fn run_with_args(cli: CLI) -> Result<()> {
 
     // Get custom root folder and create context for it.
     // If not specified, default context will be created in cwd.
-    let context = handle_error(Context::new(cli.path));
+    let context = handle_error(
+        Context::new(cli.path, cli.message_format, cli.lint_levels),
+        cli.message_format,
+    );
 
     match cli.command {
-        // CommandOpts::Init { command } => command.try_execute(context),
+        Commands::Init { command } => command.try_execute(context),
         Commands::New { command } => command.try_execute(context),
         Commands::Build { command } => command.try_execute(context),
+        Commands::Check { command } => command.try_execute(context),
         Commands::Clean { command } => command.try_execute(context),
         Commands::Run { command } => command.try_execute(context),
+        Commands::Test { command } => command.try_execute(context),
+        Commands::Explain { command } => command.try_execute(context),
+        Commands::Doc { command } => command.try_execute(context),
+        Commands::Fmt { command } => command.try_execute(context),
+        Commands::Lsp { command } => command.try_execute(context),
     }
 }
 
 fn main() {
     set_panic_hook();
-    create_session_if_not_set_then(|_| handle_error(run_with_args(CLI::parse())));
+    let cli = CLI::try_parse_args(std::env::args_os()).unwrap_or_else(|err| err.exit());
+    let message_format = cli.message_format;
+    create_session_if_not_set_then(|_| handle_error(run_with_args(cli), message_format));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lint_levels_in_argv_order() {
+        let cli = CLI::try_parse_args([
+            "leo",
+            "-D",
+            "warnings",
+            "check",
+            "-A",
+            "WLIN0370000",
+            "-D",
+            "WLIN0370001",
+        ]);
+        assert_eq!(
+            cli.unwrap().lint_levels,
+            [
+                ("warnings".to_string(), LintLevel::Deny),
+                ("WLIN0370000".to_string(), LintLevel::Allow),
+                ("WLIN0370001".to_string(), LintLevel::Deny),
+            ]
+        );
+
+        let cli = CLI::try_parse_args(["leo", "check", "--allow=warnings", "-qWwarnings"]);
+        assert_eq!(
+            cli.unwrap().lint_levels.last(),
+            Some(&("warnings".to_string(), LintLevel::Warn))
+        );
+    }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::parse_file_paths;
+use crate::{parse_file_paths, root::MANIFEST_FILE_NAME};
 use leo_errors::{PackageError, Result};
 
 use std::path::PathBuf;
@@ -82,4 +82,23 @@ impl ImportsDirectory {
 
         Ok(file_paths)
     }
+
+    /// Returns a list of the packages in the imports directory.
+    pub fn packages(path: &Path) -> Result<Vec<PathBuf>> {
+        let mut path = Cow::from(path);
+        if path.is_dir() && !path.ends_with(IMPORTS_DIRECTORY_NAME) {
+            path.to_mut().push(IMPORTS_DIRECTORY_NAME);
+        }
+
+        let directory = fs::read_dir(&path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
+        let mut package_paths = Vec::new();
+        for entry in directory {
+            let package_path = entry.map_err(PackageError::failed_to_get_leo_file_entry)?.path();
+            if package_path.join(MANIFEST_FILE_NAME).exists() {
+                package_paths.push(package_path);
+            }
+        }
+
+        Ok(package_paths)
+    }
 }
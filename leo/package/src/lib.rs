@@ -17,12 +17,14 @@
 #![doc = include_str!("../README.md")]
 
 pub mod build;
+pub mod doc;
 pub mod imports;
 pub mod inputs;
 pub mod outputs;
 pub mod package;
 pub mod root;
 pub mod source;
+pub mod tests;
 
 use leo_errors::{PackageError, Result};
 
@@ -37,7 +39,10 @@ pub(crate) fn parse_file_paths(directory: ReadDir, file_paths: &mut Vec<PathBuf>
         let file_path = file_entry.path();
 
         // Verify that the entry is structured as a valid file or directory
-        if file_path.is_dir() {
+        // Nested packages, such as imported libraries, are not part of the directory.
+        if file_path.is_dir() && file_path.join(root::MANIFEST_FILE_NAME).exists() {
+            continue;
+        } else if file_path.is_dir() {
             let directory =
                 fs::read_dir(&file_path).map_err(|err| PackageError::failed_to_read_file(file_path.display(), err))?;
 
@@ -16,16 +16,73 @@
 
 use crate::{
     inputs::{InputFile, InputsDirectory},
-    root::Gitignore,
+    root::{Gitignore, MANIFEST_FILE_NAME},
     source::{MainFile, SourceDirectory},
 };
 
-use leo_errors::{PackageError, Result};
+use leo_errors::{emitter::LintLevel, PackageError, Result};
 
 use crate::build::BuildDirectory;
-use serde::Deserialize;
+use indexmap::IndexMap;
+use serde::{de::DeserializeOwned, Deserialize};
 use std::path::Path;
 
+/// The kind of a package, declared by the optional `"kind"` field of its manifest.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PackageKind {
+    /// A package compiled into an Aleo program.
+    Program,
+    /// A package of circuits, records and helper functions, included by the programs importing it.
+    Library,
+}
+
+impl Default for PackageKind {
+    fn default() -> Self {
+        Self::Program
+    }
+}
+
+impl PackageKind {
+    /// Reads the package kind from the manifest of the package at the given path.
+    pub fn read_from(path: &Path) -> Result<Self> {
+        #[derive(Deserialize)]
+        struct Manifest {
+            #[serde(default)]
+            kind: PackageKind,
+        }
+
+        Ok(read_manifest::<Manifest>(path)?.kind)
+    }
+}
+
+/// The lint levels of a package, declared by the optional `"lints"` field of its manifest.
+/// It maps warning codes, or `warnings` for every warning, to `allow`, `warn` or `deny`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct Lints(pub IndexMap<String, LintLevel>);
+
+impl Lints {
+    /// Reads the lint levels from the manifest of the package at the given path.
+    pub fn read_from(path: &Path) -> Result<Self> {
+        #[derive(Deserialize)]
+        struct Manifest {
+            #[serde(default)]
+            lints: Lints,
+        }
+
+        Ok(read_manifest::<Manifest>(path)?.lints)
+    }
+}
+
+/// Reads the fields of type `T` from the manifest of the package at the given path.
+fn read_manifest<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let manifest_path = path.join(MANIFEST_FILE_NAME);
+    let manifest_string = std::fs::read_to_string(&manifest_path)
+        .map_err(|err| PackageError::failed_to_read_file(manifest_path.display(), err))?;
+
+    Ok(serde_json::from_str(&manifest_string).map_err(PackageError::failed_to_open_manifest)?)
+}
+
 #[derive(Deserialize)]
 pub struct Package {
     pub name: String,
@@ -130,6 +187,12 @@ impl Package {
             result = false;
         }
 
+        // Check if the manifest file already exists.
+        if path.join(MANIFEST_FILE_NAME).exists() {
+            existing_files.push(MANIFEST_FILE_NAME.to_string());
+            result = false;
+        }
+
         if !existing_files.is_empty() {
             tracing::error!("File(s) {:?} already exist", existing_files);
         }
@@ -16,3 +16,5 @@
 
 pub mod gitignore;
 pub use self::gitignore::*;
+
+pub static MANIFEST_FILE_NAME: &str = "program.json";
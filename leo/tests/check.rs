@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::{Check, Command};
+use crate::context::{Context, MessageFormat};
+
+use leo_errors::{LeoError, Result};
+use leo_span::symbol::create_session_if_not_set_then;
+
+use std::fs;
+use std::path::PathBuf;
+
+/// Creates the package `name` in a fresh temporary directory, with the given files in `src/` and `imports/`.
+fn package(name: &str, sources: &[(&str, &str)], imports: &[(&str, &str)]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("leo-check-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    for (directory, files) in [("src", sources), ("imports", imports)] {
+        fs::create_dir_all(path.join(directory)).unwrap();
+        for (file_name, program) in files {
+            fs::write(path.join(directory).join(file_name), program).unwrap();
+        }
+    }
+    let manifest = format!(
+        "{{\n    \"program\": \"{}.aleo\",\n    \"version\": \"0.0.0\",\n    \"description\": \"\",\n    \"license\": \"MIT\"\n}}\n",
+        name
+    );
+    fs::write(path.join("program.json"), manifest).unwrap();
+    path
+}
+
+/// Runs `leo check` on the package at `path`.
+fn check(path: PathBuf) -> Result<()> {
+    let context = Context::new(Some(path), MessageFormat::Human, Vec::new())?;
+    create_session_if_not_set_then(|_| Check {}.apply(context, ()))
+}
+
+#[test]
+pub fn check_after_unparsed_import() {
+    let path = package(
+        "unparsed",
+        &[
+            ("main.leo", "function main(a: u8) -> u8 {\n    return true;\n}\n"),
+            (
+                "other.leo",
+                "import broken.leo;\n\nfunction other(a: u8) -> u8 {\n    return a;\n}\n",
+            ),
+        ],
+        &[("broken.leo", "function broken(a: u8) -> u8 {\n    return a ~ a;\n}\n")],
+    );
+
+    // The type error of `main.leo` is reported after the syntax error of `broken.leo`,
+    // and `other.leo`, which imports it, is left out.
+    match check(path) {
+        Err(LeoError::LastErrorCode(code)) => assert_eq!(code / 1000, 372, "expected a type checker error"),
+        result => panic!("expected a type checker error, found {:?}", result),
+    }
+}
+
+#[test]
+pub fn check_unknown_lint() {
+    let path = package(
+        "lints",
+        &[("main.leo", "function main(a: u8) -> u8 {\n    return a;\n}\n")],
+        &[],
+    );
+    let manifest = fs::read_to_string(path.join("program.json")).unwrap();
+    let manifest = manifest.replace(
+        "\"license\"",
+        "\"lints\": { \"unused_variable\": \"allow\" },\n    \"license\"",
+    );
+    fs::write(path.join("program.json"), manifest).unwrap();
+
+    // The lints of the manifest are checked like those of the command line.
+    let err = check(path).unwrap_err();
+    assert!(err.to_string().contains("Unknown lint `unused_variable`."));
+}
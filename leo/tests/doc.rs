@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::{doc::DocFormat, Command, Doc};
+use crate::context::{Context, MessageFormat};
+
+use leo_errors::Result;
+use leo_span::symbol::create_session_if_not_set_then;
+
+use std::fs;
+use std::path::PathBuf;
+
+/// Creates the package `name` in a fresh temporary directory, with `program` as its main file.
+fn package(name: &str, program: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("leo-doc-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    fs::create_dir_all(path.join("src")).unwrap();
+    let manifest = format!(
+        "{{\n    \"program\": \"{}.aleo\",\n    \"version\": \"0.0.0\",\n    \"description\": \"\",\n    \"license\": \"MIT\"\n}}\n",
+        name
+    );
+    fs::write(path.join("program.json"), manifest).unwrap();
+    fs::write(path.join("src").join("main.leo"), program).unwrap();
+    path
+}
+
+/// Runs `leo doc` on the package at `path`.
+fn doc(path: PathBuf) -> Result<()> {
+    let context = Context::new(Some(path), MessageFormat::Human, Vec::new())?;
+    let command = Doc {
+        format: DocFormat::Markdown,
+    };
+    create_session_if_not_set_then(|_| command.apply(context, ()))
+}
+
+#[test]
+pub fn doc_package() {
+    let path = package(
+        "documented",
+        "/// Adds two numbers.\nfunction add(a: u8, b: u8) -> u8 {\n    return a + b;\n}\n",
+    );
+    assert!(doc(path.clone()).is_ok());
+    let page = fs::read_to_string(path.join("doc").join("main.md")).unwrap();
+    assert!(page.contains("Adds two numbers."));
+}
+
+#[test]
+pub fn doc_syntax_error() {
+    let path = package(
+        "malformed",
+        "/// Adds two numbers.\nfunction add(a: u8, b: u8) -> u8 {\n    return a + ;\n}\n",
+    );
+    assert!(doc(path.clone()).is_err());
+    assert!(!path.join("doc").exists());
+}
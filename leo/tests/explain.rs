@@ -0,0 +1,39 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::explanations::Explanation;
+
+use leo_errors::MessageName;
+
+#[test]
+pub fn explanations() {
+    // Guides are found by the codes of their messages.
+    let unexpected_eof = Explanation::find("EPAR0370003").unwrap();
+    assert_eq!(
+        (unexpected_eof.kind, unexpected_eof.name),
+        ("ParserError", "unexpected_eof")
+    );
+    assert!(Explanation::exists("WLIN0370000"));
+
+    // The guides of messages that are no longer reported are found by their name.
+    assert!(Explanation::find_by_name("context_annotation").is_some());
+
+    // Messages without a guide are explained by a stub.
+    let message = MessageName::from_code("ECMP0376000").unwrap();
+    assert!(!Explanation::exists("ECMP0376000"));
+    let stub = Explanation::stub("ecmp0376000", message);
+    assert!(stub.starts_with(&format!("# `ECMP0376000`: `{}`", message)));
+}
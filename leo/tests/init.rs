@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::{Command, Init};
+use crate::context::{Context, MessageFormat};
+
+use leo_errors::Result;
+use leo_span::symbol::create_session_if_not_set_then;
+
+use std::fs;
+use std::path::PathBuf;
+
+/// Returns the empty directory `name` in a fresh temporary directory.
+fn directory(name: &str) -> PathBuf {
+    let parent = std::env::temp_dir().join(format!("leo-init-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&parent);
+    let path = parent.join(name);
+    fs::create_dir_all(&path).unwrap();
+    path
+}
+
+/// Runs `leo init` in the directory at `path`.
+fn init(path: PathBuf) -> Result<()> {
+    let context = Context::new(Some(path), MessageFormat::Human, Vec::new())?;
+    create_session_if_not_set_then(|_| Init {}.apply(context, ()))
+}
+
+#[test]
+pub fn init_package() {
+    let path = directory("adopted");
+    init(path.clone()).unwrap();
+    assert!(path.join("program.json").exists());
+    assert!(path.join("src").join("main.leo").exists());
+
+    // An initialized package is not initialized again.
+    let err = init(path).unwrap_err();
+    assert!(err.to_string().contains("it would overwrite existing files"));
+}
+
+#[test]
+pub fn init_invalid_program_name() {
+    // Package names may contain dashes, but Aleo program names may not.
+    let path = directory("with-dash");
+    let err = init(path.clone()).unwrap_err();
+    assert!(err.to_string().contains("is not a valid Aleo program name"));
+    assert_eq!(fs::read_dir(&path).unwrap().count(), 0);
+}
@@ -0,0 +1,241 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::lsp::{self, read_message, write_message};
+
+use serde_json::{json, Value};
+
+const URI: &str = "file:///point/src/main.leo";
+
+const PROGRAM: &str = "circuit Point {
+    x: u32,
+    y: u32,
+}
+
+function add(a: u32, b: u32) -> u32 {
+    let p: Point = Point { x: a, y: b };
+    return p.x + p.y;
+}
+
+function main(a: u32) -> u32 {
+    return add(a, 1u32);
+}
+";
+
+/// Runs a session of the language server on the messages `messages`, and returns the messages it sends.
+fn session(messages: &[Value]) -> Vec<Value> {
+    let mut input = Vec::new();
+    for message in messages {
+        write_message(&mut input, message).unwrap();
+    }
+    let mut output = Vec::new();
+    lsp::run(input.as_slice(), &mut output).unwrap();
+
+    let mut output = output.as_slice();
+    let mut sent = Vec::new();
+    while let Some(message) = read_message(&mut output).unwrap() {
+        sent.push(serde_json::from_str(&message).unwrap());
+    }
+    sent
+}
+
+fn request(id: u32, method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+}
+
+fn notification(method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "method": method, "params": params })
+}
+
+fn open(text: &str) -> Value {
+    notification(
+        "textDocument/didOpen",
+        json!({ "textDocument": { "uri": URI, "languageId": "leo", "version": 1, "text": text } }),
+    )
+}
+
+fn change(text: &str) -> Value {
+    notification(
+        "textDocument/didChange",
+        json!({ "textDocument": { "uri": URI, "version": 2 }, "contentChanges": [{ "text": text }] }),
+    )
+}
+
+/// Returns the position of the `nth` occurrence of `needle` in `text`, plus `offset` characters.
+fn position(text: &str, needle: &str, nth: usize, offset: usize) -> Value {
+    let index = text.match_indices(needle).nth(nth).unwrap().0 + offset;
+    let line = text[..index].matches('\n').count();
+    let character = index - text[..index].rfind('\n').map_or(0, |newline| newline + 1);
+    json!({ "line": line, "character": character })
+}
+
+fn at(id: u32, method: &str, position: Value) -> Value {
+    request(
+        id,
+        method,
+        json!({ "textDocument": { "uri": URI }, "position": position }),
+    )
+}
+
+/// Returns the result of the request `id` among the messages `sent`.
+fn result(sent: &[Value], id: u32) -> &Value {
+    &sent.iter().find(|message| message["id"] == id).unwrap()["result"]
+}
+
+/// Returns the diagnostics published among the messages `sent`, in order.
+fn diagnostics(sent: &[Value]) -> Vec<&Vec<Value>> {
+    sent.iter()
+        .filter(|message| message["method"] == "textDocument/publishDiagnostics")
+        .map(|message| message["params"]["diagnostics"].as_array().unwrap())
+        .collect()
+}
+
+#[test]
+fn lsp_initialize_and_shutdown() {
+    let sent = session(&[
+        request(1, "initialize", json!({ "capabilities": {} })),
+        notification("initialized", json!({})),
+        request(2, "textDocument/formatting", json!({})),
+        request(3, "shutdown", Value::Null),
+        request(4, "textDocument/hover", json!({})),
+        notification("exit", Value::Null),
+        request(5, "shutdown", Value::Null),
+    ]);
+
+    assert_eq!(sent.len(), 4);
+    let capabilities = &result(&sent, 1)["capabilities"];
+    assert_eq!(capabilities["textDocumentSync"], 1);
+    assert_eq!(capabilities["hoverProvider"], true);
+    assert_eq!(sent[1]["error"]["code"], -32601);
+    assert_eq!(sent[2]["result"], Value::Null);
+    assert_eq!(sent[3]["error"]["code"], -32600);
+}
+
+#[test]
+fn lsp_publishes_diagnostics() {
+    let type_error = PROGRAM.replace("return add(a, 1u32);", "return add(a, 1u8);");
+    let syntax_error = PROGRAM.replace("return p.x + p.y;", "return p.x +;");
+    let sent = session(&[
+        open(&type_error),
+        change(&syntax_error),
+        change(PROGRAM),
+        notification("textDocument/didClose", json!({ "textDocument": { "uri": URI } })),
+    ]);
+
+    let published = diagnostics(&sent);
+    assert_eq!(published.len(), 4);
+
+    let error = &published[0][0];
+    assert_eq!(error["severity"], 1);
+    assert!(error["code"].as_str().unwrap().starts_with("ETYC"));
+    assert_eq!(error["range"]["start"], position(&type_error, "1u8", 0, 0));
+
+    let error = &published[1][0];
+    assert!(error["code"].as_str().unwrap().starts_with("EPAR"));
+    assert_eq!(error["range"]["start"]["line"], 7);
+
+    assert!(published[2].is_empty());
+    assert!(published[3].is_empty());
+}
+
+#[test]
+fn lsp_hover_and_definition() {
+    let sent = session(&[
+        open(PROGRAM),
+        at(1, "textDocument/hover", position(PROGRAM, "p.x", 0, 0)),
+        at(2, "textDocument/definition", position(PROGRAM, "p.x", 0, 0)),
+        at(3, "textDocument/hover", position(PROGRAM, "p.y", 0, 2)),
+        at(4, "textDocument/definition", position(PROGRAM, "p.y", 0, 2)),
+        at(5, "textDocument/hover", position(PROGRAM, "add(a", 0, 1)),
+        at(6, "textDocument/definition", position(PROGRAM, "Point {", 1, 0)),
+        at(7, "textDocument/hover", position(PROGRAM, "1u32", 0, 0)),
+    ]);
+
+    let hover = result(&sent, 1)["contents"]["value"].as_str().unwrap();
+    assert!(hover.contains("p: circuit Point"), "{}", hover);
+    assert!(hover.contains("mut var"), "{}", hover);
+    assert_eq!(result(&sent, 2)["uri"], URI);
+    assert_eq!(result(&sent, 2)["range"]["start"], position(PROGRAM, "let p", 0, 0));
+
+    let hover = result(&sent, 3)["contents"]["value"].as_str().unwrap();
+    assert!(hover.contains("y: u32"), "{}", hover);
+    assert!(hover.contains("member of circuit `Point`"), "{}", hover);
+    assert_eq!(result(&sent, 4)["range"]["start"], position(PROGRAM, "y: u32", 0, 0));
+
+    let hover = result(&sent, 5)["contents"]["value"].as_str().unwrap();
+    assert!(
+        hover.contains("function add(") && hover.contains(") -> u32"),
+        "{}",
+        hover
+    );
+
+    assert_eq!(
+        result(&sent, 6)["range"]["start"],
+        position(PROGRAM, "circuit Point", 0, 0)
+    );
+    assert_eq!(result(&sent, 7), &Value::Null);
+}
+
+#[test]
+fn lsp_document_symbols() {
+    let sent = session(&[
+        open(PROGRAM),
+        request(
+            1,
+            "textDocument/documentSymbol",
+            json!({ "textDocument": { "uri": URI } }),
+        ),
+    ]);
+
+    let symbols = result(&sent, 1).as_array().unwrap();
+    let names: Vec<&Value> = symbols.iter().map(|symbol| &symbol["name"]).collect();
+    assert_eq!(names, ["Point", "add", "main"]);
+    assert_eq!(symbols[0]["kind"], 23);
+    assert_eq!(symbols[0]["detail"], "circuit");
+    assert_eq!(symbols[0]["children"][1]["name"], "y");
+    assert_eq!(symbols[1]["kind"], 12);
+    assert_eq!(symbols[1]["selectionRange"]["start"], position(PROGRAM, "add", 0, 0));
+}
+
+#[test]
+fn lsp_completion() {
+    // The member access is being typed, so the document does not parse.
+    let member = PROGRAM.replace("return p.x + p.y;", "return p.x + p.;");
+    let core = PROGRAM.replace("return add(a, 1u32);", "return BHP256::");
+    let sent = session(&[
+        open(PROGRAM),
+        change(&member),
+        at(1, "textDocument/completion", position(&member, "p.;", 0, 2)),
+        change(&core),
+        at(2, "textDocument/completion", position(&core, "::", 0, 2)),
+        at(3, "textDocument/completion", position(&core, "return p", 0, 7)),
+    ]);
+
+    let labels = |id| -> Vec<Value> {
+        result(&sent, id)
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| item["label"].clone())
+            .collect()
+    };
+    assert_eq!(labels(1), ["x", "y"]);
+    assert_eq!(labels(2), ["commit", "hash"]);
+    let labels = labels(3);
+    for label in ["BHP256", "Pedersen64", "Point", "add", "main"] {
+        assert!(labels.contains(&json!(label)), "{:?}", labels);
+    }
+}
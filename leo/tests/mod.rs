@@ -14,6 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+mod check;
+mod doc;
+mod explain;
+mod init;
+mod lsp;
+mod templates;
+mod test;
+
 use leo_errors::Result;
 // use std::path::PathBuf;
 
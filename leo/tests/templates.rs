@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::templates::TEMPLATES;
+use crate::commands::{Build, Command};
+use crate::context::{Context, MessageFormat};
+
+use leo_package::package::Package;
+use leo_span::symbol::create_session_if_not_set_then;
+
+use std::fs;
+
+#[test]
+pub fn build_templates() {
+    for template in TEMPLATES {
+        // Name the package differently from the template, so that every placeholder must be replaced.
+        let name = format!("my{}", template.name);
+        let path = std::env::temp_dir()
+            .join(format!("leo-templates-{}", std::process::id()))
+            .join(&name);
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        let manifest = format!(
+            "{{\n    \"program\": \"{}.aleo\",\n    \"version\": \"0.0.0\",\n    \"description\": \"\",\n    \"license\": \"MIT\"\n}}\n",
+            name
+        );
+        fs::write(path.join("program.json"), manifest).unwrap();
+
+        Package::initialize(&name, &path).unwrap();
+        template.write_to(&name, &path).unwrap();
+
+        let main = fs::read_to_string(path.join("src").join("main.leo")).unwrap();
+        let input = fs::read_to_string(path.join("inputs").join(format!("{}.in", name))).unwrap();
+        assert!(
+            !main.contains("{{") && !input.contains("{{"),
+            "unreplaced placeholder in `{}`",
+            template.name
+        );
+        assert!(input.starts_with(&format!("// The program input for {}/src/main.leo", name)));
+
+        let context = Context::new(Some(path), MessageFormat::Human, Vec::new()).unwrap();
+        let build = Build {
+            compiler_options: Default::default(),
+        };
+        if let Err(err) = create_session_if_not_set_then(|_| build.apply(context, ())) {
+            panic!("the `{}` template does not build: {}", template.name, err);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::commands::{Command, Test};
+use crate::context::{Context, MessageFormat};
+
+use leo_errors::Result;
+use leo_span::symbol::create_session_if_not_set_then;
+
+use std::fs;
+use std::path::PathBuf;
+
+const PROGRAM: &str = "function add(a: u8, b: u8) -> u8 {
+    return a + b;
+}
+
+@test
+function test_sum() -> bool {
+    console.assert(add(1u8, 2u8) == 3u8);
+    return true;
+}
+
+@test
+function test_overflow() -> u8 {
+    return add(255u8, 1u8);
+}
+";
+
+const TESTS: &str = "@test
+function test_loop() -> u8 {
+    let sum: u8 = 0u8;
+    for i: u8 in 0u8..4u8 {
+        sum = sum + i;
+    }
+    console.assert(sum == 6u8);
+    return sum;
+}
+";
+
+const ASSERTIONS: &str = "@test
+function test_assert() -> bool {
+    console.assert(1u8 + 2u8 == 3u8);
+    return true;
+}
+
+@test
+function test_failing_assert() -> bool {
+    console.assert(1u8 + 2u8 == 4u8);
+    return true;
+}
+
+@test
+function test_false() -> bool {
+    return 1u8 + 2u8 != 3u8;
+}
+";
+
+/// Creates the package `name` in a fresh temporary directory, with `PROGRAM` as its main file and `TESTS` in `tests/`.
+fn package(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("leo-test-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    fs::create_dir_all(path.join("src")).unwrap();
+    fs::create_dir_all(path.join("tests")).unwrap();
+    let manifest = format!(
+        "{{\n    \"program\": \"{}.aleo\",\n    \"version\": \"0.0.0\",\n    \"description\": \"\",\n    \"license\": \"MIT\"\n}}\n",
+        name
+    );
+    fs::write(path.join("program.json"), manifest).unwrap();
+    fs::write(path.join("src").join("main.leo"), PROGRAM).unwrap();
+    fs::write(path.join("tests").join("loops.leo"), TESTS).unwrap();
+    path
+}
+
+/// Creates the package `name` with `ASSERTIONS` as its only file, which snarkVM can execute.
+fn assertions_package(name: &str) -> PathBuf {
+    let path = package(name);
+    fs::write(path.join("src").join("main.leo"), ASSERTIONS).unwrap();
+    fs::remove_dir_all(path.join("tests")).unwrap();
+    path
+}
+
+/// Runs `leo test` on the package at `path`, evaluating or proving the tests matching `filter`.
+fn test(path: PathBuf, filter: Option<&str>, prove: bool) -> Result<()> {
+    let context = Context::new(Some(path), MessageFormat::Human, Vec::new())?;
+    let command = Test {
+        filter: filter.map(str::to_string),
+        prove,
+        compiler_options: Default::default(),
+    };
+    create_session_if_not_set_then(|_| command.apply(context, ()))
+}
+
+#[test]
+pub fn test_passing_tests() {
+    assert!(test(package("passing"), Some("test_sum"), false).is_ok());
+    assert!(test(package("directory"), Some("loops::"), false).is_ok());
+}
+
+#[test]
+pub fn test_failing_test() {
+    let err = test(package("failing"), None, false).unwrap_err();
+    assert!(err.to_string().contains("1 test(s) failed"));
+    assert!(test(package("filtered"), Some("overflow"), false).is_err());
+}
+
+#[test]
+pub fn test_failing_assertions() {
+    let err = test(assertions_package("assertions"), None, false).unwrap_err();
+    assert!(err.to_string().contains("2 test(s) failed"));
+    let err = test(assertions_package("prove"), None, true).unwrap_err();
+    assert!(err.to_string().contains("2 test(s) failed"));
+}
@@ -0,0 +1,476 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{program::*, Config};
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// The number of nested conditional statements and loops in a function.
+const MAX_NESTING: usize = 2;
+
+/// Generates a well-typed program from a seed.
+pub fn generate(config: &Config, seed: u64) -> Program {
+    let mut generator = Generator {
+        config,
+        rng: StdRng::seed_from_u64(seed),
+        program: Program::default(),
+        scopes: Vec::new(),
+        next_variable: 0,
+    };
+    generator.program();
+    generator.program
+}
+
+struct Generator<'a> {
+    config: &'a Config,
+    rng: StdRng,
+    program: Program,
+    /// The variables in scope, with their types and whether they can be assigned.
+    scopes: Vec<Vec<(String, Type, bool)>>,
+    next_variable: usize,
+}
+
+impl Generator<'_> {
+    fn program(&mut self) {
+        if self.config.circuits {
+            for i in 0..self.rng.gen_range(0..=self.config.max_circuits) {
+                let members = (0..self.rng.gen_range(1..=3))
+                    .map(|j| (format!("m{}", j), self.member_type()))
+                    .collect();
+                self.program.composites.push(Composite {
+                    name: format!("C{}", i),
+                    is_record: false,
+                    members,
+                });
+            }
+        }
+        if self.config.records && self.rng.gen_bool(0.5) {
+            // snarkVM requires the owner and the gates to be the first members of a record.
+            let mut members = vec![
+                ("owner".to_string(), Type::Address),
+                ("gates".to_string(), Type::Integer(IntegerType::U64)),
+            ];
+            for j in 0..self.rng.gen_range(0..=2) {
+                members.push((format!("m{}", j), self.primitive_type()));
+            }
+            self.program.composites.push(Composite {
+                name: "R0".to_string(),
+                is_record: true,
+                members,
+            });
+        }
+
+        let functions = self.rng.gen_range(1..=self.config.max_functions);
+        for i in 0..functions {
+            let name = if i + 1 == functions {
+                "main".to_string()
+            } else {
+                format!("f{}", i)
+            };
+            let function = self.function(name);
+            self.program.functions.push(function);
+        }
+    }
+
+    fn function(&mut self, name: String) -> Function {
+        self.next_variable = 0;
+        let inputs: Vec<_> = (0..self.rng.gen_range(0..=3))
+            .map(|i| {
+                let type_ = self.any_type();
+                (type_.is_primitive() && self.rng.gen_bool(0.3), format!("a{}", i), type_)
+            })
+            .collect();
+        let output = self.any_type();
+
+        self.scopes = vec![inputs
+            .iter()
+            .map(|(_, name, type_)| (name.clone(), type_.clone(), false))
+            .collect()];
+        let mut body = self.statements(0);
+        body.push(Statement::Return(self.expression(&output, self.config.max_depth)));
+        self.scopes.clear();
+
+        Function {
+            name,
+            inputs,
+            output,
+            body,
+        }
+    }
+
+    fn primitive_type(&mut self) -> Type {
+        match self.rng.gen_range(0..6) {
+            0 => Type::Address,
+            1 => Type::Boolean,
+            2 => Type::Field,
+            3 => Type::Group,
+            4 => Type::Scalar,
+            _ => Type::Integer(*IntegerType::ALL.choose(&mut self.rng).unwrap()),
+        }
+    }
+
+    /// Returns a type for a member of a circuit, which may be a circuit that is already declared.
+    fn member_type(&mut self) -> Type {
+        match self.program.composites.choose(&mut self.rng) {
+            Some(circuit) if self.rng.gen_ratio(1, 4) => Type::Composite(circuit.name.clone()),
+            _ => self.primitive_type(),
+        }
+    }
+
+    fn any_type(&mut self) -> Type {
+        match self.program.composites.choose(&mut self.rng) {
+            Some(composite) if self.rng.gen_ratio(1, 3) => Type::Composite(composite.name.clone()),
+            _ => self.primitive_type(),
+        }
+    }
+
+    fn fresh_variable(&mut self, prefix: &str) -> String {
+        self.next_variable += 1;
+        format!("{}{}", prefix, self.next_variable - 1)
+    }
+
+    fn statements(&mut self, nesting: usize) -> Vec<Statement> {
+        let count = match nesting {
+            0 => self.rng.gen_range(0..=self.config.max_statements),
+            _ => self.rng.gen_range(1..=(self.config.max_statements / 2).max(1)),
+        };
+        (0..count).map(|_| self.statement(nesting)).collect()
+    }
+
+    /// Returns the statements of a block, with `variable` in scope.
+    fn block(&mut self, nesting: usize, variable: Option<(String, Type)>) -> Vec<Statement> {
+        self.scopes
+            .push(variable.into_iter().map(|(name, type_)| (name, type_, false)).collect());
+        let statements = self.statements(nesting);
+        self.scopes.pop();
+        statements
+    }
+
+    fn statement(&mut self, nesting: usize) -> Statement {
+        let depth = self.config.max_depth;
+        match self.rng.gen_range(0..10) {
+            0 if self.config.conditionals && nesting < MAX_NESTING => {
+                let condition = self.expression(&Type::Boolean, depth);
+                let then = self.block(nesting + 1, None);
+                let otherwise = self.block(nesting + 1, None);
+                Statement::Conditional(condition, then, otherwise)
+            }
+            1 if self.config.loops && nesting < MAX_NESTING => {
+                let variable = self.fresh_variable("j");
+                let start = self.rng.gen_range(0..3);
+                let stop = start + self.rng.gen_range(1..=self.config.max_iterations);
+                let body = self.block(nesting + 1, Some((variable.clone(), Type::Integer(IntegerType::U32))));
+                Statement::Iteration(variable, start, stop, body)
+            }
+            2 if self.config.assignments => {
+                let assignable: Vec<_> = self
+                    .scopes
+                    .iter()
+                    .flatten()
+                    .filter(|(_, _, assignable)| *assignable)
+                    .map(|(name, type_, _)| (name.clone(), type_.clone()))
+                    .collect();
+                match assignable.choose(&mut self.rng) {
+                    Some((name, type_)) => Statement::Assign(name.clone(), self.expression(type_, depth)),
+                    None => self.definition(),
+                }
+            }
+            3 => Statement::Assert(self.expression(&Type::Boolean, depth)),
+            _ => self.definition(),
+        }
+    }
+
+    fn definition(&mut self) -> Statement {
+        let type_ = self.any_type();
+        let value = self.expression(&type_, self.config.max_depth);
+        let name = self.fresh_variable("v");
+        self.scopes
+            .last_mut()
+            .unwrap()
+            .push((name.clone(), type_.clone(), true));
+        Statement::Definition(name, type_, value)
+    }
+
+    fn expression(&mut self, type_: &Type, depth: usize) -> Expression {
+        if depth == 0 || self.rng.gen_ratio(1, 4) {
+            return self.leaf(type_);
+        }
+        let depth = depth - 1;
+        match self.rng.gen_range(0..8) {
+            0 => Expression::Ternary(
+                Box::new(self.expression(&Type::Boolean, depth)),
+                Box::new(self.expression(type_, depth)),
+                Box::new(self.expression(type_, depth)),
+            ),
+            1 => match self.member_access(type_, depth) {
+                Some(access) => access,
+                None => self.operation(type_, depth),
+            },
+            2 if self.config.calls => match self.call(type_, depth) {
+                Some(call) => call,
+                None => self.operation(type_, depth),
+            },
+            _ => self.operation(type_, depth),
+        }
+    }
+
+    /// Returns a variable or a literal.
+    fn leaf(&mut self, type_: &Type) -> Expression {
+        let variables: Vec<_> = self
+            .scopes
+            .iter()
+            .flatten()
+            .filter(|(_, variable_type, _)| variable_type == type_)
+            .map(|(name, _, _)| name.clone())
+            .collect();
+        match variables.choose(&mut self.rng) {
+            Some(name) if self.rng.gen_ratio(3, 4) => Expression::Variable(name.clone()),
+            _ => self.literal(type_),
+        }
+    }
+
+    fn literal(&mut self, type_: &Type) -> Expression {
+        let literal = match type_ {
+            Type::Address => ADDRESSES.choose(&mut self.rng).unwrap().to_string(),
+            Type::Boolean => self.rng.gen_bool(0.5).to_string(),
+            Type::Field | Type::Scalar => format!("{}{}", self.rng.gen_range(0..100u32), type_),
+            // The identity is the only group element whose literal does not depend on the curve.
+            Type::Group => "0group".to_string(),
+            Type::Integer(integer_type) => {
+                let max = match integer_type {
+                    IntegerType::I8 => i8::MAX as u128,
+                    IntegerType::I16 => i16::MAX as u128,
+                    IntegerType::I32 => i32::MAX as u128,
+                    IntegerType::I64 => i64::MAX as u128,
+                    IntegerType::I128 => i128::MAX as u128,
+                    IntegerType::U8 => u8::MAX as u128,
+                    IntegerType::U16 => u16::MAX as u128,
+                    IntegerType::U32 => u32::MAX as u128,
+                    IntegerType::U64 => u64::MAX as u128,
+                    IntegerType::U128 => u128::MAX,
+                };
+                let value = match self.rng.gen_range(0..4) {
+                    0 => 0,
+                    1 => max,
+                    _ => self.rng.gen_range(0..=max.min(1000)),
+                };
+                format!("{}{}", value, integer_type)
+            }
+            Type::Composite(name) => return self.circuit_init(name, 0),
+        };
+        Expression::Literal(literal)
+    }
+
+    /// Returns an initialization of a circuit or a record, with its members in a random order.
+    fn circuit_init(&mut self, name: &str, depth: usize) -> Expression {
+        let composite = self
+            .program
+            .composites
+            .iter()
+            .find(|composite| composite.name == name)
+            .expect("composite types are declared")
+            .clone();
+        let mut members: Vec<_> = composite
+            .members
+            .iter()
+            .map(|(member, type_)| (member.clone(), self.expression(type_, depth)))
+            .collect();
+        members.shuffle(&mut self.rng);
+        Expression::CircuitInit(composite.name, members)
+    }
+
+    fn member_access(&mut self, type_: &Type, depth: usize) -> Option<Expression> {
+        let accesses: Vec<_> = self
+            .program
+            .composites
+            .iter()
+            .flat_map(|composite| {
+                composite
+                    .members
+                    .iter()
+                    .filter(|(_, member_type)| member_type == type_)
+                    .map(move |(member, _)| (composite.name.clone(), member.clone()))
+            })
+            .collect();
+        let (composite, member) = accesses.choose(&mut self.rng)?.clone();
+        let inner = self.expression(&Type::Composite(composite), depth);
+        Some(Expression::Member(Box::new(inner), member))
+    }
+
+    /// Returns a call to a function that is already generated, so that functions are not recursive.
+    fn call(&mut self, type_: &Type, depth: usize) -> Option<Expression> {
+        let functions: Vec<_> = self
+            .program
+            .functions
+            .iter()
+            .filter(|function| &function.output == type_)
+            .map(|function| (function.name.clone(), function.inputs.clone()))
+            .collect();
+        let (name, inputs) = functions.choose(&mut self.rng)?.clone();
+        let arguments = inputs
+            .iter()
+            .map(|(_, _, type_)| self.expression(type_, depth))
+            .collect();
+        Some(Expression::Call(name, arguments))
+    }
+
+    /// Returns an operation, or a core function call, whose result has the type.
+    fn operation(&mut self, type_: &Type, depth: usize) -> Expression {
+        let unary = |op, operand| Expression::Unary(op, Box::new(operand));
+        let binary = |op, left, right| Expression::Binary(op, Box::new(left), Box::new(right));
+        let method = |receiver, method, argument: Option<Expression>| {
+            Expression::Method(Box::new(receiver), method, argument.map(Box::new))
+        };
+
+        match type_ {
+            Type::Composite(name) => self.circuit_init(name, depth),
+            Type::Address => self.leaf(type_),
+            Type::Boolean => match self.rng.gen_range(0..5) {
+                0 => unary("!", self.expression(type_, depth)),
+                1 => {
+                    let op = *["&&", "||"].choose(&mut self.rng).unwrap();
+                    binary(op, self.expression(type_, depth), self.expression(type_, depth))
+                }
+                2 => {
+                    let op = *["nand", "nor"].choose(&mut self.rng).unwrap();
+                    method(self.expression(type_, depth), op, Some(self.expression(type_, depth)))
+                }
+                3 => {
+                    let operands = self.primitive_type();
+                    let op = *["==", "!="].choose(&mut self.rng).unwrap();
+                    binary(op, self.expression(&operands, depth), self.expression(&operands, depth))
+                }
+                _ => {
+                    let operands = match self.rng.gen_range(0..3) {
+                        0 => Type::Field,
+                        1 => Type::Scalar,
+                        _ => Type::Integer(*IntegerType::ALL.choose(&mut self.rng).unwrap()),
+                    };
+                    let op = *["<", "<=", ">", ">="].choose(&mut self.rng).unwrap();
+                    binary(op, self.expression(&operands, depth), self.expression(&operands, depth))
+                }
+            },
+            Type::Integer(integer_type) => match self.rng.gen_range(0..6) {
+                0 => {
+                    let op = *["+", "-", "*", "/", "&", "|", "^"].choose(&mut self.rng).unwrap();
+                    binary(op, self.expression(type_, depth), self.expression(type_, depth))
+                }
+                1 => {
+                    let op = *["add_wrapped", "sub_wrapped", "mul_wrapped", "div_wrapped"]
+                        .choose(&mut self.rng)
+                        .unwrap();
+                    method(self.expression(type_, depth), op, Some(self.expression(type_, depth)))
+                }
+                2 => {
+                    let magnitude = *[IntegerType::U8, IntegerType::U16, IntegerType::U32]
+                        .choose(&mut self.rng)
+                        .unwrap();
+                    let op = *["**", "<<", ">>"].choose(&mut self.rng).unwrap();
+                    binary(
+                        op,
+                        self.expression(type_, depth),
+                        self.expression(&Type::Integer(magnitude), depth),
+                    )
+                }
+                3 => {
+                    let magnitude = *[IntegerType::U8, IntegerType::U16, IntegerType::U32]
+                        .choose(&mut self.rng)
+                        .unwrap();
+                    let op = *["pow_wrapped", "shl_wrapped", "shr_wrapped"]
+                        .choose(&mut self.rng)
+                        .unwrap();
+                    method(
+                        self.expression(type_, depth),
+                        op,
+                        Some(self.expression(&Type::Integer(magnitude), depth)),
+                    )
+                }
+                4 if integer_type.is_signed() => match self.rng.gen_range(0..3) {
+                    0 => unary("-", self.expression(type_, depth)),
+                    1 => method(self.expression(type_, depth), "abs", None),
+                    _ => method(self.expression(type_, depth), "abs_wrapped", None),
+                },
+                _ => unary("!", self.expression(type_, depth)),
+            },
+            Type::Field => match self.rng.gen_range(0..5) {
+                0 => {
+                    let op = *["+", "-", "*", "/", "**"].choose(&mut self.rng).unwrap();
+                    binary(op, self.expression(type_, depth), self.expression(type_, depth))
+                }
+                1 => unary("-", self.expression(type_, depth)),
+                2 => {
+                    let op = *["double", "square", "inv"].choose(&mut self.rng).unwrap();
+                    method(self.expression(type_, depth), op, None)
+                }
+                _ if self.config.core_functions => {
+                    let argument_type = self.primitive_type();
+                    let argument = self.expression(&argument_type, depth);
+                    match self.rng.gen_range(0..3) {
+                        0 => {
+                            let circuit = *["Poseidon2", "Poseidon4", "Poseidon8"].choose(&mut self.rng).unwrap();
+                            Expression::Core(circuit, "hash", vec![argument])
+                        }
+                        1 => {
+                            let circuit = *["BHP256", "BHP512", "BHP768", "BHP1024"].choose(&mut self.rng).unwrap();
+                            Expression::Core(circuit, "hash", vec![argument])
+                        }
+                        _ => {
+                            let circuit = *["BHP256", "BHP512", "BHP768", "BHP1024"].choose(&mut self.rng).unwrap();
+                            let randomizer = self.expression(&Type::Scalar, depth);
+                            Expression::Core(circuit, "commit", vec![argument, randomizer])
+                        }
+                    }
+                }
+                _ => binary("+", self.expression(type_, depth), self.expression(type_, depth)),
+            },
+            Type::Group => match self.rng.gen_range(0..5) {
+                0 => {
+                    let op = *["+", "-"].choose(&mut self.rng).unwrap();
+                    binary(op, self.expression(type_, depth), self.expression(type_, depth))
+                }
+                1 => unary("-", self.expression(type_, depth)),
+                2 => method(self.expression(type_, depth), "double", None),
+                3 => binary(
+                    "*",
+                    self.expression(type_, depth),
+                    self.expression(&Type::Scalar, depth),
+                ),
+                _ if self.config.core_functions => {
+                    // Pedersen hashes only accept booleans and integers, of at most 64 bits for `Pedersen64`.
+                    let (circuit, argument_type) = match self.rng.gen_range(0..3) {
+                        0 => ("Pedersen64", Type::Boolean),
+                        1 => {
+                            let integer_type = *IntegerType::ALL
+                                .iter()
+                                .filter(|integer_type| integer_type.is_at_most_64_bits())
+                                .collect::<Vec<_>>()
+                                .choose(&mut self.rng)
+                                .unwrap();
+                            ("Pedersen64", Type::Integer(*integer_type))
+                        }
+                        _ => (
+                            "Pedersen128",
+                            Type::Integer(*IntegerType::ALL.choose(&mut self.rng).unwrap()),
+                        ),
+                    };
+                    Expression::Core(circuit, "hash", vec![self.expression(&argument_type, depth)])
+                }
+                _ => method(self.expression(type_, depth), "double", None),
+            },
+            Type::Scalar => binary("+", self.expression(type_, depth), self.expression(type_, depth)),
+        }
+    }
+}
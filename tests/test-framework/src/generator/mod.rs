@@ -0,0 +1,203 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A generator of random well-typed Leo programs, to test the compiler on programs that nobody wrote.
+//!
+//! [`run_generated_tests()`] checks generated programs with a function, e.g. one that compiles them to
+//! Aleo instructions, and reports each program that fails after shrinking it to a minimal reproducer.
+
+pub mod generate;
+pub use generate::*;
+
+pub mod program;
+pub use program::*;
+
+pub mod shrink;
+pub use shrink::*;
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::Arc,
+    thread,
+};
+
+/// The constructs and the sizes of generated programs.
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub max_circuits: usize,
+    pub max_functions: usize,
+    /// The maximum number of statements in a function body, before its return statement.
+    pub max_statements: usize,
+    pub max_depth: usize,
+    pub max_iterations: u32,
+    pub circuits: bool,
+    pub records: bool,
+    pub loops: bool,
+    /// Whether to generate conditional statements. Ternary expressions are always generated.
+    pub conditionals: bool,
+    pub assignments: bool,
+    pub calls: bool,
+    /// Whether to generate calls to the BHP, Pedersen and Poseidon hashes.
+    pub core_functions: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_circuits: 2,
+            max_functions: 3,
+            max_statements: 6,
+            max_depth: 3,
+            max_iterations: 3,
+            circuits: true,
+            records: true,
+            loops: true,
+            conditionals: true,
+            assignments: true,
+            calls: true,
+            core_functions: true,
+        }
+    }
+}
+
+/// The maximum number of checks to spend on shrinking a failing program.
+const MAX_SHRINK_CHECKS: usize = 2000;
+
+/// How a program failed a check.
+struct Failure {
+    /// The first line of the message, which identifies the failure while the program is shrunk.
+    signature: String,
+    message: String,
+}
+
+fn check_program<F: Fn(&str) -> Result<(), String>>(check: &F, program: &Program) -> Result<(), Failure> {
+    let source = program.to_string();
+    let message = match panic::catch_unwind(AssertUnwindSafe(|| check(&source))) {
+        Ok(Ok(())) => return Ok(()),
+        Ok(Err(error)) => error,
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            format!("panicked: {}", message)
+        }
+    };
+    Err(Failure {
+        signature: message.lines().next().unwrap_or_default().to_string(),
+        message,
+    })
+}
+
+/// Returns the smallest program found that fails like `program`, and its failure.
+fn shrink<F: Fn(&str) -> Result<(), String>>(
+    check: &F,
+    mut program: Program,
+    mut failure: Failure,
+) -> (Program, Failure) {
+    let mut checks = 0;
+    'shrink: while checks < MAX_SHRINK_CHECKS {
+        for simpler in simplifications(&program) {
+            if checks == MAX_SHRINK_CHECKS {
+                break 'shrink;
+            }
+            checks += 1;
+            match check_program(check, &simpler) {
+                Err(simpler_failure) if simpler_failure.signature == failure.signature => {
+                    program = simpler;
+                    failure = simpler_failure;
+                    continue 'shrink;
+                }
+                _ => {}
+            }
+        }
+        break;
+    }
+    (program, failure)
+}
+
+fn env_var<T: std::str::FromStr>(name: &str) -> Option<T> {
+    std::env::var(name).ok().and_then(|value| value.trim().parse().ok())
+}
+
+/// Restores the panic hook that was in place before [`silence_panics()`] when it is dropped.
+struct SilencedPanics(Option<Box<dyn FnOnce()>>);
+
+impl Drop for SilencedPanics {
+    fn drop(&mut self) {
+        // The panic hook cannot be changed by a panicking thread.
+        if let Some(restore) = self.0.take().filter(|_| !thread::panicking()) {
+            restore();
+        }
+    }
+}
+
+/// Stops reporting the panics of the current thread until the returned guard is dropped.
+/// The panics of other threads are still reported by the previous panic hook.
+fn silence_panics() -> SilencedPanics {
+    let thread_id = thread::current().id();
+    let previous = Arc::new(panic::take_hook());
+    let hook = previous.clone();
+    panic::set_hook(Box::new(move |info| {
+        if thread::current().id() != thread_id {
+            hook(info)
+        }
+    }));
+
+    SilencedPanics(Some(Box::new(move || {
+        // Dropping the hook set above leaves the only reference to the previous hook.
+        drop(panic::take_hook());
+        match Arc::try_unwrap(previous) {
+            Ok(previous) => panic::set_hook(previous),
+            Err(previous) => panic::set_hook(Box::new(move |info| previous(info))),
+        }
+    })))
+}
+
+/// Checks `programs` generated programs with `check`, which returns an error or panics for a failing program.
+/// Every program that fails is shrunk, and the smallest failing programs are reported before panicking.
+///
+/// The programs are generated from the seeds following `LEO_FUZZ_SEED`, which is 0 by default,
+/// and `LEO_FUZZ_PROGRAMS` overrides the number of programs.
+pub fn run_generated_tests<F: Fn(&str) -> Result<(), String>>(config: &Config, programs: u64, check: F) {
+    let first_seed = env_var("LEO_FUZZ_SEED").unwrap_or(0u64);
+    let programs = env_var("LEO_FUZZ_PROGRAMS").unwrap_or(programs);
+
+    // Only report the panics of other threads, as every failing program would be reported while it is shrunk.
+    let silenced = silence_panics();
+    let mut failures = Vec::new();
+    for seed in first_seed..first_seed + programs {
+        let program = generate(config, seed);
+        if let Err(failure) = check_program(&check, &program) {
+            failures.push((seed, shrink(&check, program, failure)));
+        }
+    }
+    drop(silenced);
+
+    if !failures.is_empty() {
+        for (i, (seed, (program, failure))) in failures.iter().enumerate() {
+            println!(
+                "\n\n-----------------GENERATED PROGRAM #{} FAILED-----------------",
+                i + 1
+            );
+            println!("Seed: {} (LEO_FUZZ_SEED={} LEO_FUZZ_PROGRAMS=1)", seed, seed);
+            println!("{}\n{}", program, failure.message);
+        }
+        panic!("failed {}/{} generated programs", failures.len(), programs);
+    }
+    println!("passed {} generated programs", programs);
+}
@@ -0,0 +1,350 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The programs produced by the generator, which render as Leo source code.
+
+use std::fmt::{self, Display, Write};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntegerType {
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+}
+
+impl IntegerType {
+    pub const ALL: [IntegerType; 10] = [
+        IntegerType::I8,
+        IntegerType::I16,
+        IntegerType::I32,
+        IntegerType::I64,
+        IntegerType::I128,
+        IntegerType::U8,
+        IntegerType::U16,
+        IntegerType::U32,
+        IntegerType::U64,
+        IntegerType::U128,
+    ];
+
+    pub fn is_signed(self) -> bool {
+        matches!(
+            self,
+            IntegerType::I8 | IntegerType::I16 | IntegerType::I32 | IntegerType::I64 | IntegerType::I128
+        )
+    }
+
+    /// Returns `true` for the types that Pedersen hashes accept.
+    pub fn is_at_most_64_bits(self) -> bool {
+        !matches!(self, IntegerType::I128 | IntegerType::U128)
+    }
+}
+
+impl Display for IntegerType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            IntegerType::I8 => "i8",
+            IntegerType::I16 => "i16",
+            IntegerType::I32 => "i32",
+            IntegerType::I64 => "i64",
+            IntegerType::I128 => "i128",
+            IntegerType::U8 => "u8",
+            IntegerType::U16 => "u16",
+            IntegerType::U32 => "u32",
+            IntegerType::U64 => "u64",
+            IntegerType::U128 => "u128",
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Type {
+    Address,
+    Boolean,
+    Field,
+    Group,
+    Scalar,
+    Integer(IntegerType),
+    /// A circuit or a record, by name.
+    Composite(String),
+}
+
+impl Type {
+    pub fn is_primitive(&self) -> bool {
+        !matches!(self, Type::Composite(_))
+    }
+
+    /// Returns the simplest literal of the type, if it is primitive.
+    pub fn default_literal(&self) -> Option<Expression> {
+        let literal = match self {
+            Type::Address => ADDRESSES[0].to_string(),
+            Type::Boolean => "false".to_string(),
+            Type::Field | Type::Group | Type::Scalar => format!("0{}", self),
+            Type::Integer(type_) => format!("0{}", type_),
+            Type::Composite(_) => return None,
+        };
+        Some(Expression::Literal(literal))
+    }
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Address => f.write_str("address"),
+            Type::Boolean => f.write_str("bool"),
+            Type::Field => f.write_str("field"),
+            Type::Group => f.write_str("group"),
+            Type::Scalar => f.write_str("scalar"),
+            Type::Integer(type_) => type_.fmt(f),
+            Type::Composite(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Valid Aleo addresses to use as literals.
+pub const ADDRESSES: [&str; 2] = [
+    "aleo10qerras5799u6k7rjtc9y3hcwxuykr45qra7x7dp6jgnc0923czqm0lgta",
+    "aleo1ak60m3dl0ln7r920c6dqqulua4zs5v3skv79yx4f4zyurkd9cgrsjm608a",
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expression {
+    Literal(String),
+    Variable(String),
+    /// A prefix operator, e.g. `-` or `!`.
+    Unary(&'static str, Box<Expression>),
+    /// An infix operator, e.g. `+` or `==`.
+    Binary(&'static str, Box<Expression>, Box<Expression>),
+    /// An operator written as a method, e.g. `double` or `add_wrapped`.
+    Method(Box<Expression>, &'static str, Option<Box<Expression>>),
+    Ternary(Box<Expression>, Box<Expression>, Box<Expression>),
+    CircuitInit(String, Vec<(String, Expression)>),
+    Member(Box<Expression>, String),
+    Call(String, Vec<Expression>),
+    /// A core function, e.g. `BHP256::hash`.
+    Core(&'static str, &'static str, Vec<Expression>),
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Operations are parenthesized, so that the source does not depend on the precedence of operators.
+        match self {
+            Expression::Literal(literal) => f.write_str(literal),
+            Expression::Variable(name) => f.write_str(name),
+            Expression::Unary(op, operand) => write!(f, "({}{})", op, operand),
+            Expression::Binary(op, left, right) => write!(f, "({} {} {})", left, op, right),
+            Expression::Method(receiver, method, None) => write!(f, "{}.{}()", Receiver(receiver), method),
+            Expression::Method(receiver, method, Some(argument)) => {
+                write!(f, "{}.{}({})", Receiver(receiver), method, argument)
+            }
+            Expression::Ternary(condition, if_true, if_false) => {
+                write!(f, "({} ? {} : {})", condition, if_true, if_false)
+            }
+            Expression::CircuitInit(name, members) => {
+                write!(f, "{} {{ ", name)?;
+                write_separated(f, members.iter().map(|(name, value)| format!("{}: {}", name, value)))?;
+                f.write_str(" }")
+            }
+            Expression::Member(inner, name) => write!(f, "{}.{}", Receiver(inner), name),
+            Expression::Call(name, arguments) => {
+                write!(f, "{}(", name)?;
+                write_separated(f, arguments.iter())?;
+                f.write_char(')')
+            }
+            Expression::Core(circuit, function, arguments) => {
+                write!(f, "{}::{}(", circuit, function)?;
+                write_separated(f, arguments.iter())?;
+                f.write_char(')')
+            }
+        }
+    }
+}
+
+/// The receiver of a method call or a member access, which is parenthesized unless it is a variable.
+struct Receiver<'a>(&'a Expression);
+
+impl Display for Receiver<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Expression::Variable(name) => f.write_str(name),
+            Expression::Unary(..) | Expression::Binary(..) | Expression::Ternary(..) => self.0.fmt(f),
+            expression => write!(f, "({})", expression),
+        }
+    }
+}
+
+fn write_separated<T: Display>(f: &mut fmt::Formatter<'_>, items: impl Iterator<Item = T>) -> fmt::Result {
+    for (i, item) in items.enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        item.fmt(f)?;
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug)]
+pub enum Statement {
+    Definition(String, Type, Expression),
+    Assign(String, Expression),
+    Conditional(Expression, Vec<Statement>, Vec<Statement>),
+    /// A loop over the `u32`s from the start, inclusive, to the stop, exclusive.
+    Iteration(String, u32, u32, Vec<Statement>),
+    Assert(Expression),
+    Return(Expression),
+}
+
+impl Statement {
+    fn write(&self, out: &mut String, indent: usize) {
+        let pad = "    ".repeat(indent);
+        match self {
+            Statement::Definition(name, type_, value) => {
+                writeln!(out, "{}let {}: {} = {};", pad, name, type_, value).unwrap()
+            }
+            Statement::Assign(name, value) => writeln!(out, "{}{} = {};", pad, name, value).unwrap(),
+            Statement::Conditional(condition, then, otherwise) => {
+                writeln!(out, "{}if {} {{", pad, condition).unwrap();
+                write_block(out, then, indent + 1);
+                writeln!(out, "{}}} else {{", pad).unwrap();
+                write_block(out, otherwise, indent + 1);
+                writeln!(out, "{}}}", pad).unwrap();
+            }
+            Statement::Iteration(variable, start, stop, body) => {
+                writeln!(out, "{}for {}: u32 in {}u32..{}u32 {{", pad, variable, start, stop).unwrap();
+                write_block(out, body, indent + 1);
+                writeln!(out, "{}}}", pad).unwrap();
+            }
+            Statement::Assert(condition) => writeln!(out, "{}console.assert({});", pad, condition).unwrap(),
+            Statement::Return(value) => writeln!(out, "{}return {};", pad, value).unwrap(),
+        }
+    }
+}
+
+fn write_block(out: &mut String, statements: &[Statement], indent: usize) {
+    for statement in statements {
+        statement.write(out, indent);
+    }
+}
+
+/// A circuit or a record.
+#[derive(Clone, Debug)]
+pub struct Composite {
+    pub name: String,
+    pub is_record: bool,
+    pub members: Vec<(String, Type)>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Function {
+    pub name: String,
+    /// The inputs, which are public or else private.
+    pub inputs: Vec<(bool, String, Type)>,
+    pub output: Type,
+    pub body: Vec<Statement>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Program {
+    pub composites: Vec<Composite>,
+    pub functions: Vec<Function>,
+}
+
+impl Program {
+    /// Returns the number of items, statements and expressions of the program, to measure shrinking.
+    pub fn size(&self) -> usize {
+        fn expression(value: &Expression) -> usize {
+            1 + match value {
+                Expression::Literal(_) | Expression::Variable(_) => 0,
+                Expression::Unary(_, operand) => expression(operand),
+                Expression::Binary(_, left, right) => expression(left) + expression(right),
+                Expression::Method(receiver, _, argument) => {
+                    expression(receiver) + argument.as_deref().map_or(0, expression)
+                }
+                Expression::Ternary(condition, if_true, if_false) => {
+                    expression(condition) + expression(if_true) + expression(if_false)
+                }
+                Expression::CircuitInit(_, members) => members.iter().map(|(_, value)| expression(value)).sum(),
+                Expression::Member(inner, _) => expression(inner),
+                Expression::Call(_, arguments) | Expression::Core(_, _, arguments) => {
+                    arguments.iter().map(expression).sum()
+                }
+            }
+        }
+        fn block(statements: &[Statement]) -> usize {
+            statements
+                .iter()
+                .map(|statement| {
+                    1 + match statement {
+                        Statement::Definition(_, _, value)
+                        | Statement::Assign(_, value)
+                        | Statement::Assert(value)
+                        | Statement::Return(value) => expression(value),
+                        Statement::Conditional(condition, then, otherwise) => {
+                            expression(condition) + block(then) + block(otherwise)
+                        }
+                        Statement::Iteration(_, start, stop, body) => (stop - start) as usize + block(body),
+                    }
+                })
+                .sum()
+        }
+
+        self.composites
+            .iter()
+            .map(|composite| 1 + composite.members.len())
+            .sum::<usize>()
+            + self
+                .functions
+                .iter()
+                .map(|function| 1 + function.inputs.len() + block(&function.body))
+                .sum::<usize>()
+    }
+}
+
+impl Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = String::new();
+        for composite in &self.composites {
+            let keyword = if composite.is_record { "record" } else { "circuit" };
+            writeln!(out, "{} {} {{", keyword, composite.name)?;
+            for (i, (name, type_)) in composite.members.iter().enumerate() {
+                let separator = if i + 1 < composite.members.len() { "," } else { "" };
+                writeln!(out, "    {}: {}{}", name, type_, separator)?;
+            }
+            writeln!(out, "}}\n")?;
+        }
+        for (i, function) in self.functions.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            write!(out, "function {}(", function.name)?;
+            for (i, (public, name, type_)) in function.inputs.iter().enumerate() {
+                let separator = if i > 0 { ", " } else { "" };
+                let mode = if *public { "public " } else { "" };
+                write!(out, "{}{}{}: {}", separator, mode, name, type_)?;
+            }
+            writeln!(out, ") -> {} {{", function.output)?;
+            write_block(&mut out, &function.body, 1);
+            writeln!(out, "}}")?;
+        }
+        f.write_str(&out)
+    }
+}
@@ -0,0 +1,261 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::program::*;
+
+/// Returns the programs that are one simplification away from `program`, from the largest simplifications
+/// to the smallest. A simplification may make the program ill-typed, in which case it fails differently.
+pub fn simplifications(program: &Program) -> Vec<Program> {
+    let mut programs = Vec::new();
+
+    for i in 0..program.functions.len() {
+        let mut simpler = program.clone();
+        simpler.functions.remove(i);
+        programs.push(simpler);
+    }
+    for i in 0..program.composites.len() {
+        let mut simpler = program.clone();
+        simpler.composites.remove(i);
+        programs.push(simpler);
+    }
+
+    for (i, function) in program.functions.iter().enumerate() {
+        for j in 0..function.inputs.len() {
+            let mut simpler = program.clone();
+            simpler.functions[i].inputs.remove(j);
+            programs.push(simpler);
+        }
+    }
+
+    let shrinker = Shrinker { program };
+    for (i, function) in program.functions.iter().enumerate() {
+        for body in shrinker.block(&function.body, &function.output) {
+            let mut simpler = program.clone();
+            simpler.functions[i].body = body;
+            programs.push(simpler);
+        }
+    }
+
+    programs
+}
+
+struct Shrinker<'a> {
+    program: &'a Program,
+}
+
+impl Shrinker<'_> {
+    /// Returns the simplifications of a block, in a function returning `output`.
+    fn block(&self, statements: &[Statement], output: &Type) -> Vec<Vec<Statement>> {
+        let mut blocks = Vec::new();
+        let replace = |i: usize, replacement: &[Statement]| {
+            let mut block = statements[..i].to_vec();
+            block.extend_from_slice(replacement);
+            block.extend_from_slice(&statements[i + 1..]);
+            block
+        };
+
+        // Remove statements, or replace them with the statements they contain.
+        for (i, statement) in statements.iter().enumerate() {
+            blocks.push(replace(i, &[]));
+            match statement {
+                Statement::Conditional(_, then, otherwise) => {
+                    blocks.push(replace(i, then));
+                    blocks.push(replace(i, otherwise));
+                }
+                Statement::Iteration(variable, start, stop, body) => {
+                    blocks.push(replace(i, body));
+                    if stop - start > 1 {
+                        let once = Statement::Iteration(variable.clone(), *start, start + 1, body.clone());
+                        blocks.push(replace(i, &[once]));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Simplify the statements.
+        for (i, statement) in statements.iter().enumerate() {
+            for simpler in self.statement(statement, output) {
+                blocks.push(replace(i, &[simpler]));
+            }
+        }
+
+        blocks
+    }
+
+    fn statement(&self, statement: &Statement, output: &Type) -> Vec<Statement> {
+        match statement {
+            Statement::Definition(name, type_, value) => self
+                .expression(value, Some(type_))
+                .into_iter()
+                .map(|value| Statement::Definition(name.clone(), type_.clone(), value))
+                .collect(),
+            Statement::Assign(name, value) => self
+                .expression(value, None)
+                .into_iter()
+                .map(|value| Statement::Assign(name.clone(), value))
+                .collect(),
+            Statement::Conditional(condition, then, otherwise) => {
+                let mut statements: Vec<_> = self
+                    .expression(condition, Some(&Type::Boolean))
+                    .into_iter()
+                    .map(|condition| Statement::Conditional(condition, then.clone(), otherwise.clone()))
+                    .collect();
+                for then in self.block(then, output) {
+                    statements.push(Statement::Conditional(condition.clone(), then, otherwise.clone()));
+                }
+                for otherwise in self.block(otherwise, output) {
+                    statements.push(Statement::Conditional(condition.clone(), then.clone(), otherwise));
+                }
+                statements
+            }
+            Statement::Iteration(variable, start, stop, body) => self
+                .block(body, output)
+                .into_iter()
+                .map(|body| Statement::Iteration(variable.clone(), *start, *stop, body))
+                .collect(),
+            Statement::Assert(condition) => self
+                .expression(condition, Some(&Type::Boolean))
+                .into_iter()
+                .map(Statement::Assert)
+                .collect(),
+            Statement::Return(value) => self
+                .expression(value, Some(output))
+                .into_iter()
+                .map(Statement::Return)
+                .collect(),
+        }
+    }
+
+    /// Returns the simplifications of an expression, whose type is `type_` if it is known.
+    /// An expression is replaced with the simplest literal of its type, or with one of its operands.
+    fn expression(&self, expression: &Expression, type_: Option<&Type>) -> Vec<Expression> {
+        let mut expressions = Vec::new();
+        if let Some(literal) = type_.and_then(Type::default_literal) {
+            if &literal != expression {
+                expressions.push(literal);
+            }
+        }
+
+        // The operands of the operations whose operands and result have the same type.
+        let same_type = |op: &str| match op {
+            "*" => type_.filter(|type_| **type_ != Type::Group),
+            "==" | "!=" | "<" | "<=" | ">" | ">=" | "**" | "<<" | ">>" => None,
+            _ => type_,
+        };
+        let boxed = |expression: Expression| Box::new(expression);
+
+        match expression {
+            Expression::Literal(_) | Expression::Variable(_) => {}
+            Expression::Unary(op, operand) => {
+                expressions.push(operand.as_ref().clone());
+                for operand in self.expression(operand, type_) {
+                    expressions.push(Expression::Unary(op, boxed(operand)));
+                }
+            }
+            Expression::Binary(op, left, right) => {
+                expressions.push(left.as_ref().clone());
+                expressions.push(right.as_ref().clone());
+                for left in self.expression(left, same_type(op)) {
+                    expressions.push(Expression::Binary(op, boxed(left), right.clone()));
+                }
+                for right in self.expression(right, same_type(op)) {
+                    expressions.push(Expression::Binary(op, left.clone(), boxed(right)));
+                }
+            }
+            Expression::Method(receiver, method, argument) => {
+                expressions.push(receiver.as_ref().clone());
+                expressions.extend(argument.as_deref().cloned());
+                let receiver_type = match *method {
+                    "nand" | "nor" => Some(&Type::Boolean),
+                    _ => type_,
+                };
+                for receiver in self.expression(receiver, receiver_type) {
+                    expressions.push(Expression::Method(boxed(receiver), method, argument.clone()));
+                }
+                if let Some(argument) = argument {
+                    let argument_type = match *method {
+                        "pow_wrapped" | "shl_wrapped" | "shr_wrapped" => None,
+                        _ => receiver_type,
+                    };
+                    for argument in self.expression(argument, argument_type) {
+                        expressions.push(Expression::Method(receiver.clone(), method, Some(boxed(argument))));
+                    }
+                }
+            }
+            Expression::Ternary(condition, if_true, if_false) => {
+                expressions.push(if_true.as_ref().clone());
+                expressions.push(if_false.as_ref().clone());
+                for condition in self.expression(condition, Some(&Type::Boolean)) {
+                    expressions.push(Expression::Ternary(boxed(condition), if_true.clone(), if_false.clone()));
+                }
+                for if_true in self.expression(if_true, type_) {
+                    expressions.push(Expression::Ternary(condition.clone(), boxed(if_true), if_false.clone()));
+                }
+                for if_false in self.expression(if_false, type_) {
+                    expressions.push(Expression::Ternary(condition.clone(), if_true.clone(), boxed(if_false)));
+                }
+            }
+            Expression::CircuitInit(name, members) => {
+                let composite = self.program.composites.iter().find(|composite| &composite.name == name);
+                for (i, (member, value)) in members.iter().enumerate() {
+                    let member_type = composite.and_then(|composite| {
+                        composite
+                            .members
+                            .iter()
+                            .find(|(name, _)| name == member)
+                            .map(|(_, type_)| type_)
+                    });
+                    for value in self.expression(value, member_type) {
+                        let mut members = members.clone();
+                        members[i].1 = value;
+                        expressions.push(Expression::CircuitInit(name.clone(), members));
+                    }
+                }
+            }
+            Expression::Member(inner, member) => {
+                for inner in self.expression(inner, None) {
+                    expressions.push(Expression::Member(boxed(inner), member.clone()));
+                }
+            }
+            Expression::Call(name, arguments) => {
+                let function = self.program.functions.iter().find(|function| &function.name == name);
+                for (i, argument) in arguments.iter().enumerate() {
+                    let argument_type = function
+                        .and_then(|function| function.inputs.get(i))
+                        .map(|(_, _, type_)| type_);
+                    for argument in self.expression(argument, argument_type) {
+                        let mut arguments = arguments.clone();
+                        arguments[i] = argument;
+                        expressions.push(Expression::Call(name.clone(), arguments));
+                    }
+                }
+            }
+            Expression::Core(circuit, function, arguments) => {
+                for (i, argument) in arguments.iter().enumerate() {
+                    let argument_type = if i == 1 { Some(&Type::Scalar) } else { None };
+                    for argument in self.expression(argument, argument_type) {
+                        let mut arguments = arguments.clone();
+                        arguments[i] = argument;
+                        expressions.push(Expression::Core(circuit, function, arguments));
+                    }
+                }
+            }
+        }
+
+        expressions
+    }
+}
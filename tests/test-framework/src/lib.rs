@@ -29,6 +29,8 @@ pub mod error;
 
 pub mod fetch;
 
+pub mod generator;
+
 pub mod output;
 
 pub mod runner;
@@ -182,6 +182,18 @@ impl TestCases {
     }
 }
 
+/// Splits the content of a test file into the tests of its namespace.
+fn split_tests(parse_type: ParseType, content: &str) -> Vec<String> {
+    match parse_type {
+        ParseType::Line => crate::fetch::split_tests_one_line(content)
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+        ParseType::ContinuousLines => crate::fetch::split_tests_two_line(content),
+        ParseType::Whole => vec![content.to_string()],
+    }
+}
+
 pub fn run_tests<T: Runner>(runner: &T, expectation_category: &str) {
     let (mut cases, configs) = TestCases::new(expectation_category, |_| true);
 
@@ -198,14 +210,7 @@ pub fn run_tests<T: Runner>(runner: &T, expectation_category: &str) {
 
         let (expectation_path, expectations) = cases.load_expectations(path, expectation_category);
 
-        let tests = match namespace.parse_type() {
-            ParseType::Line => crate::fetch::split_tests_one_line(content)
-                .into_iter()
-                .map(|x| x.to_string())
-                .collect(),
-            ParseType::ContinuousLines => crate::fetch::split_tests_two_line(content),
-            ParseType::Whole => vec![content.to_string()],
-        };
+        let tests = split_tests(namespace.parse_type(), content);
 
         let mut errors = vec![];
         if let Some(expectations) = expectations.as_ref() {
@@ -303,6 +308,53 @@ pub fn run_tests<T: Runner>(runner: &T, expectation_category: &str) {
     std::env::remove_var("LEO_TESTFRAMEWORK");
 }
 
+/// Runs the tests of all `categories` with the namespaces of `runner`, without any expectations.
+/// Tests whose namespace the runner does not resolve are skipped,
+/// and every other test must succeed, whatever the expectation in its header.
+pub fn run_checks<T: Runner>(runner: &T, categories: &[&str]) {
+    let mut pass_tests = 0;
+    let mut failures = vec![];
+
+    for category in categories {
+        let (mut cases, configs) = TestCases::new(category, |_| true);
+        cases.process_tests(configs, |_, (path, content, test_name, config)| {
+            let namespace = match runner.resolve_namespace(&config.namespace) {
+                Some(ns) => ns,
+                None => return,
+            };
+
+            for test in split_tests(namespace.parse_type(), content) {
+                println!("checking test {} @ '{}'", test_name, path.to_str().unwrap());
+                let panic_buf = set_hook();
+                let leo_output = panic::catch_unwind(|| {
+                    namespace.run_test(Test {
+                        name: test_name.to_string(),
+                        content: test.clone(),
+                        path: path.into(),
+                        config: config.extra.clone(),
+                    })
+                });
+                match take_hook(leo_output, panic_buf) {
+                    Ok(Ok(_)) => pass_tests += 1,
+                    Ok(Err(error)) | Err(error) => failures.push((path.to_path_buf(), test, error)),
+                }
+            }
+        });
+    }
+
+    if !failures.is_empty() {
+        for (i, (path, test, error)) in failures.iter().enumerate() {
+            println!("\n\n-----------------CHECK #{} FAILED-----------------", i + 1);
+            println!("File: {}", path.display());
+            println!("{}\n{}", test, error);
+        }
+        panic!("failed {}/{} checks", failures.len(), failures.len() + pass_tests);
+    }
+    println!("passed {} checks", pass_tests);
+
+    std::env::remove_var("LEO_TESTFRAMEWORK");
+}
+
 /// returns (name, content) for all benchmark samples
 pub fn get_benches() -> Vec<(String, String)> {
     let (mut cases, configs) = TestCases::new("compiler", |config| {